@@ -1,10 +1,11 @@
 use crate::utils::format::write_output;
+use crate::utils::units::UnitSystem;
 use crate::{Cli, tables};
 use anyhow::{Result, anyhow};
 use clap::{Args, Subcommand};
 use noaa_weather_client::apis::configuration::Configuration;
 use noaa_weather_client::apis::gridpoints as gridpoints_api;
-use noaa_weather_client::models::{GridpointForecastUnits, NwsForecastOfficeId};
+use noaa_weather_client::models::NwsForecastOfficeId;
 
 /// Common arguments for identifying a specific NWS gridpoint.
 #[derive(Args, Debug, Clone)]
@@ -50,9 +51,6 @@ pub enum GridpointCommands {
     Forecast {
         #[clap(flatten)]
         location: GridpointLocationArgs,
-        /// Specify units for forecast data (`us` for US customary, `si` for Metric).
-        #[arg(long, value_enum)]
-        units: Option<GridpointForecastUnits>,
     },
     /// Get the hourly textual forecast for a gridpoint.
     ///
@@ -61,9 +59,6 @@ pub enum GridpointCommands {
     ForecastHourly {
         #[clap(flatten)]
         location: GridpointLocationArgs,
-        /// Specify units for forecast data (`us` for US customary, `si` for Metric).
-        #[arg(long, value_enum)]
-        units: Option<GridpointForecastUnits>,
     },
     /// List observation stations usable for retrieving observations for a gridpoint.
     ///
@@ -111,18 +106,18 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::gridpoints::create_gridpoint_table(&result);
+                let table = tables::gridpoints::create_gridpoint_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
         }
-        GridpointCommands::Forecast { location, units } => {
+        GridpointCommands::Forecast { location } => {
             let result = gridpoints_api::get_gridpoint_forecast(
                 config,
                 location.forecast_office_id,
                 location.x,
                 location.y,
                 None,
-                *units,
+                cli.units.map(UnitSystem::forecast_units),
             )
             .await
             .map_err(|error| anyhow!("getting gridpoint forecast: {}", error))?;
@@ -133,18 +128,18 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::gridpoints::create_forecast_table(&result);
+                let table = tables::gridpoints::create_forecast_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
         }
-        GridpointCommands::ForecastHourly { location, units } => {
+        GridpointCommands::ForecastHourly { location } => {
             let result = gridpoints_api::get_gridpoint_forecast_hourly(
                 config,
                 location.forecast_office_id,
                 location.x,
                 location.y,
                 None,
-                *units,
+                cli.units.map(UnitSystem::forecast_units),
             )
             .await
             .map_err(|error| anyhow!("getting hourly gridpoint forecast: {}", error))?;
@@ -155,7 +150,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::gridpoints::create_hourly_forecast_table(&result);
+                let table = tables::gridpoints::create_hourly_forecast_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
         }
@@ -177,7 +172,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::stations::create_stations_table(&result);
+                let table = tables::stations::create_stations_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
         }
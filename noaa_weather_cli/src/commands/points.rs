@@ -75,7 +75,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::stations::create_stations_table(&result);
+                let table = tables::stations::create_stations_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -1,11 +1,16 @@
 use anyhow::{Result, anyhow};
 use clap::{Args, Subcommand};
+use jiff::{Span, Timestamp, Unit, Zoned};
+use noaa_weather_client::afd::AfdSectionKind;
 use noaa_weather_client::apis::configuration::Configuration;
 use noaa_weather_client::apis::products as products_api;
 use noaa_weather_client::apis::products::ProductsQueryParams;
+use noaa_weather_client::lsr::StormReport;
 use noaa_weather_client::models::NwsForecastOfficeId;
+use noaa_weather_client::{product_diff, product_search};
+use regex::Regex;
 
-use crate::utils::format::write_output;
+use crate::utils::format::{format_csv_record, write_output};
 use crate::{Cli, tables};
 
 /// Arguments for commands requiring a product issuance location ID.
@@ -99,6 +104,91 @@ pub struct LatestProductArgs {
     pub location_id: String,
 }
 
+/// Arguments for showing the latest Area Forecast Discussion for an office.
+#[derive(Args, Debug, Clone)]
+pub struct DiscussionArgs {
+    /// Forecast office ID (e.g., PSR, LWX).
+    #[arg(long, value_enum)]
+    pub office: NwsForecastOfficeId,
+    /// Show only this section (e.g., key-messages, short-term, long-term,
+    /// aviation, fire-weather, marine, wwa).
+    #[arg(long)]
+    pub section: Option<AfdSectionKind>,
+}
+
+/// Arguments for listing storm reports from recent Local Storm Report products.
+#[derive(Args, Debug, Clone)]
+pub struct StormReportsArgs {
+    /// Forecast office ID (e.g., PSR, OUN).
+    #[arg(long, value_enum)]
+    pub office: NwsForecastOfficeId,
+    /// Only include reports at or after this time, given as a timestamp
+    /// (e.g., "2025-10-18T00:00:00Z") or a duration before now (e.g., P3D, PT12H).
+    #[arg(long, value_parser = parse_since)]
+    pub since: Option<Timestamp>,
+    /// Maximum number of LSR products to read.
+    #[arg(long, default_value_t = 25, value_parser = clap::value_parser!(i32).range(1..=500))]
+    pub limit: i32,
+    /// Maximum number of LSR products to download at once.
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u8).range(1..=16))]
+    pub concurrency: u8,
+    /// Output the reports as CSV.
+    #[arg(long, default_value_t = false, conflicts_with = "json")]
+    pub csv: bool,
+}
+
+/// Arguments for showing the latest daily climate report for a climate site.
+#[derive(Args, Debug, Clone)]
+pub struct ClimateArgs {
+    /// Forecast office ID that issues the report (e.g., PSR).
+    #[arg(long, value_enum)]
+    pub office: NwsForecastOfficeId,
+    /// Climate site ID, the last three letters of the CLI product ID (e.g., PHX for CLIPHX).
+    #[arg(long)]
+    pub station: String,
+}
+
+/// Arguments for comparing the two most recent issuances of a product.
+#[derive(Args, Debug, Clone)]
+pub struct DiffArgs {
+    /// Product type ID (e.g., AFD, HWO, ZFP).
+    #[arg(long = "type")]
+    pub r#type: String,
+    /// Product issuance location ID (e.g., PSR, LWX).
+    #[arg(long, value_enum)]
+    pub location: NwsForecastOfficeId,
+    /// Number of unchanged lines to show around each change.
+    #[arg(long, default_value_t = 3)]
+    pub context: usize,
+}
+
+/// Arguments for searching the text of recent products.
+#[derive(Args, Debug, Clone)]
+pub struct SearchArgs {
+    /// Words to find, in order, ignoring case and line breaks (e.g., "blowing dust").
+    /// With `--regex`, a regular expression.
+    pub query: String,
+    /// Treat the query as a regular expression.
+    #[arg(long, default_value_t = false)]
+    pub regex: bool,
+    /// Product type code(s) to search (e.g., AFD, HWO, comma-separated).
+    #[arg(long = "type", value_name = "TYPE", value_delimiter = ',')]
+    pub types: Option<Vec<String>>,
+    /// Product issuance location ID(s) to search (e.g., PSR, TWC, comma-separated).
+    #[arg(long, value_delimiter = ',', value_enum)]
+    pub office: Option<Vec<NwsForecastOfficeId>>,
+    /// Only search products issued since this time, given as a timestamp
+    /// (e.g., "2025-10-18T00:00:00Z") or a duration before now (e.g., P3D, PT12H).
+    #[arg(long, value_parser = parse_since)]
+    pub since: Option<Timestamp>,
+    /// Maximum number of products to download.
+    #[arg(long, default_value_t = 50, value_parser = clap::value_parser!(i32).range(1..=500))]
+    pub limit: i32,
+    /// Maximum number of products to download at once.
+    #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u8).range(1..=16))]
+    pub concurrency: u8,
+}
+
 /// Access information about NWS text products.
 #[derive(Subcommand, Debug, Clone)]
 pub enum ProductCommands {
@@ -146,6 +236,31 @@ pub enum ProductCommands {
     /// Example: `noaa-weather products latest --type-id AFD --location-id PSR`
     #[clap(name = "latest")]
     Latest(LatestProductArgs),
+    /// Get the latest Area Forecast Discussion for an office, split into its sections.
+    ///
+    /// Example: `noaa-weather products discussion --office PSR --section aviation`
+    #[clap(name = "discussion")]
+    Discussion(DiscussionArgs),
+    /// List storm reports (tornado, hail, wind, flooding) from an office's recent LSR products.
+    ///
+    /// Example: `noaa-weather products storm-reports --office PSR --since 2025-10-18T00:00:00Z --csv`
+    #[clap(name = "storm-reports")]
+    StormReports(StormReportsArgs),
+    /// Get the latest daily climate report (CLI) for a climate site.
+    ///
+    /// Example: `noaa-weather products climate --office PSR --station PHX`
+    #[clap(name = "climate")]
+    Climate(ClimateArgs),
+    /// Show what changed between the two most recent issuances of a product type for a location.
+    ///
+    /// Example: `noaa-weather products diff --type AFD --location PSR`
+    #[clap(name = "diff")]
+    Diff(DiffArgs),
+    /// Search the text of recent products and show the matching lines.
+    ///
+    /// Example: `noaa-weather products search "blowing dust" --type AFD --office PSR,TWC --since P3D`
+    #[clap(name = "search")]
+    Search(SearchArgs),
 }
 
 /// Handles the execution of product-related subcommands.
@@ -318,5 +433,219 @@ pub async fn handle_command(
             }
             Ok(())
         }
+        ProductCommands::Discussion(args) => {
+            let product = products_api::get_latest_product_by_type_and_location(
+                config,
+                "AFD",
+                &args.office.to_string(),
+            )
+            .await
+            .map_err(|error| anyhow!("getting latest discussion: {}", error))?;
+            let mut discussion = product
+                .area_forecast_discussion()
+                .map_err(|error| anyhow!("parsing discussion: {}", error))?
+                .ok_or_else(|| anyhow!("the latest discussion for {} has no text", args.office))?;
+            if let Some(section) = &args.section {
+                discussion.sections.retain(|found| found.kind == *section);
+                if discussion.sections.is_empty() {
+                    return Err(anyhow!(
+                        "the latest discussion for {} has no {} section",
+                        args.office,
+                        section
+                    ));
+                }
+            }
+            if cli.json {
+                write_output(
+                    cli.output.as_deref(),
+                    &serde_json::to_string_pretty(&discussion)?,
+                )?;
+            } else {
+                let table = tables::products::create_discussion_table(&discussion);
+                write_output(cli.output.as_deref(), &table.to_string())?;
+            }
+            Ok(())
+        }
+        ProductCommands::StormReports(args) => {
+            // `conflicts_with` misses a global `--json` given before the subcommand.
+            if args.csv && cli.json {
+                return Err(anyhow!("the argument '--csv' cannot be used with '--json'"));
+            }
+            let params = ProductsQueryParams {
+                location_ids: Some(vec![args.office]),
+                start_time: args.since.map(|since| since.to_string()),
+                product_type_codes: Some(vec!["LSR".to_owned()]),
+                limit: Some(args.limit),
+                ..Default::default()
+            };
+            let products =
+                product_search::fetch_products(config, params, usize::from(args.concurrency))
+                    .await
+                    .map_err(|error| anyhow!("getting storm reports: {}", error))?;
+            let mut reports: Vec<StormReport> = Vec::new();
+            for report in products.iter().flat_map(|product| product.storm_reports()) {
+                let recent = args
+                    .since
+                    .is_none_or(|since| report.time.is_none_or(|time| time >= since));
+                if recent && !reports.contains(&report) {
+                    reports.push(report);
+                }
+            }
+            reports.sort_by_key(|report| (report.time, report.local_time));
+            if cli.json {
+                write_output(
+                    cli.output.as_deref(),
+                    &serde_json::to_string_pretty(&reports)?,
+                )?;
+            } else if args.csv {
+                write_output(cli.output.as_deref(), &storm_reports_csv(&reports))?;
+            } else {
+                let table = tables::products::create_storm_reports_table(&reports);
+                write_output(cli.output.as_deref(), &table.to_string())?;
+            }
+            Ok(())
+        }
+        ProductCommands::Climate(args) => {
+            let station = args.station.to_uppercase();
+            let product =
+                products_api::get_latest_product_by_type_and_location(config, "CLI", &station)
+                    .await
+                    .map_err(|error| anyhow!("getting latest climate report: {}", error))?;
+            if let Some(issuing_office) = product.issuing_office.as_deref()
+                && !issuing_office.ends_with(&args.office.to_string())
+            {
+                return Err(anyhow!(
+                    "the climate report for {} is issued by {}, not {}",
+                    station,
+                    issuing_office,
+                    args.office
+                ));
+            }
+            let report = product
+                .climate_report()
+                .map_err(|error| anyhow!("parsing climate report: {}", error))?
+                .ok_or_else(|| anyhow!("the latest climate report for {} has no text", station))?;
+            if cli.json {
+                write_output(
+                    cli.output.as_deref(),
+                    &serde_json::to_string_pretty(&report)?,
+                )?;
+            } else {
+                let table = tables::products::create_climate_report_table(&report);
+                write_output(cli.output.as_deref(), &table.to_string())?;
+            }
+            Ok(())
+        }
+        ProductCommands::Diff(args) => {
+            let diff = product_diff::diff_latest_issuances(config, &args.r#type, &args.location)
+                .await
+                .map_err(|error| anyhow!("comparing products: {}", error))?;
+            if cli.json {
+                write_output(cli.output.as_deref(), &serde_json::to_string_pretty(&diff)?)?;
+            } else {
+                let table = tables::products::create_product_diff_table(&diff);
+                let changes = if diff.has_changes() {
+                    diff.unified(args.context)
+                } else {
+                    "No changes.\n".to_owned()
+                };
+                write_output(cli.output.as_deref(), &format!("{table}\n{changes}"))?;
+            }
+            Ok(())
+        }
+        ProductCommands::Search(args) => {
+            let pattern = if args.regex {
+                Regex::new(&args.query)
+            } else {
+                product_search::keyword_pattern(&args.query)
+            }
+            .map_err(|error| anyhow!("invalid search query: {}", error))?;
+            let params = ProductsQueryParams {
+                location_ids: args.office.clone(),
+                start_time: args.since.map(|since| since.to_string()),
+                product_type_codes: args.types.clone(),
+                limit: Some(args.limit),
+                ..Default::default()
+            };
+            let matches = product_search::search_products(
+                config,
+                params,
+                &pattern,
+                usize::from(args.concurrency),
+            )
+            .await
+            .map_err(|error| anyhow!("searching products: {}", error))?;
+            if cli.json {
+                write_output(
+                    cli.output.as_deref(),
+                    &serde_json::to_string_pretty(&matches)?,
+                )?;
+            } else {
+                let table = tables::products::create_product_search_table(&matches);
+                write_output(cli.output.as_deref(), &table.to_string())?;
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Parses a `--since` value given as a timestamp or as an ISO 8601 or friendly
+/// duration (e.g., `P3D`, `12h`) before now.
+fn parse_since(value: &str) -> Result<Timestamp, String> {
+    if let Ok(timestamp) = value.parse::<Timestamp>() {
+        return Ok(timestamp);
+    }
+    let span: Span = value
+        .parse()
+        .map_err(|_| format!("expected a timestamp or a duration such as P3D, got {value:?}"))?;
+    Zoned::now()
+        .checked_sub(span.abs())
+        .and_then(|since| since.timestamp().round(Unit::Second))
+        .map_err(|error| error.to_string())
+}
+
+/// Formats storm reports as CSV with a header row.
+fn storm_reports_csv(reports: &[StormReport]) -> String {
+    let mut lines = vec![format_csv_record(&[
+        "time",
+        "local_time",
+        "event",
+        "magnitude",
+        "unit",
+        "location",
+        "latitude",
+        "longitude",
+        "county",
+        "state",
+        "source",
+        "remarks",
+    ])];
+    for report in reports {
+        let optional = |value: Option<String>| value.unwrap_or_default();
+        lines.push(format_csv_record(&[
+            optional(report.time.map(|time| time.to_string())),
+            report.local_time.to_string(),
+            report.event.to_string(),
+            optional(
+                report
+                    .magnitude
+                    .as_ref()
+                    .map(|magnitude| magnitude.value.to_string()),
+            ),
+            optional(
+                report
+                    .magnitude
+                    .as_ref()
+                    .map(|magnitude| magnitude.unit.clone()),
+            ),
+            report.location.clone(),
+            optional(report.latitude.map(|latitude| latitude.to_string())),
+            optional(report.longitude.map(|longitude| longitude.to_string())),
+            report.county.clone(),
+            report.state.clone(),
+            report.source.clone(),
+            optional(report.remarks.clone()),
+        ]));
     }
+    lines.join("\n")
 }
@@ -1,9 +1,9 @@
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use clap::{Args, Subcommand};
 use noaa_weather_client::apis::configuration::Configuration;
 use noaa_weather_client::apis::radar as radar_api;
 use noaa_weather_client::apis::radar::RadarDataQueueQueryParams;
-use noaa_weather_client::models::RadarQueueHost;
+use noaa_weather_client::models::{RadarQueueHost, RadarWindProfiler};
 
 use crate::utils::format::write_output;
 use crate::{Cli, tables};
@@ -29,13 +29,14 @@ pub enum RadarCommand {
     StationAlarms(RadarStationAlarmsArgs),
     /// Get a list of radar stations, optionally filtered by type or host.
     Stations(RadarStationsArgs),
-    /// Get metadata for a specific radar wind profiler station.
+    /// Get the wind profile for a specific radar wind profiler station.
+    #[command(visible_alias = "profiler")]
     WindProfiler(RadarWindProfilerArgs),
 }
 
 /// Arguments for the `profiler` subcommand.
 #[derive(Args, Debug, Clone)]
-#[command(about = "Get metadata for a radar wind profiler station.")]
+#[command(about = "Get the wind-by-height profile for a radar wind profiler station.")]
 pub struct RadarWindProfilerArgs {
     /// The ID of the radar wind profiler station (e.g., "HWPA2").
     #[arg(long, required = true)]
@@ -183,10 +184,17 @@ pub async fn handle_command(
                 args.interval.as_deref(),
             )
             .await?;
-            write_output(
-                cli.output.as_deref(),
-                &serde_json::to_string_pretty(&result)?,
-            )?;
+            if cli.json {
+                write_output(
+                    cli.output.as_deref(),
+                    &serde_json::to_string_pretty(&result)?,
+                )?;
+            } else {
+                let profiler: RadarWindProfiler = serde_json::from_value(result)
+                    .map_err(|error| anyhow!("reading radar wind profile: {}", error))?;
+                let table = tables::radar::create_radar_wind_profiler_table(&profiler, cli.units);
+                write_output(cli.output.as_deref(), &table.to_string())?;
+            }
             Ok(())
         }
         RadarCommand::DataQueue(args) => {
@@ -256,7 +264,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::radar::create_radar_station_feature_table(&result);
+                let table = tables::radar::create_radar_station_feature_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -288,7 +296,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::radar::create_radar_stations_table(&result);
+                let table = tables::radar::create_radar_stations_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -3,6 +3,7 @@ use clap::Subcommand;
 use noaa_weather_client::apis::configuration::Configuration;
 use noaa_weather_client::apis::stations as station_api;
 use noaa_weather_client::models::{AreaCode, StateTerritoryCode};
+use noaa_weather_client::taf::DecodedTaf;
 use std::str::FromStr as _;
 
 use crate::utils::format::write_output;
@@ -88,6 +89,9 @@ pub enum StationCommands {
     /// Get a specific Terminal Aerodrome Forecast (TAF) by date and time.
     ///
     /// Example: `noaa-weather stations terminal-aerodrome-forecast --station-id KPHX --date 2025-05-03 --time 1800`
+    ///
+    /// Example: `noaa-weather stations taf --station-id KPHX --date 2025-05-03 --time 1800 --decode`
+    #[command(visible_alias = "taf")]
     TerminalAerodromeForecast {
         /// Airport Station ID (e.g., KPHX).
         #[arg(long)]
@@ -98,6 +102,9 @@ pub enum StationCommands {
         /// Time of the TAF (HHMM format, UTC).
         #[arg(long)]
         time: String,
+        /// Optional: Show the decoded forecast with one row per change group.
+        #[arg(long, default_value_t = false)]
+        decode: bool,
     },
 }
 
@@ -128,7 +135,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::stations::create_observation_station_table(&result);
+                let table = tables::stations::create_observation_station_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -168,7 +175,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::stations::create_stations_table(&result);
+                let table = tables::stations::create_stations_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -190,7 +197,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::stations::create_stations_observation_table(&result);
+                let table = tables::stations::create_stations_observation_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -217,7 +224,8 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::stations::create_stations_observations_table(&result);
+                let table =
+                    tables::stations::create_stations_observations_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -232,7 +240,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::stations::create_stations_observation_table(&result);
+                let table = tables::stations::create_stations_observation_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -256,6 +264,7 @@ pub async fn handle_command(
             station_id,
             date,
             time,
+            decode,
         } => {
             let result = station_api::get_terminal_aerodrome_forecast(
                 config,
@@ -265,13 +274,24 @@ pub async fn handle_command(
             )
             .await
             .map_err(|error| anyhow!("Error getting specific TAF: {error}"))?;
-            if cli.json {
+            if *decode {
+                let decoded = DecodedTaf::from(result);
+                if cli.json {
+                    write_output(
+                        cli.output.as_deref(),
+                        &serde_json::to_string_pretty(&decoded)?,
+                    )?;
+                } else {
+                    let table = tables::stations::create_decoded_taf_table(&decoded, cli.units);
+                    write_output(cli.output.as_deref(), &table.to_string())?;
+                }
+            } else if cli.json {
                 write_output(
                     cli.output.as_deref(),
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::stations::create_stations_taf_table(&result);
+                let table = tables::stations::create_stations_taf_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -65,10 +65,13 @@ pub enum ZoneCommands {
     },
     /// Get the text forecast for a specific zone.
     ///
-    /// Example: `noaa-weather zones forecast --type forecast --id AZZ540`
+    /// Example: `noaa-weather zones forecast --type forecast --id AZZ540 --compact`
     Forecast {
         #[clap(flatten)]
         zone_args: ZoneTypeAndIdArgs,
+        /// Optional: Show the sky, temperatures, wind, and chance of precipitation in columns instead of the full text.
+        #[arg(long, default_value_t = false)]
+        compact: bool,
     },
     /// List observation stations within a forecast zone.
     ///
@@ -224,7 +227,7 @@ pub async fn handle_command(
             }
             Ok(())
         }
-        ZoneCommands::Forecast { zone_args } => {
+        ZoneCommands::Forecast { zone_args, compact } => {
             let result = zones_api::get_current_zone_forecast(
                 config,
                 &zone_args.r#type.to_string(),
@@ -245,7 +248,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::zones::create_zone_forecast_table(&result);
+                let table = tables::zones::create_zone_forecast_table(&result, *compact, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -262,7 +265,7 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::stations::create_stations_table(&result);
+                let table = tables::stations::create_stations_table(&result, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -289,7 +292,8 @@ pub async fn handle_command(
                     &serde_json::to_string_pretty(&result)?,
                 )?;
             } else {
-                let table = tables::zones::create_zone_observations_table(&result.features);
+                let table =
+                    tables::zones::create_zone_observations_table(&result.features, cli.units);
                 write_output(cli.output.as_deref(), &table.to_string())?;
             }
             Ok(())
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use clap::Parser;
 use noaa_weather_client::apis::configuration::Configuration;
+use utils::units::UnitSystem;
 
 mod commands;
 mod tables;
@@ -30,6 +31,10 @@ struct Cli {
     /// Output file path
     #[arg(short, long, global = true)]
     output: Option<String>,
+
+    /// Unit system for displayed values (defaults to the units returned by the API)
+    #[arg(long, value_enum, global = true, env = "NOAA_WEATHER_UNITS")]
+    units: Option<UnitSystem>,
 }
 
 #[tokio::main]
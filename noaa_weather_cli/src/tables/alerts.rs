@@ -4,6 +4,7 @@ use noaa_weather_client::models::{
     ActiveAlertsCountResponse, Alert, AlertCollectionGeoJson, AlertGeoJson, AlertSeverity,
     AlertTypesResponse,
 };
+use noaa_weather_client::same;
 
 use crate::utils::format::{
     format_datetime_human_readable, format_optional_number, get_zone_from_url,
@@ -229,6 +230,10 @@ pub fn create_single_alert_table(alert_data: &AlertGeoJson) -> comfy_table::Tabl
     );
 
     details.push(format!("Affected Zones: {formatted_affected_zones}"));
+    details.push(format!(
+        "Affected Counties: {}",
+        format_affected_counties(alert)
+    ));
     let description = alert
         .description
         .clone()
@@ -238,6 +243,24 @@ pub fn create_single_alert_table(alert_data: &AlertGeoJson) -> comfy_table::Tabl
     table
 }
 
+/// Names the counties covered by an alert's SAME codes, falling back to the raw
+/// code for any county missing from the bundled table.
+fn format_affected_counties(alert: &Alert) -> String {
+    let Some(same_codes) = alert
+        .geocode
+        .as_ref()
+        .and_then(|geocode| geocode.same.as_ref())
+        .filter(|same_codes| !same_codes.is_empty())
+    else {
+        return "N/A".to_owned();
+    };
+    same_codes
+        .iter()
+        .map(|code| same::lookup(code).map_or_else(|| code.clone(), |area| area.to_string()))
+        .collect::<Vec<String>>()
+        .join("; ")
+}
+
 /// Formats the active alerts count into a comfy table.
 pub fn create_alert_count_table(count_data: &ActiveAlertsCountResponse) -> comfy_table::Table {
     let mut table = Table::new();
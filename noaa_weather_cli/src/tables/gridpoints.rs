@@ -1,4 +1,7 @@
-use crate::utils::format::{format_datetime_human_readable, format_dewpoint};
+use crate::utils::format::{format_datetime_human_readable, format_forecast_with_icon};
+use crate::utils::units::{
+    Quantity, Unit, UnitSystem, convert_speed_text, convert_to_system, format_quantitative_value,
+};
 use comfy_table::presets::UTF8_FULL_CONDENSED;
 use comfy_table::{Cell, CellAlignment, ContentArrangement, Table};
 use noaa_weather_client::models::{
@@ -15,7 +18,10 @@ macro_rules! add_row_if_some {
 }
 
 /// Formats raw gridpoint data into a `comfy_table::Table`.
-pub fn create_gridpoint_table(gridpoint_data: &GridpointGeoJson) -> Table {
+pub fn create_gridpoint_table(
+    gridpoint_data: &GridpointGeoJson,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL_CONDENSED);
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -29,31 +35,20 @@ pub fn create_gridpoint_table(gridpoint_data: &GridpointGeoJson) -> Table {
     add_row_if_some!(table, "Grid Y", props.grid_y);
     add_row_if_some!(table, "Update Time", props.update_time);
 
-    // Add elevation if available
-    let elevation_str = props
-        .elevation
-        .as_ref()
-        .and_then(|qv| qv.value)
-        .flatten()
-        .map(|v| {
-            format!(
-                "{:.1} {}",
-                v,
-                {
-                    let qv = props.elevation.as_ref();
-                    qv.unwrap().unit_code.as_deref()
-                }
-                .unwrap_or("m")
-            )
-        })
-        .unwrap_or_else(|| "N/A".to_owned());
+    let elevation_str = props.elevation.as_ref().map_or_else(
+        || "N/A".to_owned(),
+        |elevation| format_quantitative_value(elevation, Quantity::Height, units),
+    );
     table.add_row(vec!["Elevation", &elevation_str]);
 
     table
 }
 
 /// Formats the multi-day 12-hour forecast into a comfy table.
-pub fn create_forecast_table(forecast_data: &Gridpoint12hForecastGeoJson) -> Table {
+pub fn create_forecast_table(
+    forecast_data: &Gridpoint12hForecastGeoJson,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL_CONDENSED);
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -76,6 +71,7 @@ pub fn create_forecast_table(forecast_data: &Gridpoint12hForecastGeoJson) -> Tab
                             })
                             .as_deref()
                             .or(default_unit.as_deref()),
+                        units,
                     )
                 },
             );
@@ -86,7 +82,7 @@ pub fn create_forecast_table(forecast_data: &Gridpoint12hForecastGeoJson) -> Tab
                     .wind_speed
                     .as_ref()
                     .map(|gridpoint_forecast_period_wind_speed| {
-                        gridpoint_forecast_period_wind_speed.to_string()
+                        convert_speed_text(&gridpoint_forecast_period_wind_speed.to_string(), units)
                     })
                     .unwrap_or_else(|| "N/A".to_owned()),
                 period
@@ -105,7 +101,10 @@ pub fn create_forecast_table(forecast_data: &Gridpoint12hForecastGeoJson) -> Tab
                 &format!("{start_time_formatted} to {end_time_formatted}"),
                 &temp_str,
                 &wind_str.trim(),
-                period.short_forecast.as_deref().unwrap_or("-"),
+                &format_forecast_with_icon(
+                    period.icon.as_deref(),
+                    period.short_forecast.as_deref(),
+                ),
             ]);
         }
     } else {
@@ -120,7 +119,10 @@ pub fn create_forecast_table(forecast_data: &Gridpoint12hForecastGeoJson) -> Tab
 }
 
 /// Formats the hourly forecast into a comfy table.
-pub fn create_hourly_forecast_table(forecast_data: &GridpointHourlyForecastGeoJson) -> Table {
+pub fn create_hourly_forecast_table(
+    forecast_data: &GridpointHourlyForecastGeoJson,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL_CONDENSED);
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -145,23 +147,17 @@ pub fn create_hourly_forecast_table(forecast_data: &GridpointHourlyForecastGeoJs
                             })
                             .as_deref()
                             .or(default_unit.as_deref()),
+                        units,
                     )
                 },
             );
 
-            let dewpoint_str = period
-                .dewpoint
-                .as_ref()
-                .and_then(|quantitative_value| {
-                    quantitative_value.value.flatten().map(|value| {
-                        format_dewpoint(
-                            value.to_string(),
-                            quantitative_value.unit_code.as_deref(),
-                            None,
-                        )
-                    })
-                })
-                .unwrap_or_else(|| "N/A".to_owned());
+            let dewpoint_str = period.dewpoint.as_ref().map_or_else(
+                || "N/A".to_owned(),
+                |quantitative_value| {
+                    format_quantitative_value(quantitative_value, Quantity::Temperature, units)
+                },
+            );
 
             let precip_str = period.probability_of_precipitation.as_ref().map_or_else(
                 || "N/A".to_owned(),
@@ -189,7 +185,7 @@ pub fn create_hourly_forecast_table(forecast_data: &GridpointHourlyForecastGeoJs
                     .wind_speed
                     .as_ref()
                     .map(|gridpoint_forecast_period_wind_speed| {
-                        gridpoint_forecast_period_wind_speed.to_string()
+                        convert_speed_text(&gridpoint_forecast_period_wind_speed.to_string(), units)
                     })
                     .unwrap_or_else(|| "N/A".to_owned()),
                 period
@@ -210,7 +206,10 @@ pub fn create_hourly_forecast_table(forecast_data: &GridpointHourlyForecastGeoJs
                 &precip_str,
                 &humidity_str,
                 wind_str.trim(),
-                period.short_forecast.as_deref().unwrap_or("-"),
+                &format_forecast_with_icon(
+                    period.icon.as_deref(),
+                    period.short_forecast.as_deref(),
+                ),
             ]);
         }
     } else {
@@ -225,22 +224,26 @@ pub fn create_hourly_forecast_table(forecast_data: &GridpointHourlyForecastGeoJs
 }
 
 // Helper to format temperature (which can be QuantitativeValue or Integer)
-fn format_temperature(temp: &GridpointForecastPeriodTemperature, unit: Option<&str>) -> String {
+fn format_temperature(
+    temp: &GridpointForecastPeriodTemperature,
+    unit: Option<&str>,
+    units: Option<UnitSystem>,
+) -> String {
     match temp {
         GridpointForecastPeriodTemperature::QuantitativeValue(qv) => {
-            let value_str = qv
-                .value
-                .flatten()
-                .map_or_else(|| "N/A".to_owned(), |value| format!("{value:.0}"));
-            let unit_str = qv.unit_code.as_deref().unwrap_or(unit.unwrap_or("?"));
-            format!(
-                "{}\u{b0}{}",
-                value_str,
-                unit_str.split(':').next_back().unwrap_or(unit_str)
-            )
+            format_quantitative_value(qv, Quantity::Temperature, units)
         }
         GridpointForecastPeriodTemperature::Integer(i) => {
-            format!("{}\u{b0}{}", i, unit.unwrap_or("?"))
+            let converted =
+                units
+                    .zip(unit.and_then(Unit::from_code))
+                    .and_then(|(units, source_unit)| {
+                        convert_to_system(f64::from(*i), source_unit, Quantity::Temperature, units)
+                    });
+            match converted {
+                Some((value, target_unit)) => format!("{value:.0}{}", target_unit.label()),
+                None => format!("{}\u{b0}{}", i, unit.unwrap_or("?")),
+            }
         }
     }
 }
@@ -1,8 +1,13 @@
 use comfy_table::presets::UTF8_FULL_CONDENSED;
 use comfy_table::{Cell, CellAlignment, ContentArrangement, Table};
+use noaa_weather_client::afd::AreaForecastDiscussion;
+use noaa_weather_client::climate::{ClimateReport, ClimateValue, TRACE};
+use noaa_weather_client::lsr::StormReport;
 use noaa_weather_client::models::{
     TextProduct, TextProductCollection, TextProductLocationCollection, TextProductTypeCollection,
 };
+use noaa_weather_client::product_diff::{ProductDiff, SectionChangeKind};
+use noaa_weather_client::product_search::ProductMatch;
 
 use crate::utils::format::format_datetime_human_readable;
 
@@ -157,3 +162,282 @@ pub fn create_products_locations_table(product_locations: &TextProductLocationCo
 
     table
 }
+
+/// Formats an `AreaForecastDiscussion` into a `comfy_table::Table` with one row per section.
+pub fn create_discussion_table(discussion: &AreaForecastDiscussion) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Section")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Text")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+    ]);
+
+    table.add_row(vec![
+        Cell::new("Office"),
+        Cell::new(discussion.office.as_deref().unwrap_or("N/A")),
+    ]);
+    table.add_row(vec![
+        Cell::new("Issued"),
+        Cell::new(discussion.issuance_time.as_deref().unwrap_or("N/A")),
+    ]);
+    for section in &discussion.sections {
+        let heading = match &section.forecaster {
+            Some(forecaster) => format!("{}\n({forecaster})", section.heading),
+            None => section.heading.clone(),
+        };
+        table.add_row(vec![
+            Cell::new(heading).add_attribute(comfy_table::Attribute::Bold),
+            Cell::new(&section.text),
+        ]);
+    }
+    if !discussion.forecasters.is_empty() {
+        table.add_row(vec![
+            Cell::new("Forecasters"),
+            Cell::new(discussion.forecasters.join(", ")),
+        ]);
+    }
+
+    table
+}
+
+/// Formats the issuances and section changes of a product diff into a `comfy_table::Table`.
+pub fn create_product_diff_table(diff: &ProductDiff) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Section")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Change")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+    ]);
+
+    let issuance = |time: Option<&str>, id: Option<&str>| {
+        format!(
+            "{} ({})",
+            format_datetime_human_readable(time),
+            id.unwrap_or("N/A")
+        )
+    };
+    table.add_row(vec![
+        Cell::new("Older"),
+        Cell::new(issuance(
+            diff.older_issuance_time.as_deref(),
+            diff.older_id.as_deref(),
+        )),
+    ]);
+    table.add_row(vec![
+        Cell::new("Newer"),
+        Cell::new(issuance(
+            diff.newer_issuance_time.as_deref(),
+            diff.newer_id.as_deref(),
+        )),
+    ]);
+    for section in &diff.sections {
+        let change = match section.change {
+            SectionChangeKind::Unchanged => section.change.to_string(),
+            _ => format!(
+                "{} (+{} -{})",
+                section.change, section.added_lines, section.removed_lines
+            ),
+        };
+        table.add_row(vec![
+            Cell::new(&section.name).add_attribute(comfy_table::Attribute::Bold),
+            Cell::new(change),
+        ]);
+    }
+
+    table
+}
+
+/// Formats product search results into a `comfy_table::Table` with one row per matching snippet.
+pub fn create_product_search_table(matches: &[ProductMatch]) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Issued")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Office")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Type")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Line")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Text")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+    ]);
+
+    if matches.is_empty() {
+        table.add_row(vec![
+            Cell::new("No matching products found.")
+                .add_attribute(comfy_table::Attribute::Italic)
+                .set_alignment(CellAlignment::Center),
+        ]);
+    }
+    for product_match in matches {
+        for snippet in &product_match.snippets {
+            table.add_row(vec![
+                Cell::new(format_datetime_human_readable(
+                    product_match.issuance_time.as_deref(),
+                )),
+                Cell::new(product_match.issuing_office.as_deref().unwrap_or("N/A")),
+                Cell::new(product_match.product_code.as_deref().unwrap_or("N/A")),
+                Cell::new(snippet.line_number).set_alignment(CellAlignment::Right),
+                Cell::new(&snippet.text),
+            ]);
+        }
+    }
+
+    table
+}
+
+/// Formats storm reports into a `comfy_table::Table` with one row per report.
+pub fn create_storm_reports_table(reports: &[StormReport]) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Time")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Event")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Magnitude")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Location")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("County")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Source")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Remarks")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+    ]);
+
+    for report in reports {
+        let time = report.time.map_or_else(
+            || report.local_time.to_string(),
+            |time| format_datetime_human_readable(Some(&time.to_string())),
+        );
+        table.add_row(vec![
+            Cell::new(time),
+            Cell::new(&report.event),
+            Cell::new(
+                report
+                    .magnitude
+                    .as_ref()
+                    .map_or_else(|| "N/A".to_owned(), ToString::to_string),
+            ),
+            Cell::new(&report.location),
+            Cell::new(format!("{}, {}", report.county, report.state)),
+            Cell::new(&report.source),
+            Cell::new(report.remarks.as_deref().unwrap_or("")),
+        ]);
+    }
+
+    table
+}
+
+/// Formats a `ClimateReport` into a `comfy_table::Table` with one row per climate item.
+pub fn create_climate_report_table(report: &ClimateReport) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    let title = format!(
+        "{} {}",
+        report.station.as_deref().unwrap_or("N/A"),
+        report
+            .date
+            .map_or_else(|| "N/A".to_owned(), |date| date.to_string())
+    );
+    table.set_header(vec![
+        Cell::new(title)
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Observed")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Time")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Record (Year)")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Normal")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Departure")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Last Year")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+    ]);
+
+    let sections = [
+        ("Temperature (F)", &report.temperature),
+        ("Precipitation (in)", &report.precipitation),
+        ("Snowfall (in)", &report.snowfall),
+        ("Heating Degree Days", &report.heating_degree_days),
+        ("Cooling Degree Days", &report.cooling_degree_days),
+    ];
+    for (name, values) in sections {
+        if values.is_empty() {
+            continue;
+        }
+        table.add_row(vec![
+            Cell::new(name).add_attribute(comfy_table::Attribute::Bold),
+        ]);
+        for value in values {
+            table.add_row(climate_value_row(value));
+        }
+    }
+
+    table
+}
+
+/// Builds the table row for a climate report value.
+fn climate_value_row(value: &ClimateValue) -> Vec<Cell> {
+    let format = |number: Option<f64>| match number {
+        Some(TRACE) => "T".to_owned(),
+        Some(number) => number.to_string(),
+        None => "MM".to_owned(),
+    };
+    let mut observed = format(value.observed);
+    if value.record_set {
+        observed.push_str(" (record)");
+    }
+    let record = match (value.record, value.record_year) {
+        (None, None) => String::new(),
+        (record, Some(year)) => format!("{} ({year})", format(record)),
+        (record, None) => format(record),
+    };
+    vec![
+        Cell::new(format!("  {}", value.label)),
+        Cell::new(observed),
+        Cell::new(value.time.as_deref().unwrap_or("")),
+        Cell::new(record),
+        Cell::new(format(value.normal)),
+        Cell::new(format(value.departure)),
+        Cell::new(format(value.last_year)),
+    ]
+}
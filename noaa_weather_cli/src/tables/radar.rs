@@ -4,8 +4,8 @@ use comfy_table::presets::UTF8_FULL_CONDENSED;
 use comfy_table::{Attribute, Cell, CellAlignment, ContentArrangement, Table};
 use noaa_weather_client::models::radar_server::RadarServerNetworkInterfaceStats;
 use noaa_weather_client::models::{
-    RadarQueuesResponse, RadarServer, RadarServersResponse, RadarStationAlarmsResponse,
-    RadarStationFeature, RadarStationsResponse,
+    QuantitativeValue, RadarQueuesResponse, RadarServer, RadarServersResponse,
+    RadarStationAlarmsResponse, RadarStationFeature, RadarStationsResponse, RadarWindProfiler,
 };
 
 use crate::utils::format::{
@@ -13,6 +13,7 @@ use crate::utils::format::{
     format_optional_f64_display, format_optional_f64_precise, format_optional_i32,
     format_optional_i64, format_optional_string, format_optional_value_unit,
 };
+use crate::utils::units::{Quantity, UnitSystem, format_quantitative_value, format_value_unit};
 
 // --- Helper Functions ---
 // These are kept private to this module as they are specific to formatting radar station data.
@@ -110,7 +111,10 @@ fn add_network_interface_stats_rows(
 ///
 /// A `Result<Table>` which is the `comfy_table::Table` ready for printing,
 /// or an error if table creation fails (though current implementation always returns Ok).
-pub fn create_radar_station_feature_table(radar_station_feature: &RadarStationFeature) -> Table {
+pub fn create_radar_station_feature_table(
+    radar_station_feature: &RadarStationFeature,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL_CONDENSED);
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -154,7 +158,11 @@ pub fn create_radar_station_feature_table(radar_station_feature: &RadarStationFe
         ]);
         table.add_row(vec![
             Cell::new("Elevation"),
-            Cell::new(format_optional_value_unit(&station.elevation)),
+            Cell::new(format_value_unit(
+                &station.elevation,
+                Quantity::Height,
+                units,
+            )),
         ]);
         table.add_row(vec![
             Cell::new("Time Zone"),
@@ -300,12 +308,18 @@ pub fn create_radar_station_feature_table(radar_station_feature: &RadarStationFe
                 ]);
                 table.add_row(vec![
                     Cell::new("Shelter Temp."),
-                    Cell::new(format_optional_value_unit(&perf_props.shelter_temperature)),
+                    Cell::new(format_value_unit(
+                        &perf_props.shelter_temperature,
+                        Quantity::Temperature,
+                        units,
+                    )),
                 ]);
                 table.add_row(vec![
                     Cell::new("Radome Air Temp."),
-                    Cell::new(format_optional_value_unit(
+                    Cell::new(format_value_unit(
                         &perf_props.radome_air_temperature,
+                        Quantity::Temperature,
+                        units,
                     )),
                 ]);
                 table.add_row(vec![
@@ -370,7 +384,10 @@ pub fn create_radar_station_feature_table(radar_station_feature: &RadarStationFe
     table
 }
 
-pub fn create_radar_stations_table(radar_stations: &RadarStationsResponse) -> Table {
+pub fn create_radar_stations_table(
+    radar_stations: &RadarStationsResponse,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL_CONDENSED);
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -398,7 +415,11 @@ pub fn create_radar_stations_table(radar_stations: &RadarStationsResponse) -> Ta
                 Cell::new(format_optional_string(&station.id)),
                 Cell::new(format_optional_string(&station.name)),
                 Cell::new(format_optional_string(&station.station_type)),
-                Cell::new(format_optional_value_unit(&station.elevation)),
+                Cell::new(format_value_unit(
+                    &station.elevation,
+                    Quantity::Height,
+                    units,
+                )),
                 Cell::new(format_optional_string(&station.time_zone)),
             ]);
         }
@@ -842,3 +863,90 @@ pub fn create_radar_servers_table(radar_servers_response: &RadarServersResponse)
 
     table
 }
+
+/// Creates a table showing a radar wind profile, one row per measured height.
+///
+/// Levels are listed from the lowest height up, beneath a row identifying the
+/// station and measurement time.
+pub fn create_radar_wind_profiler_table(
+    profiler: &RadarWindProfiler,
+    units: Option<UnitSystem>,
+) -> Table {
+    let mut table = Table::new();
+    table.load_preset(UTF8_FULL_CONDENSED);
+    table.set_content_arrangement(ContentArrangement::Dynamic);
+    table.set_header(vec![
+        Cell::new("Height")
+            .add_attribute(Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Wind Direction")
+            .add_attribute(Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+        Cell::new("Wind Speed")
+            .add_attribute(Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
+    ]);
+
+    let station = profiler
+        .station_id
+        .as_deref()
+        .or(profiler.name.as_deref())
+        .unwrap_or("N/A");
+    table.add_row(vec![
+        Cell::new(format!("Station: {station}")).add_attribute(Attribute::Bold),
+        Cell::new(format!(
+            "Time: {}",
+            format_datetime_human_readable(profiler.time.as_deref())
+        ))
+        .add_attribute(Attribute::Bold),
+        Cell::new(""),
+    ]);
+
+    let levels = profiler.levels_by_height();
+    if levels.is_empty() {
+        table.add_row(vec![
+            Cell::new("No profile levels found.")
+                .add_attribute(Attribute::Bold)
+                .set_alignment(CellAlignment::Center),
+        ]);
+    }
+    for level in levels {
+        table.add_row(vec![
+            Cell::new(format_optional_quantity(
+                &level.height,
+                Quantity::Height,
+                units,
+            ))
+            .set_alignment(CellAlignment::Right),
+            Cell::new(format_wind_direction(&level.wind_direction))
+                .set_alignment(CellAlignment::Right),
+            Cell::new(format_optional_quantity(
+                &level.wind_speed,
+                Quantity::Speed,
+                units,
+            ))
+            .set_alignment(CellAlignment::Right),
+        ]);
+    }
+    table
+}
+
+/// Formats an optional `QuantitativeValue`, using "N/A" when it is missing.
+fn format_optional_quantity(
+    value: &Option<QuantitativeValue>,
+    quantity: Quantity,
+    units: Option<UnitSystem>,
+) -> String {
+    value.as_ref().map_or_else(
+        || "N/A".to_owned(),
+        |value| format_quantitative_value(value, quantity, units),
+    )
+}
+
+/// Formats a wind direction in whole degrees (e.g., "230°").
+fn format_wind_direction(direction: &Option<QuantitativeValue>) -> String {
+    direction
+        .as_ref()
+        .and_then(|direction| direction.value.flatten())
+        .map_or_else(|| "N/A".to_owned(), |degrees| format!("{degrees:.0}\u{b0}"))
+}
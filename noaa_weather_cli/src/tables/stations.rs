@@ -1,6 +1,7 @@
 use comfy_table::presets::{UTF8_FULL, UTF8_FULL_CONDENSED};
-use comfy_table::{Attribute, Cell, CellAlignment, ContentArrangement, Table};
+use comfy_table::{Attribute, Cell, CellAlignment, Color, ContentArrangement, Table};
 use jiff::Timestamp;
+use noaa_weather_client::flight_category::FlightCategory;
 use noaa_weather_client::models::terminal_aerodrome_forecast::{
     BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayer,
     BaseForecastMeteorologicalAerodromeForecastPrevailingVisibility,
@@ -14,10 +15,14 @@ use noaa_weather_client::models::{
     ObservationStationCollectionGeoJson, ObservationStationGeoJson, TerminalAerodromeForecast,
     TerminalAerodromeForecastsResponse,
 };
+use noaa_weather_client::taf::{
+    DecodedTaf, TafChangeKind, TafConditions, change_forecast_flight_categories,
+};
 
 use crate::utils::format::{
     format_datetime_human_readable, format_optional_value_unit, get_zone_from_url,
 };
+use crate::utils::units::{Quantity, Unit, UnitSystem, format_measurement, format_value_unit};
 
 /// Creates a table listing all observation stations with key summary information.
 ///
@@ -25,7 +30,10 @@ use crate::utils::format::{
 /// and formats them into a table. Each row represents a station, displaying its ID, name,
 /// elevation, and time zone.
 ///
-pub fn create_stations_table(station_data: &ObservationStationCollectionGeoJson) -> Table {
+pub fn create_stations_table(
+    station_data: &ObservationStationCollectionGeoJson,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -36,7 +44,7 @@ pub fn create_stations_table(station_data: &ObservationStationCollectionGeoJson)
         Cell::new("Name")
             .add_attribute(comfy_table::Attribute::Bold)
             .set_alignment(CellAlignment::Center),
-        Cell::new("Elevation (m)")
+        Cell::new("Elevation")
             .add_attribute(comfy_table::Attribute::Bold)
             .set_alignment(CellAlignment::Center),
         Cell::new("Time Zone")
@@ -51,7 +59,7 @@ pub fn create_stations_table(station_data: &ObservationStationCollectionGeoJson)
     ]);
 
     for feature in &station_data.features {
-        table.add_row(create_station_row(feature));
+        table.add_row(create_station_row(feature, units));
     }
 
     table
@@ -63,7 +71,10 @@ pub fn create_stations_table(station_data: &ObservationStationCollectionGeoJson)
 /// and formats it into a table. Each row represents a station, displaying its ID, name,
 /// elevation, and time zone.
 ///
-pub fn create_observation_station_table(observation_station: &ObservationStationGeoJson) -> Table {
+pub fn create_observation_station_table(
+    observation_station: &ObservationStationGeoJson,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL_CONDENSED);
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -74,7 +85,7 @@ pub fn create_observation_station_table(observation_station: &ObservationStation
         Cell::new("Name")
             .add_attribute(comfy_table::Attribute::Bold)
             .set_alignment(CellAlignment::Center),
-        Cell::new("Elevation (m)")
+        Cell::new("Elevation")
             .add_attribute(comfy_table::Attribute::Bold)
             .set_alignment(CellAlignment::Center),
         Cell::new("Time Zone")
@@ -88,7 +99,7 @@ pub fn create_observation_station_table(observation_station: &ObservationStation
             .set_alignment(CellAlignment::Center),
     ]);
 
-    table.add_row(create_station_row(observation_station));
+    table.add_row(create_station_row(observation_station, units));
 
     table
 }
@@ -99,7 +110,10 @@ pub fn create_observation_station_table(observation_station: &ObservationStation
 /// and formats it into a table. Each row represents a station, displaying its ID, name,
 /// elevation, and time zone.
 ///
-pub fn create_stations_observation_table(observation: &ObservationGeoJson) -> Table {
+pub fn create_stations_observation_table(
+    observation: &ObservationGeoJson,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL_CONDENSED);
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -131,12 +145,20 @@ pub fn create_stations_observation_table(observation: &ObservationGeoJson) -> Ta
 
     table.add_row(vec![
         Cell::new("Temperature").add_attribute(comfy_table::Attribute::Bold),
-        Cell::new(format_optional_value_unit(&props.temperature)),
+        Cell::new(format_value_unit(
+            &props.temperature,
+            Quantity::Temperature,
+            units,
+        )),
     ]);
 
     table.add_row(vec![
         Cell::new("Dewpoint").add_attribute(comfy_table::Attribute::Bold),
-        Cell::new(format_optional_value_unit(&props.dewpoint)),
+        Cell::new(format_value_unit(
+            &props.dewpoint,
+            Quantity::Temperature,
+            units,
+        )),
     ]);
 
     table.add_row(vec![
@@ -146,27 +168,53 @@ pub fn create_stations_observation_table(observation: &ObservationGeoJson) -> Ta
 
     table.add_row(vec![
         Cell::new("Wind Speed").add_attribute(comfy_table::Attribute::Bold),
-        Cell::new(format_optional_value_unit(&props.wind_speed)),
+        Cell::new(format_value_unit(&props.wind_speed, Quantity::Speed, units)),
     ]);
 
     table.add_row(vec![
         Cell::new("Wind Gust").add_attribute(comfy_table::Attribute::Bold),
-        Cell::new(format_optional_value_unit(&props.wind_gust)),
+        Cell::new(format_value_unit(&props.wind_gust, Quantity::Speed, units)),
     ]);
 
     table.add_row(vec![
         Cell::new("Barometric Pressure").add_attribute(comfy_table::Attribute::Bold),
-        Cell::new(format_optional_value_unit(&props.barometric_pressure)),
+        Cell::new(format_value_unit(
+            &props.barometric_pressure,
+            Quantity::Pressure,
+            units,
+        )),
     ]);
 
     table.add_row(vec![
         Cell::new("Sea Level Pressure").add_attribute(comfy_table::Attribute::Bold),
-        Cell::new(format_optional_value_unit(&props.sea_level_pressure)),
+        Cell::new(format_value_unit(
+            &props.sea_level_pressure,
+            Quantity::Pressure,
+            units,
+        )),
     ]);
 
     table.add_row(vec![
         Cell::new("Visibility").add_attribute(comfy_table::Attribute::Bold),
-        Cell::new(format_optional_value_unit(&props.visibility)),
+        Cell::new(format_value_unit(
+            &props.visibility,
+            Quantity::Visibility,
+            units,
+        )),
+    ]);
+
+    table.add_row(vec![
+        Cell::new("Flight Category").add_attribute(comfy_table::Attribute::Bold),
+        flight_category_cell(props.flight_category()),
+    ]);
+
+    table.add_row(vec![
+        Cell::new("Precipitation (Last Hour)").add_attribute(comfy_table::Attribute::Bold),
+        Cell::new(format_value_unit(
+            &props.precipitation_last_hour,
+            Quantity::Precipitation,
+            units,
+        )),
     ]);
 
     table.add_row(vec![
@@ -176,12 +224,20 @@ pub fn create_stations_observation_table(observation: &ObservationGeoJson) -> Ta
 
     table.add_row(vec![
         Cell::new("Wind Chill").add_attribute(comfy_table::Attribute::Bold),
-        Cell::new(format_optional_value_unit(&props.wind_chill)),
+        Cell::new(format_value_unit(
+            &props.wind_chill,
+            Quantity::Temperature,
+            units,
+        )),
     ]);
 
     table.add_row(vec![
         Cell::new("Heat Index").add_attribute(comfy_table::Attribute::Bold),
-        Cell::new(format_optional_value_unit(&props.heat_index)),
+        Cell::new(format_value_unit(
+            &props.heat_index,
+            Quantity::Temperature,
+            units,
+        )),
     ]);
 
     table
@@ -193,7 +249,10 @@ pub fn create_stations_observation_table(observation: &ObservationGeoJson) -> Ta
 /// and formats it into a table. Each row represents a station, displaying its ID, name,
 /// elevation, and time zone.
 ///
-pub fn create_stations_observations_table(observations: &ObservationCollectionGeoJson) -> Table {
+pub fn create_stations_observations_table(
+    observations: &ObservationCollectionGeoJson,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -226,6 +285,9 @@ pub fn create_stations_observations_table(observations: &ObservationCollectionGe
         Cell::new("Visibility")
             .add_attribute(comfy_table::Attribute::Bold)
             .set_alignment(CellAlignment::Center),
+        Cell::new("Flight Category")
+            .add_attribute(comfy_table::Attribute::Bold)
+            .set_alignment(CellAlignment::Center),
         Cell::new("Relative Humidity")
             .add_attribute(comfy_table::Attribute::Bold)
             .set_alignment(CellAlignment::Center),
@@ -241,20 +303,48 @@ pub fn create_stations_observations_table(observations: &ObservationCollectionGe
     for observation in observations_features {
         let timestamp_str =
             format_datetime_human_readable(observation.properties.timestamp.as_deref());
-        let temperature_str = format_optional_value_unit(&observation.properties.temperature);
-        let dewpoint_str = format_optional_value_unit(&observation.properties.dewpoint);
+        let temperature_str = format_value_unit(
+            &observation.properties.temperature,
+            Quantity::Temperature,
+            units,
+        );
+        let dewpoint_str = format_value_unit(
+            &observation.properties.dewpoint,
+            Quantity::Temperature,
+            units,
+        );
         let wind_direction_str = format_optional_value_unit(&observation.properties.wind_direction);
-        let wind_speed_str = format_optional_value_unit(&observation.properties.wind_speed);
-        let wind_gust_str = format_optional_value_unit(&observation.properties.wind_gust);
-        let barometric_pressure_str =
-            format_optional_value_unit(&observation.properties.barometric_pressure);
-        let sea_level_pressure_str =
-            format_optional_value_unit(&observation.properties.sea_level_pressure);
-        let visibility_str = format_optional_value_unit(&observation.properties.visibility);
+        let wind_speed_str =
+            format_value_unit(&observation.properties.wind_speed, Quantity::Speed, units);
+        let wind_gust_str =
+            format_value_unit(&observation.properties.wind_gust, Quantity::Speed, units);
+        let barometric_pressure_str = format_value_unit(
+            &observation.properties.barometric_pressure,
+            Quantity::Pressure,
+            units,
+        );
+        let sea_level_pressure_str = format_value_unit(
+            &observation.properties.sea_level_pressure,
+            Quantity::Pressure,
+            units,
+        );
+        let visibility_str = format_value_unit(
+            &observation.properties.visibility,
+            Quantity::Visibility,
+            units,
+        );
         let relative_humidity_str =
             format_optional_value_unit(&observation.properties.relative_humidity);
-        let wind_chill_str = format_optional_value_unit(&observation.properties.wind_chill);
-        let heat_index_str = format_optional_value_unit(&observation.properties.heat_index);
+        let wind_chill_str = format_value_unit(
+            &observation.properties.wind_chill,
+            Quantity::Temperature,
+            units,
+        );
+        let heat_index_str = format_value_unit(
+            &observation.properties.heat_index,
+            Quantity::Temperature,
+            units,
+        );
 
         table.add_row(vec![
             Cell::new(timestamp_str),
@@ -266,6 +356,7 @@ pub fn create_stations_observations_table(observations: &ObservationCollectionGe
             Cell::new(barometric_pressure_str),
             Cell::new(sea_level_pressure_str),
             Cell::new(visibility_str),
+            flight_category_cell(observation.properties.flight_category()),
             Cell::new(relative_humidity_str),
             Cell::new(wind_chill_str),
             Cell::new(heat_index_str),
@@ -388,6 +479,7 @@ impl VisibilityDataProvider for ChangeForecastMeteorologicalAerodromeForecastPre
 fn format_visibility_generic<T: VisibilityDataProvider>(
     vis_data_opt: Option<&T>,
     operator_opt: Option<&String>,
+    units: Option<UnitSystem>,
 ) -> String {
     match vis_data_opt {
         Some(vis_data) => {
@@ -395,7 +487,27 @@ fn format_visibility_generic<T: VisibilityDataProvider>(
             let uom = vis_data.uom_val();
 
             if (value_str == "10000" || value_str == "9999") && uom == "m" {
-                return "10+ km (6+ mi)".to_owned();
+                return match units.map(|units| units.unit_for(Quantity::Visibility)) {
+                    Some(Unit::Miles) => "6+ mi".to_owned(),
+                    Some(_) => "10+ km".to_owned(),
+                    None => "10+ km (6+ mi)".to_owned(),
+                };
+            }
+
+            if let (Some(units), Ok(value)) = (units, value_str.parse::<f64>()) {
+                let mut display_str = String::new();
+                if operator_opt.is_some_and(|op_str| op_str == "ABOVE") {
+                    display_str.push('\u{2265}');
+                } else if operator_opt.is_some_and(|op_str| op_str == "BELOW") {
+                    display_str.push('\u{2264}');
+                }
+                display_str.push_str(&format_measurement(
+                    value,
+                    uom,
+                    Quantity::Visibility,
+                    Some(units),
+                ));
+                return display_str;
             }
 
             let mut display_str = String::new();
@@ -490,7 +602,10 @@ impl WindDataProvider
     }
 }
 
-fn format_wind_generic<T: WindDataProvider>(wind_data_opt: Option<&T>) -> String {
+fn format_wind_generic<T: WindDataProvider>(
+    wind_data_opt: Option<&T>,
+    units: Option<UnitSystem>,
+) -> String {
     wind_data_opt.map_or_else(
         || "N/A".to_owned(),
         |wind_data| {
@@ -502,25 +617,19 @@ fn format_wind_generic<T: WindDataProvider>(wind_data_opt: Option<&T>) -> String
             }
 
             if let Some(speed_val) = wind_data.mean_wind_speed_val() {
-                let speed_uom = wind_data
-                    .mean_wind_speed_uom()
-                    .unwrap_or("")
-                    .replace("[kn_i]", "kts");
+                let speed_uom = wind_data.mean_wind_speed_uom().unwrap_or("");
                 if !parts.is_empty() && parts.last().is_some_and(|part| part != "at") {
                     parts.push("at".to_owned());
                 } else if parts.is_empty() {
                     parts.push("Wind".to_owned());
                 }
-                parts.push(format!("{speed_val} {speed_uom}"));
+                parts.push(format_taf_speed(speed_val, speed_uom, units));
             }
 
             if let Some(gust_val) = wind_data.wind_gust_speed_val() {
-                let gust_uom = wind_data
-                    .wind_gust_speed_uom()
-                    .unwrap_or("")
-                    .replace("[kn_i]", "kts");
+                let gust_uom = wind_data.wind_gust_speed_uom().unwrap_or("");
                 parts.push("gusting".to_owned());
-                parts.push(format!("{gust_val} {gust_uom}"));
+                parts.push(format_taf_speed(gust_val, gust_uom, units));
             }
 
             if parts
@@ -536,6 +645,16 @@ fn format_wind_generic<T: WindDataProvider>(wind_data_opt: Option<&T>) -> String
     )
 }
 
+/// Formats a TAF wind speed, converting it to the selected unit system when possible.
+fn format_taf_speed(speed_val: &str, speed_uom: &str, units: Option<UnitSystem>) -> String {
+    match (units, speed_val.parse::<f64>()) {
+        (Some(units), Ok(speed)) => {
+            format_measurement(speed, speed_uom, Quantity::Speed, Some(units))
+        }
+        _ => format!("{speed_val} {}", speed_uom.replace("[kn_i]", "kts")),
+    }
+}
+
 // Cloud amount description, CloudLayerDataProvider trait and impls, format_clouds_generic
 fn get_cloud_amount_description(xlink_href: &str) -> String {
     if xlink_href.contains("/FEW") {
@@ -587,6 +706,7 @@ impl CloudLayerDataProvider
 }
 fn format_clouds_generic<CLD: CloudLayerDataProvider>(
     layers_data_opt: Option<&Vec<CLD>>,
+    units: Option<UnitSystem>,
 ) -> String {
     match layers_data_opt {
         Some(layers) if !layers.is_empty() => layers
@@ -594,8 +714,21 @@ fn format_clouds_generic<CLD: CloudLayerDataProvider>(
             .map(|layer_data| {
                 let amount = get_cloud_amount_description(layer_data.amount_href());
                 let base_val = layer_data.base_value().unwrap_or_else(|| "N/A".to_owned());
-                let base_uom = layer_data.base_uom().replace("[ft_i]", "ft AGL");
-                format!("{amount} at {base_val} {base_uom}")
+                match (units, base_val.parse::<f64>()) {
+                    (Some(units), Ok(base)) => {
+                        let base_str = format_measurement(
+                            base,
+                            layer_data.base_uom(),
+                            Quantity::Height,
+                            Some(units),
+                        );
+                        format!("{amount} at {base_str} AGL")
+                    }
+                    _ => {
+                        let base_uom = layer_data.base_uom().replace("[ft_i]", "ft AGL");
+                        format!("{amount} at {base_val} {base_uom}")
+                    }
+                }
             })
             .collect::<Vec<String>>()
             .join("\n"),
@@ -632,15 +765,21 @@ fn get_weather_description(weather_opt: Option<&Vec<Weather>>) -> String {
     }
 }
 
+/// The elements of a TAF base or change forecast shown for each period.
+struct ForecastPeriod<'a, SWD, PVD, CLD> {
+    surface_wind: Option<&'a SWD>,
+    prevailing_visibility: Option<&'a PVD>,
+    visibility_operator: Option<&'a String>,
+    weather: Option<&'a Vec<Weather>>,
+    cloud_layers: Option<&'a Vec<CLD>>,
+}
+
 /// Helper function to add a forecast period's details to the table.
 fn add_forecast_period_to_table<SWD, PVD, CLD>(
     table: &mut Table,
     period_title: &str,
-    surface_wind_data: Option<&SWD>,
-    prevailing_visibility_data: Option<&PVD>,
-    visibility_operator: Option<&String>,
-    weather_data: Option<&Vec<Weather>>,
-    cloud_layers_data: Option<&Vec<CLD>>,
+    period: ForecastPeriod<'_, SWD, PVD, CLD>,
+    units: Option<UnitSystem>,
 ) where
     SWD: WindDataProvider + Sized,
     PVD: VisibilityDataProvider + Sized,
@@ -658,22 +797,31 @@ fn add_forecast_period_to_table<SWD, PVD, CLD>(
     ]);
     table.add_row(vec![
         Cell::new("Wind:").add_attribute(Attribute::Bold),
-        Cell::new(format_wind_generic(surface_wind_data)),
+        Cell::new(format_wind_generic(period.surface_wind, units)),
     ]);
     table.add_row(vec![
         Cell::new("Visibility:").add_attribute(Attribute::Bold),
         Cell::new(format_visibility_generic(
-            prevailing_visibility_data,
-            visibility_operator,
+            period.prevailing_visibility,
+            period.visibility_operator,
+            units,
         )),
     ]);
     table.add_row(vec![
         Cell::new("Weather:").add_attribute(Attribute::Bold),
-        Cell::new(get_weather_description(weather_data)),
+        Cell::new(get_weather_description(period.weather)),
     ]);
     table.add_row(vec![
         Cell::new("Clouds:").add_attribute(Attribute::Bold),
-        Cell::new(format_clouds_generic(cloud_layers_data)),
+        Cell::new(format_clouds_generic(period.cloud_layers, units)),
+    ]);
+}
+
+/// Adds a forecast period's flight category to the table.
+fn add_flight_category_to_table(table: &mut Table, flight_category: Option<FlightCategory>) {
+    table.add_row(vec![
+        Cell::new("Flight Category:").add_attribute(Attribute::Bold),
+        flight_category_cell(flight_category),
     ]);
 }
 
@@ -694,11 +842,16 @@ fn add_forecast_period_to_table<SWD, PVD, CLD>(
 /// - Visibility: Prevailing visibility.
 /// - Weather: Significant weather phenomena.
 /// - Clouds: Cloud layers with amount and base height.
+/// - Flight Category: VFR, MVFR, IFR, or LIFR, colour-coded.
 ///
 /// # Arguments
 /// * `taf_bulletin`: A reference to the `TerminalAerodromeForecast` struct containing the TAF data.
+/// * `units`: The unit system to display wind, visibility, and cloud bases in, if any.
 ///
-pub fn create_stations_taf_table(taf_bulletin: &TerminalAerodromeForecast) -> Table {
+pub fn create_stations_taf_table(
+    taf_bulletin: &TerminalAerodromeForecast,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table
         .load_preset(UTF8_FULL_CONDENSED)
@@ -744,6 +897,8 @@ pub fn create_stations_taf_table(taf_bulletin: &TerminalAerodromeForecast) -> Ta
 
     // --- Base Forecast ---
     let bf_props = &taf.base_forecast.meteorological_aerodrome_forecast;
+    let mut flight_categories = change_forecast_flight_categories(taf_bulletin).into_iter();
+    let base_flight_category = flight_categories.next().flatten();
     if let Some(bf_phenom_time) = &bf_props.phenomenon_time {
         let period_title = format!(
             "INITIAL FORECAST\nValid (UTC): {}",
@@ -755,18 +910,22 @@ pub fn create_stations_taf_table(taf_bulletin: &TerminalAerodromeForecast) -> Ta
         add_forecast_period_to_table(
             &mut table,
             &period_title,
-            bf_props
-                .surface_wind
-                .as_ref()
-                .map(|sw| &sw.aerodrome_surface_wind_forecast),
-            bf_props.prevailing_visibility.as_ref(),
-            bf_props.prevailing_visibility_operator.as_ref(),
-            None,
-            bf_props
-                .cloud
-                .as_ref()
-                .map(|cloud| &cloud.aerodrome_cloud_forecast.layer),
+            ForecastPeriod {
+                surface_wind: bf_props
+                    .surface_wind
+                    .as_ref()
+                    .map(|sw| &sw.aerodrome_surface_wind_forecast),
+                prevailing_visibility: bf_props.prevailing_visibility.as_ref(),
+                visibility_operator: bf_props.prevailing_visibility_operator.as_ref(),
+                weather: bf_props.weather.as_ref(),
+                cloud_layers: bf_props
+                    .cloud
+                    .as_ref()
+                    .map(|cloud| &cloud.aerodrome_cloud_forecast.layer),
+            },
+            units,
         );
+        add_flight_category_to_table(&mut table, base_flight_category);
     }
 
     // --- Change Forecasts ---
@@ -786,30 +945,221 @@ pub fn create_stations_taf_table(taf_bulletin: &TerminalAerodromeForecast) -> Ta
         add_forecast_period_to_table(
             &mut table,
             &period_title,
-            cf_props
-                .surface_wind
-                .as_ref()
-                .map(|sw| &sw.aerodrome_surface_wind_forecast),
-            cf_props.prevailing_visibility.as_ref(),
-            cf_props.prevailing_visibility_operator.as_ref(),
-            cf_props.weather.as_ref(),
-            Some(&cf_props.cloud.aerodrome_cloud_forecast.layer),
+            ForecastPeriod {
+                surface_wind: cf_props
+                    .surface_wind
+                    .as_ref()
+                    .map(|sw| &sw.aerodrome_surface_wind_forecast),
+                prevailing_visibility: cf_props.prevailing_visibility.as_ref(),
+                visibility_operator: cf_props.prevailing_visibility_operator.as_ref(),
+                weather: cf_props.weather.as_ref(),
+                cloud_layers: cf_props
+                    .cloud
+                    .as_ref()
+                    .map(|cloud| &cloud.aerodrome_cloud_forecast.layer),
+            },
+            units,
         );
+
+        add_flight_category_to_table(&mut table, flight_categories.next().flatten());
     }
 
     table
 }
 
+/// Creates a table displaying a decoded Terminal Aerodrome Forecast (TAF), one row per group.
+/// All times are displayed in UTC.
+///
+/// Wind, visibility, and clouds are shown in TAF notation (e.g., "27015G25KT", "P6SM",
+/// "BKN050") unless a unit system is selected. Cells are left blank for elements a
+/// change group does not forecast. Each group's flight category is colour-coded.
+///
+/// # Arguments
+/// * `taf`: A reference to the `DecodedTaf` to display.
+/// * `units`: The unit system to display wind, visibility, and cloud bases in, if any.
+///
+pub fn create_decoded_taf_table(taf: &DecodedTaf, units: Option<UnitSystem>) -> Table {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL_CONDENSED)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(
+            [
+                "Group",
+                "Valid (UTC)",
+                "Wind",
+                "Visibility",
+                "Weather",
+                "Clouds",
+                "Category",
+            ]
+            .map(|header| {
+                Cell::new(header)
+                    .add_attribute(Attribute::Bold)
+                    .set_alignment(CellAlignment::Center)
+            }),
+        );
+
+    table.add_row(vec![
+        Cell::new(&taf.station).add_attribute(Attribute::Bold),
+        Cell::new(format!(
+            "Issued {}",
+            format_decoded_taf_time(taf.issue_time)
+        ))
+        .add_attribute(Attribute::Bold),
+        Cell::new(format!(
+            "Valid {}",
+            format_decoded_taf_period(taf.valid_from, taf.valid_to)
+        ))
+        .add_attribute(Attribute::Bold),
+    ]);
+
+    let mut flight_categories = taf.flight_categories().into_iter();
+    let mut base_row = decoded_taf_row(
+        "BASE",
+        &format_decoded_taf_period(taf.valid_from, taf.valid_to),
+        &taf.base,
+        units,
+    );
+    base_row.push(flight_category_cell(flight_categories.next().flatten()));
+    table.add_row(base_row);
+    for change in &taf.changes {
+        let period = match change.kind {
+            TafChangeKind::From => format!("From {}", format_decoded_taf_time(change.from)),
+            _ => format_decoded_taf_period(change.from, change.to),
+        };
+        let mut row = decoded_taf_row(&change.kind.to_string(), &period, &change.conditions, units);
+        row.push(flight_category_cell(flight_categories.next().flatten()));
+        table.add_row(row);
+    }
+    table
+}
+
+/// Creates a row for a single decoded TAF group.
+fn decoded_taf_row(
+    group: &str,
+    period: &str,
+    conditions: &TafConditions,
+    units: Option<UnitSystem>,
+) -> Vec<Cell> {
+    let wind = conditions
+        .wind
+        .map_or_else(String::new, |wind| match units {
+            Some(units) if !wind.is_calm() => {
+                let direction = wind.direction.map_or_else(
+                    || "Variable".to_owned(),
+                    |direction| format!("{direction}\u{b0}"),
+                );
+                let speed =
+                    format_measurement(wind.speed_knots, "[kn_i]", Quantity::Speed, Some(units));
+                match wind.gust_knots {
+                    Some(gust) => format!(
+                        "{direction} at {speed}, gusts {}",
+                        format_measurement(gust, "[kn_i]", Quantity::Speed, Some(units))
+                    ),
+                    None => format!("{direction} at {speed}"),
+                }
+            }
+            _ => wind.to_string(),
+        });
+    let visibility = if conditions.cavok {
+        "CAVOK".to_owned()
+    } else {
+        conditions
+            .visibility
+            .map_or_else(String::new, |visibility| match units {
+                Some(units) => {
+                    let distance = format_measurement(
+                        visibility.statute_miles,
+                        "[mi_i]",
+                        Quantity::Visibility,
+                        Some(units),
+                    );
+                    if visibility.greater_than {
+                        format!("> {distance}")
+                    } else if visibility.less_than {
+                        format!("< {distance}")
+                    } else {
+                        distance
+                    }
+                }
+                None => visibility.to_string(),
+            })
+    };
+    let clouds = conditions
+        .clouds
+        .iter()
+        .map(|layer| match (units, layer.base_feet) {
+            (Some(units), Some(base)) => format!(
+                "{} {}",
+                layer.cover,
+                format_measurement(base, "[ft_i]", Quantity::Height, Some(units))
+            ),
+            _ => layer.to_string(),
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+    vec![
+        Cell::new(group).add_attribute(Attribute::Bold),
+        Cell::new(period),
+        Cell::new(wind),
+        Cell::new(visibility),
+        Cell::new(conditions.weather.join(" ")),
+        Cell::new(clouds),
+    ]
+}
+
+/// Formats a decoded TAF time as "DD Mon HH:MM", or "N/A" when it is missing.
+fn format_decoded_taf_time(time: Option<Timestamp>) -> String {
+    time.map_or_else(
+        || "N/A".to_owned(),
+        |time| {
+            let day = time.strftime("%d").to_string();
+            format!(
+                "{} {}",
+                day.trim_start_matches('0'),
+                time.strftime("%b %H:%M")
+            )
+        },
+    )
+}
+
+/// Formats a decoded TAF period as "DD Mon HH:MM to DD Mon HH:MM".
+fn format_decoded_taf_period(from: Option<Timestamp>, to: Option<Timestamp>) -> String {
+    format!(
+        "{} to {}",
+        format_decoded_taf_time(from),
+        format_decoded_taf_time(to)
+    )
+}
+
+/// Creates a cell showing a flight category, colour-coded with the conventional aviation colours.
+fn flight_category_cell(category: Option<FlightCategory>) -> Cell {
+    let Some(category) = category else {
+        return Cell::new("N/A");
+    };
+    let cell = Cell::new(category.to_string()).add_attribute(Attribute::Bold);
+    match category {
+        FlightCategory::Vfr => cell.fg(Color::Green),
+        FlightCategory::Mvfr => cell.fg(Color::Blue),
+        FlightCategory::Ifr => cell.fg(Color::Red),
+        FlightCategory::Lifr => cell.fg(Color::Magenta),
+    }
+}
+
 /// Creates a row for a single observation station.
 ///
 /// This function processes an `ObservationStationGeoJson`, which contains a single observation station,
 /// and formats it into a row. Each row represents a station, displaying its ID, name,
 /// elevation, and time zone.
 ///
-fn create_station_row(observation_station: &ObservationStationGeoJson) -> Vec<String> {
+fn create_station_row(
+    observation_station: &ObservationStationGeoJson,
+    units: Option<UnitSystem>,
+) -> Vec<String> {
     let station = &observation_station.properties;
 
-    let elevation_str = format_optional_value_unit(&station.elevation);
+    let elevation_str = format_value_unit(&station.elevation, Quantity::Height, units);
 
     let point_str = observation_station.geometry.as_ref().map_or_else(
         || "N/A".to_owned(),
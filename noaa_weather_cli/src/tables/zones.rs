@@ -4,11 +4,12 @@ use noaa_weather_client::models::{
     MetarPhenomenon, Observation, ObservationCloudLayersInner, ObservationGeoJson, Zone,
     ZoneCollectionGeoJson, ZoneForecastGeoJson, ZoneGeoJson, ZoneState,
 };
+use noaa_weather_client::zone_forecast::{ForecastRange, ZonePeriodSummary};
 
 use crate::utils::format::{
-    format_datetime_human_readable, format_observation_wind, format_optional_value_unit,
-    get_zone_from_url,
+    format_datetime_human_readable, format_observation_wind, get_zone_from_url,
 };
+use crate::utils::units::{Quantity, Unit, UnitSystem, convert_speed_text, format_value_unit};
 
 /// Creates a table listing all zones with key summary information.
 ///
@@ -108,26 +109,35 @@ pub fn create_zone_metadata_table(zone_geo: &ZoneGeoJson) -> Table {
 ///
 /// This function processes a `ZoneForecastGeoJson`, which contains the forecast for a single zone,
 /// and formats it into a table. Each row represents a forecast period, displaying its name and
-/// detailed forecast.
+/// detailed forecast. In the compact view, the detailed forecast is replaced by the sky condition,
+/// temperatures, wind, and chance of precipitation extracted from it.
 ///
 /// # Arguments
 /// * `zone_forecast`: A reference to the `ZoneForecastGeoJson` struct.
+/// * `compact`: Whether to show the extracted values in columns instead of the detailed forecast.
+/// * `units`: The unit system for the compact view's temperatures and wind speeds.
 ///
 /// # Returns
 /// A `Result<Table>` which is the `comfy_table::Table` ready for display, or an error.
-pub fn create_zone_forecast_table(zone_forecast: &ZoneForecastGeoJson) -> Table {
+pub fn create_zone_forecast_table(
+    zone_forecast: &ZoneForecastGeoJson,
+    compact: bool,
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
     table.set_content_arrangement(ContentArrangement::Dynamic);
 
-    table.set_header(vec![
-        Cell::new("Day/Night")
-            .add_attribute(Attribute::Bold)
-            .set_alignment(CellAlignment::Center),
-        Cell::new("Forecast")
+    let headers: &[&str] = if compact {
+        &["Day/Night", "Sky", "High", "Low", "Wind", "Precip"]
+    } else {
+        &["Day/Night", "Forecast"]
+    };
+    table.set_header(headers.iter().map(|header| {
+        Cell::new(header)
             .add_attribute(Attribute::Bold)
-            .set_alignment(CellAlignment::Center),
-    ]);
+            .set_alignment(CellAlignment::Center)
+    }));
 
     let properties = &zone_forecast.properties;
 
@@ -135,9 +145,28 @@ pub fn create_zone_forecast_table(zone_forecast: &ZoneForecastGeoJson) -> Table
         Some(periods_vec) if !periods_vec.is_empty() => {
             for period_item in periods_vec {
                 let name_cell = Cell::new(&period_item.name);
-                let forecast_cell = Cell::new(&period_item.detailed_forecast);
-
-                table.add_row(vec![name_cell, forecast_cell]);
+                if compact {
+                    let summary = period_item.summary();
+                    table.add_row(vec![
+                        name_cell,
+                        Cell::new(
+                            summary
+                                .sky
+                                .map_or_else(|| "N/A".to_owned(), |sky| sky.to_string()),
+                        ),
+                        Cell::new(format_temperature_range(summary.high, units)),
+                        Cell::new(format_temperature_range(summary.low, units)),
+                        Cell::new(format_zone_forecast_wind(&summary, units)),
+                        Cell::new(
+                            summary
+                                .precipitation_chance
+                                .map_or_else(|| "N/A".to_owned(), |chance| format!("{chance}%")),
+                        ),
+                    ]);
+                } else {
+                    let forecast_cell = Cell::new(&period_item.detailed_forecast);
+                    table.add_row(vec![name_cell, forecast_cell]);
+                }
             }
         }
         _ => {
@@ -155,6 +184,51 @@ pub fn create_zone_forecast_table(zone_forecast: &ZoneForecastGeoJson) -> Table
     table
 }
 
+/// Formats a temperature range given in degrees Fahrenheit, e.g., "98-103 °F".
+fn format_temperature_range(range: Option<ForecastRange>, units: Option<UnitSystem>) -> String {
+    let Some(range) = range else {
+        return "N/A".to_owned();
+    };
+    let target_unit = units.map_or(Unit::Fahrenheit, |units| {
+        units.unit_for(Quantity::Temperature)
+    });
+    let convert = |value: i16| {
+        Unit::Fahrenheit
+            .convert(f64::from(value), target_unit)
+            .map_or(value, |converted| converted.round() as i16)
+    };
+    format!(
+        "{} {}",
+        ForecastRange::new(convert(range.min), convert(range.max)),
+        target_unit.label()
+    )
+}
+
+/// Formats the wind of a zone forecast period, e.g., "SW 5 to 10 mph, gusts 25 mph".
+fn format_zone_forecast_wind(summary: &ZonePeriodSummary, units: Option<UnitSystem>) -> String {
+    let speed = summary.wind_speed.map(|speed| {
+        let speed_text = if speed.min == speed.max {
+            format!("{} mph", speed.max)
+        } else {
+            format!("{} to {} mph", speed.min, speed.max)
+        };
+        convert_speed_text(&speed_text, units)
+    });
+    let wind = match (summary.wind_direction, speed) {
+        (Some(direction), Some(speed)) => format!("{direction} {speed}"),
+        (Some(direction), None) => direction.to_string(),
+        (None, Some(speed)) => speed,
+        (None, None) => return "N/A".to_owned(),
+    };
+    match summary.wind_gust {
+        Some(gust) => format!(
+            "{wind}, gusts {}",
+            convert_speed_text(&format!("{gust} mph"), units)
+        ),
+        None => wind,
+    }
+}
+
 fn create_zone_row(zone: &Zone) -> Vec<Cell> {
     let zone_id_str = zone.id.as_deref().unwrap_or("N/A").to_owned();
     let name_str = zone.name.as_deref().unwrap_or("N/A").to_owned();
@@ -238,13 +312,15 @@ fn create_zone_row(zone: &Zone) -> Vec<Cell> {
 /// Formats cloud layers from an observation.
 fn format_observation_clouds(
     cloud_layers_field: Option<&Option<Vec<ObservationCloudLayersInner>>>,
+    units: Option<UnitSystem>,
 ) -> String {
     match cloud_layers_field {
         Some(Some(layers)) if !layers.is_empty() => layers
             .iter()
             .map(|layer| {
                 let amount = &layer.amount;
-                let base_str = format_optional_value_unit(&Some(layer.base.as_ref().clone()));
+                let base_str =
+                    format_value_unit(&Some(layer.base.as_ref().clone()), Quantity::Height, units);
                 format!("{amount} at {base_str}")
             })
             .collect::<Vec<String>>()
@@ -275,10 +351,14 @@ fn format_observation_present_weather(weather_opt: Option<&Vec<MetarPhenomenon>>
 /// # Arguments
 /// * `observations_features`: A slice of `ObservationGeoJson` features. This typically comes
 ///   from the `features` array of an `ObservationCollectionGeoJson` response.
+/// * `units`: The unit system to display measurements in, if any.
 ///
 /// # Returns
 /// A `Result<Table>` which is the `comfy_table::Table` ready for display, or an error.
-pub fn create_zone_observations_table(observations_features: &[ObservationGeoJson]) -> Table {
+pub fn create_zone_observations_table(
+    observations_features: &[ObservationGeoJson],
+    units: Option<UnitSystem>,
+) -> Table {
     let mut table = Table::new();
     table.load_preset(UTF8_FULL);
     table.set_content_arrangement(ContentArrangement::Dynamic);
@@ -330,25 +410,28 @@ pub fn create_zone_observations_table(observations_features: &[ObservationGeoJso
         let station_name_code = format!("{station_name}\n({station_id})");
         let timestamp = format_datetime_human_readable(properties.timestamp.as_deref());
 
-        let temp = format_optional_value_unit(&properties.temperature);
-        let dewpoint = format_optional_value_unit(&properties.dewpoint);
+        let temp = format_value_unit(&properties.temperature, Quantity::Temperature, units);
+        let dewpoint = format_value_unit(&properties.dewpoint, Quantity::Temperature, units);
 
         let wind = format_observation_wind(
             properties.wind_speed.clone(),
             properties.wind_direction.clone(),
+            units,
         );
 
         // Prioritize Sea Level Pressure, fallback to Barometric if SLP is not available
-        let pressure = format_optional_value_unit(
+        let pressure = format_value_unit(
             &properties
                 .sea_level_pressure
                 .as_ref()
                 .or(properties.barometric_pressure.as_ref())
                 .cloned(),
+            Quantity::Pressure,
+            units,
         );
 
-        let visibility = format_optional_value_unit(&properties.visibility);
-        let clouds = format_observation_clouds(properties.cloud_layers.as_ref());
+        let visibility = format_value_unit(&properties.visibility, Quantity::Visibility, units);
+        let clouds = format_observation_clouds(properties.cloud_layers.as_ref(), units);
 
         // For weather description, use textDescription. If empty, use formatted presentWeather.
         let mut weather_description = properties
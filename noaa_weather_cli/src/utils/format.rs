@@ -1,11 +1,12 @@
 use anyhow::Result;
 use jiff::Timestamp;
 use jiff::tz::TimeZone;
+use noaa_weather_client::forecast_icon::{ForecastIcon, IconCode};
 use noaa_weather_client::models::{UnitCodeType, ValueUnit};
 use std::fs::File;
 use std::io::Write as _;
 
-use crate::utils::temperature::{celsius_to_fahrenheit, fahrenheit_to_celsius};
+use crate::utils::units::{Quantity, UnitSystem, format_value_unit};
 
 /// Write output to either stdout or a file
 pub fn write_output(output_path: Option<&str>, content: &str) -> Result<()> {
@@ -66,104 +67,6 @@ pub fn format_optional_number(number_opt: Option<i32>) -> String {
     number_opt.map_or_else(|| "N/A".to_owned(), |number| number.to_string())
 }
 
-// Unit strings used in the API.
-const WMO_UNIT_DEGC: &str = "wmoUnit:degC";
-const WMO_UNIT_DEGF: &str = "wmoUnit:degF";
-
-// User-requested target units.
-pub const FAHRENHEIT: &str = "fahrenheit";
-pub const CELSIUS: &str = "celsius";
-
-// Display strings for the temperature units.
-const DEG_C_DISPLAY: &str = "\u{b0}C";
-const DEG_F_DISPLAY: &str = "\u{b0}F";
-
-/// Formats a dewpoint temperature value based on its original unit and a user's target unit preference.
-///
-/// This function handles parsing the dewpoint value, identifying its initial unit,
-/// performing a temperature scale conversion if a target unit is specified and different,
-/// rounding the final value to the nearest integer, and formatting the output string
-/// with the rounded value and the appropriate unit symbol.
-///
-/// # Arguments
-///
-/// * `dewpoint_str_value` - A `String` containing the numerical value of the dewpoint temperature.
-/// * `api_unit_str_opt` - An `Option<&str>` indicating the unit of the `dewpoint_str_value`
-///   as provided by an API (e.g., "wmoUnit:degC"). If `None`, an error string is returned.
-/// * `user_target_unit_opt` - An `Option<&str>` indicating the user's desired output unit
-///   (e.g., "fahrenheit", "celsius"). If `None`, the original unit is used, and no
-///   conversion takes place, but the value is still rounded.
-///
-/// # Returns
-///
-/// A `String` ready for display, containing the formatted dewpoint temperature
-/// (e.g., "46 °F" or "8 °C"), or an error message if the input is invalid or incomplete.
-pub fn format_dewpoint(
-    dewpoint_str_value: String,
-    api_unit_str_opt: Option<&str>,
-    user_target_unit_opt: Option<&str>,
-) -> String {
-    // Ensure the API unit string is provided.
-    let api_unit_actual_str = match api_unit_str_opt {
-        Some(unit_str) => unit_str,
-        None => return "N/A".to_owned(),
-    };
-
-    // Attempt to parse the dewpoint value string into a floating-point number.
-    let initial_dewpoint_val_f64 = match dewpoint_str_value.parse::<f64>() {
-        Ok(val) => val,
-        Err(_) => return "N/A".to_owned(),
-    };
-
-    // Determine the initial unit type.
-    let initial_unit_is_celsius = api_unit_actual_str == WMO_UNIT_DEGC;
-    let initial_unit_is_fahrenheit = api_unit_actual_str == WMO_UNIT_DEGF;
-
-    // Return an error if the provided API unit is not supported.
-    if !initial_unit_is_celsius && !initial_unit_is_fahrenheit {
-        return "N/A".to_owned();
-    }
-
-    let mut final_dewpoint_val_f64 = initial_dewpoint_val_f64;
-    let mut display_unit_str = "";
-
-    // Determine the target unit and perform conversion if necessary.
-    if let Some(target_unit_str) = user_target_unit_opt {
-        if initial_unit_is_celsius && target_unit_str == FAHRENHEIT {
-            // Convert Celsius to Fahrenheit
-            final_dewpoint_val_f64 = celsius_to_fahrenheit(initial_dewpoint_val_f64);
-            display_unit_str = DEG_F_DISPLAY;
-        } else if initial_unit_is_fahrenheit && target_unit_str == CELSIUS {
-            // Convert Fahrenheit to Celsius
-            final_dewpoint_val_f64 = fahrenheit_to_celsius(initial_dewpoint_val_f64);
-            display_unit_str = DEG_C_DISPLAY;
-        } else {
-            // If the target unit is the same as the initial, or an unsupported target
-            // was provided, use the initial value and determine the display unit
-            // based on the initial unit.
-            if initial_unit_is_celsius {
-                display_unit_str = DEG_C_DISPLAY;
-            } else if initial_unit_is_fahrenheit {
-                display_unit_str = DEG_F_DISPLAY;
-            }
-        }
-    } else {
-        // If no target unit is specified, use the initial value and unit.
-        final_dewpoint_val_f64 = initial_dewpoint_val_f64;
-        if initial_unit_is_celsius {
-            display_unit_str = DEG_C_DISPLAY;
-        } else if initial_unit_is_fahrenheit {
-            display_unit_str = DEG_F_DISPLAY;
-        }
-    }
-
-    // Round the final temperature value to the nearest integer as required by examples.
-    let rounded_final_value = final_dewpoint_val_f64.round();
-
-    // Format the rounded value with the determined unit symbol.
-    format!("{rounded_final_value} {display_unit_str}")
-}
-
 /// Formats an `Option<String>` for display, using "N/A" if None.
 pub fn format_optional_string(optional_string: &Option<String>) -> String {
     optional_string.as_deref().unwrap_or("N/A").to_owned()
@@ -262,10 +165,11 @@ pub fn format_bytes_to_human_readable(bytes_opt: Option<i64>) -> String {
     )
 }
 
-/// Formats wind observation data.
+/// Formats wind observation data, converting the speed to the selected unit system.
 pub fn format_observation_wind(
     speed_vu_opt: Option<ValueUnit>,
     direction_vu_opt: Option<ValueUnit>,
+    units: Option<UnitSystem>,
 ) -> String {
     if speed_vu_opt.is_none() {
         return "N/A".to_owned();
@@ -274,7 +178,7 @@ pub fn format_observation_wind(
     if speed_vu.value.is_none() {
         return "N/A".to_owned();
     }
-    let speed_str = format_optional_value_unit(&Some(speed_vu));
+    let speed_str = format_value_unit(&Some(speed_vu), Quantity::Speed, units);
     if direction_vu_opt.is_none() {
         return speed_str;
     }
@@ -286,10 +190,107 @@ pub fn format_observation_wind(
     format!("{speed_str} {direction_str}")
 }
 
+/// Formats fields as one CSV record, quoting fields that contain commas, quotes, or line breaks.
+pub fn format_csv_record<S: AsRef<str>>(fields: &[S]) -> String {
+    fields
+        .iter()
+        .map(|field| {
+            let field = field.as_ref();
+            if field.contains([',', '"', '\n', '\r']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Returns an emoji glyph for a forecast icon condition code, or an empty string for unknown codes.
+pub fn icon_glyph(code: &IconCode, is_daytime: bool) -> &'static str {
+    match code {
+        IconCode::Skc if is_daytime => "\u{2600}\u{fe0f}",
+        IconCode::Skc | IconCode::Few if !is_daytime => "\u{1f319}",
+        IconCode::Few => "\u{1f324}\u{fe0f}",
+        IconCode::Sct => "\u{26c5}",
+        IconCode::Bkn => "\u{1f325}\u{fe0f}",
+        IconCode::Ovc => "\u{2601}\u{fe0f}",
+        IconCode::WindSkc
+        | IconCode::WindFew
+        | IconCode::WindSct
+        | IconCode::WindBkn
+        | IconCode::WindOvc => "\u{1f32c}\u{fe0f}",
+        IconCode::Snow | IconCode::Blizzard => "\u{2744}\u{fe0f}",
+        IconCode::RainSnow | IconCode::SnowSleet | IconCode::SnowFzra => "\u{1f328}\u{fe0f}",
+        IconCode::RainSleet | IconCode::Fzra | IconCode::RainFzra | IconCode::Sleet => "\u{1f9ca}",
+        IconCode::Rain | IconCode::RainShowers => "\u{1f327}\u{fe0f}",
+        IconCode::RainShowersHi => "\u{1f326}\u{fe0f}",
+        IconCode::Tsra | IconCode::TsraSct | IconCode::TsraHi => "\u{26c8}\u{fe0f}",
+        IconCode::Tornado => "\u{1f32a}\u{fe0f}",
+        IconCode::Hurricane | IconCode::TropicalStorm => "\u{1f300}",
+        IconCode::Dust => "\u{1f4a8}",
+        IconCode::Smoke | IconCode::Haze | IconCode::Fog => "\u{1f32b}\u{fe0f}",
+        IconCode::Hot => "\u{1f975}",
+        IconCode::Cold => "\u{1f976}",
+        _ => "",
+    }
+}
+
+/// Prefixes a short forecast with the glyphs of its forecast icon, e.g., "⛈️→🌧️ Chance Showers And Thunderstorms".
+///
+/// Icons that cannot be parsed, or have no known condition codes, add no glyphs.
+pub fn format_forecast_with_icon(icon: Option<&str>, short_forecast: Option<&str>) -> String {
+    let short_forecast = short_forecast.unwrap_or("-");
+    let Some(icon) = icon.and_then(|icon| icon.parse::<ForecastIcon>().ok()) else {
+        return short_forecast.to_owned();
+    };
+    let mut glyphs: Vec<&str> = icon
+        .conditions
+        .iter()
+        .map(|condition| icon_glyph(&condition.code, icon.is_daytime))
+        .filter(|glyph| !glyph.is_empty())
+        .collect();
+    glyphs.dedup();
+    if glyphs.is_empty() {
+        short_forecast.to_owned()
+    } else {
+        format!("{} {short_forecast}", glyphs.join("\u{2192}"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn format_forecast_with_icon_adds_glyphs() {
+        assert_eq!(
+            format_forecast_with_icon(
+                Some("https://api.weather.gov/icons/land/day/tsra,40/rain,20?size=medium"),
+                Some("Chance Showers And Thunderstorms"),
+            ),
+            "\u{26c8}\u{fe0f}\u{2192}\u{1f327}\u{fe0f} Chance Showers And Thunderstorms"
+        );
+        assert_eq!(
+            format_forecast_with_icon(Some("/icons/land/night/skc/few"), Some("Clear")),
+            "\u{1f319} Clear"
+        );
+        assert_eq!(
+            format_forecast_with_icon(Some("/icons/land/day/volcano"), Some("Ash")),
+            "Ash"
+        );
+        assert_eq!(format_forecast_with_icon(None, None), "-");
+    }
+
+    #[test]
+    fn format_csv_record_quotes_special_characters() {
+        assert_eq!(format_csv_record(&["Hail", "1.75"]), "Hail,1.75");
+        assert_eq!(
+            format_csv_record(&["3 NW Mesa, AZ", "a \"big\" one", ""]),
+            "\"3 NW Mesa, AZ\",\"a \"\"big\"\" one\","
+        );
+    }
+
     #[test]
     fn get_zone_from_url_valid() {
         let url = Some("https://api.weather.gov/zones/forecast/NYZ072".to_owned());
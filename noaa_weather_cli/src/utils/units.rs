@@ -0,0 +1,469 @@
+use clap::ValueEnum;
+use noaa_weather_client::models::{
+    GridpointForecastUnits, QuantitativeValue, UnitCodeType, ValueUnit, WmoUnitCode,
+};
+
+use crate::utils::format::format_optional_value_unit;
+use crate::utils::temperature::{celsius_to_fahrenheit, fahrenheit_to_celsius};
+
+/// The system of units used to display quantities in tables.
+///
+/// When no unit system is selected, values are displayed in whatever unit the
+/// NWS API returned them in.
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnitSystem {
+    /// US customary units: °F, mph, inHg, miles, feet, and inches.
+    Us,
+    /// International System of Units: °C, km/h, hPa, kilometers, meters, and millimeters.
+    Si,
+    /// Metric units with US customary wind speed and visibility: °C, mph, hPa, miles, meters, and millimeters.
+    Mixed,
+}
+
+impl UnitSystem {
+    /// Returns the display unit this system uses for the given kind of quantity.
+    pub fn unit_for(self, quantity: Quantity) -> Unit {
+        match (self, quantity) {
+            (Self::Us, Quantity::Temperature) => Unit::Fahrenheit,
+            (Self::Si | Self::Mixed, Quantity::Temperature) => Unit::Celsius,
+            (Self::Us | Self::Mixed, Quantity::Speed) => Unit::MilesPerHour,
+            (Self::Si, Quantity::Speed) => Unit::KilometersPerHour,
+            (Self::Us, Quantity::Pressure) => Unit::InchesOfMercury,
+            (Self::Si | Self::Mixed, Quantity::Pressure) => Unit::Hectopascals,
+            (Self::Us | Self::Mixed, Quantity::Visibility) => Unit::Miles,
+            (Self::Si, Quantity::Visibility) => Unit::Kilometers,
+            (Self::Us, Quantity::Height) => Unit::Feet,
+            (Self::Si | Self::Mixed, Quantity::Height) => Unit::Meters,
+            (Self::Us, Quantity::Precipitation) => Unit::Inches,
+            (Self::Si | Self::Mixed, Quantity::Precipitation) => Unit::Millimeters,
+        }
+    }
+
+    /// Returns the units to request from the gridpoint forecast endpoints.
+    ///
+    /// The mixed system requests SI units; wind speeds are converted locally.
+    pub fn forecast_units(self) -> GridpointForecastUnits {
+        match self {
+            Self::Us => GridpointForecastUnits::Us,
+            Self::Si | Self::Mixed => GridpointForecastUnits::Si,
+        }
+    }
+}
+
+/// The kind of physical quantity being displayed.
+///
+/// Lengths are split by use, since visibility, heights, and precipitation
+/// are each shown in a different unit within the same unit system.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Quantity {
+    Temperature,
+    Speed,
+    Pressure,
+    Visibility,
+    Height,
+    Precipitation,
+}
+
+/// The physical dimension of a unit, used to reject conversions between incompatible units.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Dimension {
+    Temperature,
+    Speed,
+    Pressure,
+    Length,
+}
+
+/// A unit that values can be converted from or to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Unit {
+    Celsius,
+    Fahrenheit,
+    Kelvin,
+    MetersPerSecond,
+    KilometersPerHour,
+    MilesPerHour,
+    Knots,
+    Pascals,
+    Hectopascals,
+    Kilopascals,
+    InchesOfMercury,
+    Meters,
+    Kilometers,
+    Miles,
+    Feet,
+    Millimeters,
+    Centimeters,
+    Inches,
+}
+
+const METERS_PER_MILE: f64 = 1609.344;
+const METERS_PER_FOOT: f64 = 0.3048;
+const METERS_PER_INCH: f64 = 0.0254;
+const PASCALS_PER_INCH_OF_MERCURY: f64 = 3386.389;
+
+impl Unit {
+    /// Maps a WMO unit code to a convertible unit, if supported.
+    pub fn from_wmo(wmo_unit_code: WmoUnitCode) -> Option<Self> {
+        match wmo_unit_code {
+            WmoUnitCode::DegreeCelsius | WmoUnitCode::DegreesCelsius8 => Some(Self::Celsius),
+            WmoUnitCode::Kelvin => Some(Self::Kelvin),
+            WmoUnitCode::MetresPerSecond => Some(Self::MetersPerSecond),
+            WmoUnitCode::KilometresPerHour => Some(Self::KilometersPerHour),
+            WmoUnitCode::Knot => Some(Self::Knots),
+            WmoUnitCode::Pascal => Some(Self::Pascals),
+            WmoUnitCode::Hectopascal => Some(Self::Hectopascals),
+            WmoUnitCode::Kilopascal => Some(Self::Kilopascals),
+            WmoUnitCode::Metre => Some(Self::Meters),
+            WmoUnitCode::Kilometre => Some(Self::Kilometers),
+            WmoUnitCode::Foot => Some(Self::Feet),
+            WmoUnitCode::Millimetre => Some(Self::Millimeters),
+            WmoUnitCode::Centimetre => Some(Self::Centimeters),
+            _ => None,
+        }
+    }
+
+    /// Parses a unit code string as returned by the API.
+    ///
+    /// Accepts namespaced WMO codes (e.g., "wmoUnit:degC"), UCUM codes used in
+    /// IWXXM documents (e.g., "[kn_i]", "[ft_i]"), and the short labels used
+    /// in textual forecasts (e.g., "F", "mph").
+    pub fn from_code(unit_code: &str) -> Option<Self> {
+        let code = unit_code
+            .rsplit_once(':')
+            .map_or(unit_code, |(_, code)| code);
+        match code {
+            "degC" | "Cel" | "C" => Some(Self::Celsius),
+            "degF" | "[degF]" | "F" => Some(Self::Fahrenheit),
+            "K" => Some(Self::Kelvin),
+            "m_s-1" | "m/s" => Some(Self::MetersPerSecond),
+            "km_h-1" | "km/h" => Some(Self::KilometersPerHour),
+            "mph" | "[mi_i]/h" => Some(Self::MilesPerHour),
+            "kt" | "[kn_i]" => Some(Self::Knots),
+            "Pa" => Some(Self::Pascals),
+            "hPa" | "mbar" => Some(Self::Hectopascals),
+            "kPa" => Some(Self::Kilopascals),
+            "[in_i'Hg]" | "inHg" => Some(Self::InchesOfMercury),
+            "m" => Some(Self::Meters),
+            "km" => Some(Self::Kilometers),
+            "[mi_i]" | "[smi_us]" | "mi" => Some(Self::Miles),
+            "ft" | "[ft_i]" => Some(Self::Feet),
+            "mm" => Some(Self::Millimeters),
+            "cm" => Some(Self::Centimeters),
+            "in" | "[in_i]" => Some(Self::Inches),
+            _ => None,
+        }
+    }
+
+    /// Returns the short label used when displaying values in this unit.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Celsius => "\u{b0}C",
+            Self::Fahrenheit => "\u{b0}F",
+            Self::Kelvin => "K",
+            Self::MetersPerSecond => "m/s",
+            Self::KilometersPerHour => "km/h",
+            Self::MilesPerHour => "mph",
+            Self::Knots => "kt",
+            Self::Pascals => "Pa",
+            Self::Hectopascals => "hPa",
+            Self::Kilopascals => "kPa",
+            Self::InchesOfMercury => "inHg",
+            Self::Meters => "m",
+            Self::Kilometers => "km",
+            Self::Miles => "mi",
+            Self::Feet => "ft",
+            Self::Millimeters => "mm",
+            Self::Centimeters => "cm",
+            Self::Inches => "in",
+        }
+    }
+
+    /// Returns the number of decimal places shown for values in this unit.
+    fn precision(self) -> usize {
+        match self {
+            Self::InchesOfMercury | Self::Inches => 2,
+            Self::Hectopascals | Self::Kilopascals | Self::Miles | Self::Kilometers => 1,
+            Self::Millimeters | Self::Centimeters => 1,
+            _ => 0,
+        }
+    }
+
+    fn dimension(self) -> Dimension {
+        match self {
+            Self::Celsius | Self::Fahrenheit | Self::Kelvin => Dimension::Temperature,
+            Self::MetersPerSecond | Self::KilometersPerHour | Self::MilesPerHour | Self::Knots => {
+                Dimension::Speed
+            }
+            Self::Pascals | Self::Hectopascals | Self::Kilopascals | Self::InchesOfMercury => {
+                Dimension::Pressure
+            }
+            Self::Meters
+            | Self::Kilometers
+            | Self::Miles
+            | Self::Feet
+            | Self::Millimeters
+            | Self::Centimeters
+            | Self::Inches => Dimension::Length,
+        }
+    }
+
+    /// Converts a value in this unit to the base unit of its dimension (°C, m/s, Pa, or m).
+    fn to_base(self, value: f64) -> f64 {
+        match self {
+            Self::Celsius | Self::MetersPerSecond | Self::Pascals | Self::Meters => value,
+            Self::Fahrenheit => fahrenheit_to_celsius(value),
+            Self::Kelvin => value - 273.15,
+            Self::KilometersPerHour => value / 3.6,
+            Self::MilesPerHour => value * METERS_PER_MILE / 3600.0,
+            Self::Knots => value * 1852.0 / 3600.0,
+            Self::Hectopascals => value * 100.0,
+            Self::Kilopascals => value * 1000.0,
+            Self::InchesOfMercury => value * PASCALS_PER_INCH_OF_MERCURY,
+            Self::Kilometers => value * 1000.0,
+            Self::Miles => value * METERS_PER_MILE,
+            Self::Feet => value * METERS_PER_FOOT,
+            Self::Millimeters => value / 1000.0,
+            Self::Centimeters => value / 100.0,
+            Self::Inches => value * METERS_PER_INCH,
+        }
+    }
+
+    /// Converts a value in the base unit of this unit's dimension to this unit.
+    fn convert_from_base(self, value: f64) -> f64 {
+        match self {
+            Self::Celsius | Self::MetersPerSecond | Self::Pascals | Self::Meters => value,
+            Self::Fahrenheit => celsius_to_fahrenheit(value),
+            Self::Kelvin => value + 273.15,
+            Self::KilometersPerHour => value * 3.6,
+            Self::MilesPerHour => value * 3600.0 / METERS_PER_MILE,
+            Self::Knots => value * 3600.0 / 1852.0,
+            Self::Hectopascals => value / 100.0,
+            Self::Kilopascals => value / 1000.0,
+            Self::InchesOfMercury => value / PASCALS_PER_INCH_OF_MERCURY,
+            Self::Kilometers => value / 1000.0,
+            Self::Miles => value / METERS_PER_MILE,
+            Self::Feet => value / METERS_PER_FOOT,
+            Self::Millimeters => value * 1000.0,
+            Self::Centimeters => value * 100.0,
+            Self::Inches => value / METERS_PER_INCH,
+        }
+    }
+
+    /// Converts `value` from this unit to `target`.
+    ///
+    /// Returns `None` if the two units measure different dimensions.
+    pub fn convert(self, value: f64, target: Self) -> Option<f64> {
+        if self.dimension() != target.dimension() {
+            return None;
+        }
+        Some(target.convert_from_base(self.to_base(value)))
+    }
+
+    /// Formats a value in this unit with the unit's display precision and label.
+    pub fn format(self, value: f64) -> String {
+        format!(
+            "{:.precision$} {}",
+            value,
+            self.label(),
+            precision = self.precision()
+        )
+    }
+}
+
+/// Converts a value from its source unit into the unit system's unit for `quantity`.
+///
+/// Returns the converted value and its unit, or `None` if the source unit is
+/// not recognized or does not match the quantity.
+pub fn convert_to_system(
+    value: f64,
+    source_unit: Unit,
+    quantity: Quantity,
+    units: UnitSystem,
+) -> Option<(f64, Unit)> {
+    let target_unit = units.unit_for(quantity);
+    source_unit
+        .convert(value, target_unit)
+        .map(|converted| (converted, target_unit))
+}
+
+/// Formats a raw value and unit code in the selected unit system.
+///
+/// Falls back to the value and unit code as given when no unit system is
+/// selected or the unit code cannot be converted.
+pub fn format_measurement(
+    value: f64,
+    unit_code: &str,
+    quantity: Quantity,
+    units: Option<UnitSystem>,
+) -> String {
+    units
+        .zip(Unit::from_code(unit_code))
+        .and_then(|(units, source_unit)| convert_to_system(value, source_unit, quantity, units))
+        .map_or_else(
+            || format!("{value} {unit_code}").trim().to_owned(),
+            |(converted, target_unit)| target_unit.format(converted),
+        )
+}
+
+/// Formats an `Option<ValueUnit>` in the selected unit system.
+///
+/// Without a unit system, or when the value's unit is not convertible, this
+/// behaves like [`format_optional_value_unit`].
+pub fn format_value_unit(
+    value_unit_opt: &Option<ValueUnit>,
+    quantity: Quantity,
+    units: Option<UnitSystem>,
+) -> String {
+    let converted = value_unit_opt.as_ref().and_then(|value_unit| {
+        let source_unit = match value_unit.unit_code.as_ref()? {
+            UnitCodeType::Wmo(wmo_unit_code) => Unit::from_wmo(*wmo_unit_code)?,
+            UnitCodeType::Nws(_) => return None,
+        };
+        convert_to_system(value_unit.value?, source_unit, quantity, units?)
+    });
+    converted.map_or_else(
+        || format_optional_value_unit(value_unit_opt),
+        |(value, unit)| unit.format(value),
+    )
+}
+
+/// Formats a `QuantitativeValue` in the selected unit system.
+///
+/// Without a unit system, or when the unit code is not convertible, the value
+/// is shown rounded with the unit's short label when known.
+pub fn format_quantitative_value(
+    quantitative_value: &QuantitativeValue,
+    quantity: Quantity,
+    units: Option<UnitSystem>,
+) -> String {
+    let Some(value) = quantitative_value.value.flatten() else {
+        return "N/A".to_owned();
+    };
+    let Some(source_unit) = quantitative_value
+        .unit_code
+        .as_deref()
+        .and_then(Unit::from_code)
+    else {
+        return format!("{value}");
+    };
+    units
+        .and_then(|units| convert_to_system(value, source_unit, quantity, units))
+        .map_or_else(
+            || source_unit.format(value),
+            |(converted, target_unit)| target_unit.format(converted),
+        )
+}
+
+/// Converts the numbers in a textual wind speed (e.g., "5 to 10 mph") to the selected unit system.
+///
+/// Text without a recognized speed unit, or with no unit system selected, is returned unchanged.
+pub fn convert_speed_text(speed_text: &str, units: Option<UnitSystem>) -> String {
+    let Some(units) = units else {
+        return speed_text.to_owned();
+    };
+    let target_unit = units.unit_for(Quantity::Speed);
+    let Some(source_unit) = speed_text
+        .split_whitespace()
+        .next_back()
+        .and_then(Unit::from_code)
+    else {
+        return speed_text.to_owned();
+    };
+    if source_unit.dimension() != Dimension::Speed {
+        return speed_text.to_owned();
+    }
+    let mut words: Vec<String> = speed_text
+        .split_whitespace()
+        .map(|word| {
+            word.parse::<f64>().map_or_else(
+                |_| word.to_owned(),
+                |value| {
+                    source_unit
+                        .convert(value, target_unit)
+                        .map_or_else(|| word.to_owned(), |converted| format!("{converted:.0}"))
+                },
+            )
+        })
+        .collect();
+    if let Some(last_word) = words.last_mut() {
+        target_unit.label().clone_into(last_word);
+    }
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_convert_temperature() {
+        assert_eq!(Unit::Celsius.convert(100.0, Unit::Fahrenheit), Some(212.0));
+        assert_eq!(Unit::Kelvin.convert(273.15, Unit::Celsius), Some(0.0));
+    }
+
+    #[test]
+    fn test_convert_incompatible_units() {
+        assert_eq!(Unit::Celsius.convert(1.0, Unit::Meters), None);
+    }
+
+    #[test]
+    fn test_format_value_unit_converts_observation_values() {
+        let temperature = Some(ValueUnit {
+            unit_code: Some(UnitCodeType::Wmo(WmoUnitCode::DegreesCelsius8)),
+            value: Some(20.0),
+            ..Default::default()
+        });
+        assert_eq!(
+            format_value_unit(&temperature, Quantity::Temperature, Some(UnitSystem::Us)),
+            "68 \u{b0}F"
+        );
+
+        let pressure = Some(ValueUnit {
+            unit_code: Some(UnitCodeType::Wmo(WmoUnitCode::Pascal)),
+            value: Some(101_325.0),
+            ..Default::default()
+        });
+        assert_eq!(
+            format_value_unit(&pressure, Quantity::Pressure, Some(UnitSystem::Us)),
+            "29.92 inHg"
+        );
+        assert_eq!(
+            format_value_unit(&pressure, Quantity::Pressure, Some(UnitSystem::Si)),
+            "1013.2 hPa"
+        );
+
+        let visibility = Some(ValueUnit {
+            unit_code: Some(UnitCodeType::Wmo(WmoUnitCode::Metre)),
+            value: Some(16_090.0),
+            ..Default::default()
+        });
+        assert_eq!(
+            format_value_unit(&visibility, Quantity::Visibility, Some(UnitSystem::Mixed)),
+            "10.0 mi"
+        );
+    }
+
+    #[test]
+    fn test_format_value_unit_without_unit_system() {
+        let wind_speed = Some(ValueUnit {
+            unit_code: Some(UnitCodeType::Wmo(WmoUnitCode::KilometresPerHour)),
+            value: Some(9.36),
+            ..Default::default()
+        });
+        assert_eq!(
+            format_value_unit(&wind_speed, Quantity::Speed, None),
+            format_optional_value_unit(&wind_speed)
+        );
+    }
+
+    #[test]
+    fn test_convert_speed_text() {
+        assert_eq!(
+            convert_speed_text("10 to 20 km/h", Some(UnitSystem::Mixed)),
+            "6 to 12 mph"
+        );
+        assert_eq!(convert_speed_text("5 mph", Some(UnitSystem::Us)), "5 mph");
+        assert_eq!(convert_speed_text("Calm", Some(UnitSystem::Si)), "Calm");
+        assert_eq!(convert_speed_text("10 mph", None), "10 mph");
+    }
+}
@@ -110,3 +110,67 @@ fn test_product_success() {
     cmd.arg("a4791428-298e-473c-8e6f-5796701c9e4a");
     cmd.assert().success();
 }
+
+#[test]
+fn test_products_discussion_success() {
+    let mut cmd = Command::new(cargo_bin!("noaa-weather"));
+    cmd.arg("products");
+    cmd.arg("discussion");
+    cmd.arg("--office");
+    cmd.arg("PSR");
+    cmd.arg("--section");
+    cmd.arg("aviation");
+    cmd.assert().success();
+}
+
+#[test]
+fn test_products_storm_reports_success() {
+    let mut cmd = Command::new(cargo_bin!("noaa-weather"));
+    cmd.arg("products");
+    cmd.arg("storm-reports");
+    cmd.arg("--office");
+    cmd.arg("OUN");
+    cmd.arg("--limit");
+    cmd.arg("2");
+    cmd.arg("--csv");
+    cmd.assert().success();
+}
+
+#[test]
+fn test_products_climate_success() {
+    let mut cmd = Command::new(cargo_bin!("noaa-weather"));
+    cmd.arg("products");
+    cmd.arg("climate");
+    cmd.arg("--office");
+    cmd.arg("PSR");
+    cmd.arg("--station");
+    cmd.arg("PHX");
+    cmd.assert().success();
+}
+
+#[test]
+fn test_products_diff_success() {
+    let mut cmd = Command::new(cargo_bin!("noaa-weather"));
+    cmd.arg("products");
+    cmd.arg("diff");
+    cmd.arg("--type");
+    cmd.arg("AFD");
+    cmd.arg("--location");
+    cmd.arg("PSR");
+    cmd.assert().success();
+}
+
+#[test]
+fn test_products_search_success() {
+    let mut cmd = Command::new(cargo_bin!("noaa-weather"));
+    cmd.arg("products");
+    cmd.arg("search");
+    cmd.arg("wind");
+    cmd.arg("--type");
+    cmd.arg("AFD");
+    cmd.arg("--office");
+    cmd.arg("PSR,TWC");
+    cmd.arg("--since");
+    cmd.arg("P3D");
+    cmd.assert().success();
+}
@@ -76,3 +76,19 @@ fn test_stations_taf_success() {
     cmd.arg("1800");
     cmd.assert().success();
 }
+
+#[ignore = "Ignore this test for now since the data needs to be updated dynamically"]
+#[test]
+fn test_stations_taf_decode_success() {
+    let mut cmd = Command::new(cargo_bin!("noaa-weather"));
+    cmd.arg("stations");
+    cmd.arg("taf");
+    cmd.arg("--station-id");
+    cmd.arg("KPHX");
+    cmd.arg("--date");
+    cmd.arg("2025-05-03");
+    cmd.arg("--time");
+    cmd.arg("1800");
+    cmd.arg("--decode");
+    cmd.assert().success();
+}
@@ -44,6 +44,19 @@ fn test_zones_forecast_success() {
     cmd.assert().success();
 }
 
+#[test]
+fn test_zones_forecast_compact_success() {
+    let mut cmd = Command::new(cargo_bin!("noaa-weather"));
+    cmd.arg("zones");
+    cmd.arg("forecast");
+    cmd.arg("--id");
+    cmd.arg("AZZ543");
+    cmd.arg("--type");
+    cmd.arg("public");
+    cmd.arg("--compact");
+    cmd.assert().success();
+}
+
 #[test]
 fn test_zones_observations_success() {
     let mut cmd = Command::new(cargo_bin!("noaa-weather"));
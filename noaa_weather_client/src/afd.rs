@@ -0,0 +1,418 @@
+//! Parser for Area Forecast Discussion (AFD) text products.
+//!
+//! An AFD is a free-text product in which forecasters explain their
+//! reasoning. Its body is divided into sections that start with a
+//! `.HEADING...` line and usually end with `&&`, followed by a `$$` line and
+//! a signature block naming the forecasters:
+//!
+//! ```text
+//! Area Forecast Discussion
+//! National Weather Service Phoenix AZ
+//! 312 AM MST Sat Oct 18 2025
+//!
+//! .SHORT TERM /TODAY THROUGH SUNDAY/...
+//! Dry conditions continue.
+//! &&
+//!
+//! .AVIATION...Updated at 0945Z.
+//! Winds follow typical diurnal tendencies.
+//! &&
+//!
+//! $$
+//!
+//! SHORT TERM...Smith
+//! AVIATION...Jones
+//! ```
+//!
+//! [`TextProduct::area_forecast_discussion`](crate::models::TextProduct::area_forecast_discussion)
+//! parses the `product_text` of an `AFD` product.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::afd::{AfdSectionKind, AreaForecastDiscussion};
+//!
+//! let text = "Area Forecast Discussion\nNational Weather Service Phoenix AZ\n312 AM MST Sat Oct 18 2025\n\n.AVIATION...\nVFR conditions.\n&&\n\n$$\n\nAVIATION...Jones\n";
+//! let afd: AreaForecastDiscussion = text.parse().unwrap();
+//! assert_eq!(afd.issuance_time.as_deref(), Some("312 AM MST Sat Oct 18 2025"));
+//! let aviation = afd.section(&AfdSectionKind::Aviation).unwrap();
+//! assert_eq!(aviation.text, "VFR conditions.");
+//! assert_eq!(aviation.forecaster.as_deref(), Some("Jones"));
+//! ```
+
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/// An error returned when a text product is not an Area Forecast Discussion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAfdError {
+    invalid_value: String,
+}
+
+impl ParseAfdError {
+    fn new(value: &str) -> Self {
+        Self {
+            invalid_value: value
+                .lines()
+                .find(|line| !line.trim().is_empty())
+                .unwrap_or_default()
+                .trim()
+                .to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseAfdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid Area Forecast Discussion value: no sections found in product starting with {:?}",
+            self.invalid_value
+        )
+    }
+}
+
+impl std::error::Error for ParseAfdError {}
+
+/// A parsed Area Forecast Discussion.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AreaForecastDiscussion {
+    /// The issuing office line, e.g., `National Weather Service Phoenix AZ`.
+    pub office: Option<String>,
+    /// The local issuance time line, e.g., `312 AM MST Sat Oct 18 2025`.
+    pub issuance_time: Option<String>,
+    /// The sections in product order.
+    pub sections: Vec<AfdSection>,
+    /// The forecaster names from the signature block, without duplicates.
+    pub forecasters: Vec<String>,
+}
+
+impl AreaForecastDiscussion {
+    /// Returns the first section of the given kind.
+    pub fn section(&self, kind: &AfdSectionKind) -> Option<&AfdSection> {
+        self.sections.iter().find(|section| section.kind == *kind)
+    }
+}
+
+impl FromStr for AreaForecastDiscussion {
+    type Err = ParseAfdError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let text = string.replace("\r\n", "\n");
+        let lines: Vec<&str> = text.lines().collect();
+        let end = lines
+            .iter()
+            .position(|line| line.trim() == "$$")
+            .unwrap_or(lines.len());
+        let body = &lines[..end];
+        let signature = lines.get(end + 1..).unwrap_or_default();
+
+        let mut discussion = Self::default();
+        let mut header = body
+            .iter()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty());
+        if header
+            .find(|line| {
+                line.to_ascii_lowercase()
+                    .starts_with("national weather service")
+            })
+            .map(|line| discussion.office = Some(line.to_string()))
+            .is_some()
+        {
+            discussion.issuance_time = header.next().map(str::to_string);
+        }
+
+        let mut current: Option<(String, Vec<&str>)> = None;
+        for line in body {
+            let line = line.trim_end();
+            if let Some((heading, rest)) = section_heading(line) {
+                discussion.push_section(current.take());
+                let first_line = rest.trim();
+                current = Some((
+                    heading.to_string(),
+                    if first_line.is_empty() {
+                        Vec::new()
+                    } else {
+                        vec![first_line]
+                    },
+                ));
+            } else if line.trim() == "&&" {
+                discussion.push_section(current.take());
+            } else if let Some((_, lines)) = current.as_mut() {
+                lines.push(line);
+            }
+        }
+        discussion.push_section(current);
+        if discussion.sections.is_empty() {
+            return Err(ParseAfdError::new(string));
+        }
+
+        for line in signature
+            .iter()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty())
+        {
+            let name = match line.split_once("...") {
+                Some((label, name)) => {
+                    let kind = AfdSectionKind::from_heading(label);
+                    let name = name.trim().trim_matches('.').trim();
+                    for section in discussion
+                        .sections
+                        .iter_mut()
+                        .filter(|section| section.kind == kind && section.forecaster.is_none())
+                    {
+                        section.forecaster = Some(name.to_string());
+                    }
+                    name
+                }
+                None => line.trim_start_matches("Forecaster:").trim(),
+            };
+            if !name.is_empty() && !discussion.forecasters.iter().any(|known| known == name) {
+                discussion.forecasters.push(name.to_string());
+            }
+        }
+        Ok(discussion)
+    }
+}
+
+impl AreaForecastDiscussion {
+    fn push_section(&mut self, section: Option<(String, Vec<&str>)>) {
+        let Some((heading, lines)) = section else {
+            return;
+        };
+        self.sections.push(AfdSection {
+            kind: AfdSectionKind::from_heading(&heading),
+            heading,
+            text: lines.join("\n").trim_matches('\n').to_string(),
+            forecaster: None,
+        });
+    }
+}
+
+/// A section of an Area Forecast Discussion.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AfdSection {
+    /// The kind of section.
+    pub kind: AfdSectionKind,
+    /// The heading without the surrounding dots, e.g., `SHORT TERM /TODAY THROUGH SUNDAY/`.
+    pub heading: String,
+    /// The section text, including any text on the heading line after the dots.
+    pub text: String,
+    /// The forecaster credited with the section in the signature block.
+    pub forecaster: Option<String>,
+}
+
+/// The kind of an Area Forecast Discussion section.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AfdSectionKind {
+    KeyMessages,
+    Update,
+    Synopsis,
+    Discussion,
+    NearTerm,
+    ShortTerm,
+    LongTerm,
+    Aviation,
+    Marine,
+    FireWeather,
+    Hydrology,
+    Climate,
+    WatchesWarningsAdvisories,
+    /// Any other section, by its heading.
+    Other(String),
+}
+
+impl AfdSectionKind {
+    /// Returns the kind of section for a heading such as `SHORT TERM /TODAY THROUGH SUNDAY/`.
+    pub fn from_heading(heading: &str) -> Self {
+        let heading = heading.trim().to_ascii_uppercase();
+        let name = heading.replace(['-', '_'], " ");
+        if name.contains("WATCHES/WARNINGS/ADVISORIES") || name == "WWA" {
+            return Self::WatchesWarningsAdvisories;
+        }
+        let name = name.split(" /").next().unwrap_or_default().trim();
+        match name {
+            "KEY MESSAGES" => Self::KeyMessages,
+            "UPDATE" => Self::Update,
+            "SYNOPSIS" => Self::Synopsis,
+            "DISCUSSION" => Self::Discussion,
+            "NEAR TERM" => Self::NearTerm,
+            "SHORT TERM" => Self::ShortTerm,
+            "LONG TERM" => Self::LongTerm,
+            "AVIATION" => Self::Aviation,
+            "MARINE" => Self::Marine,
+            "FIRE WEATHER" => Self::FireWeather,
+            "HYDROLOGY" => Self::Hydrology,
+            "CLIMATE" => Self::Climate,
+            _ => Self::Other(heading),
+        }
+    }
+}
+
+impl fmt::Display for AfdSectionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::KeyMessages => write!(f, "KEY MESSAGES"),
+            Self::Update => write!(f, "UPDATE"),
+            Self::Synopsis => write!(f, "SYNOPSIS"),
+            Self::Discussion => write!(f, "DISCUSSION"),
+            Self::NearTerm => write!(f, "NEAR TERM"),
+            Self::ShortTerm => write!(f, "SHORT TERM"),
+            Self::LongTerm => write!(f, "LONG TERM"),
+            Self::Aviation => write!(f, "AVIATION"),
+            Self::Marine => write!(f, "MARINE"),
+            Self::FireWeather => write!(f, "FIRE WEATHER"),
+            Self::Hydrology => write!(f, "HYDROLOGY"),
+            Self::Climate => write!(f, "CLIMATE"),
+            Self::WatchesWarningsAdvisories => write!(f, "WATCHES/WARNINGS/ADVISORIES"),
+            Self::Other(heading) => write!(f, "{heading}"),
+        }
+    }
+}
+
+/// Parses a section name such as `aviation`, `short-term`, `fire_weather`, or `wwa`.
+///
+/// Names that are not a known section become [`AfdSectionKind::Other`].
+impl FromStr for AfdSectionKind {
+    type Err = Infallible;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_heading(string))
+    }
+}
+
+/// Returns the heading and the rest of the line for a `.HEADING...rest` line.
+fn section_heading(line: &str) -> Option<(&str, &str)> {
+    let heading = line.strip_prefix('.')?;
+    if !heading.starts_with(|character: char| character.is_ascii_uppercase()) {
+        return None;
+    }
+    let (heading, rest) = heading.split_once("...")?;
+    Some((heading.trim(), rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const AFD: &str = "000
+FXUS65 KPSR 181012
+AFDPSR
+
+Area Forecast Discussion
+National Weather Service Phoenix AZ
+312 AM MST Sat Oct 18 2025
+
+.KEY MESSAGES...
+- Above normal temperatures continue through the weekend.
+
+&&
+
+.SHORT TERM /TODAY THROUGH SUNDAY/...
+High pressure remains over the region.
+
+Highs near 95 degrees.
+&&
+
+.LONG TERM /MONDAY THROUGH FRIDAY/...
+A trough brings cooler weather.
+&&
+
+.AVIATION...Updated at 0945Z.
+
+South Central AZ including KPHX, KIWA, KSDL, and KDVT:
+No aviation weather concerns.
+&&
+
+.FIRE WEATHER...
+Minimum humidities of 10-15 percent.
+&&
+
+.PSR WATCHES/WARNINGS/ADVISORIES...
+AZ...None.
+CA...None.
+&&
+
+$$
+
+KEY MESSAGES...Smith
+SHORT TERM...Smith
+LONG TERM...Jones
+AVIATION...Doe
+FIRE WEATHER...Jones
+";
+
+    #[test]
+    fn test_parse_afd() {
+        let afd: AreaForecastDiscussion = AFD.parse().unwrap();
+        assert_eq!(
+            afd.office.as_deref(),
+            Some("National Weather Service Phoenix AZ")
+        );
+        assert_eq!(
+            afd.issuance_time.as_deref(),
+            Some("312 AM MST Sat Oct 18 2025")
+        );
+        assert_eq!(afd.forecasters, vec!["Smith", "Jones", "Doe"]);
+
+        let kinds: Vec<_> = afd.sections.iter().map(|section| &section.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                &AfdSectionKind::KeyMessages,
+                &AfdSectionKind::ShortTerm,
+                &AfdSectionKind::LongTerm,
+                &AfdSectionKind::Aviation,
+                &AfdSectionKind::FireWeather,
+                &AfdSectionKind::WatchesWarningsAdvisories,
+            ]
+        );
+
+        let short_term = afd.section(&AfdSectionKind::ShortTerm).unwrap();
+        assert_eq!(short_term.heading, "SHORT TERM /TODAY THROUGH SUNDAY/");
+        assert_eq!(
+            short_term.text,
+            "High pressure remains over the region.\n\nHighs near 95 degrees."
+        );
+        assert_eq!(short_term.forecaster.as_deref(), Some("Smith"));
+
+        let aviation = afd.section(&AfdSectionKind::Aviation).unwrap();
+        assert!(
+            aviation
+                .text
+                .starts_with("Updated at 0945Z.\n\nSouth Central AZ")
+        );
+        assert_eq!(aviation.forecaster.as_deref(), Some("Doe"));
+
+        let wwa = afd
+            .section(&AfdSectionKind::WatchesWarningsAdvisories)
+            .unwrap();
+        assert_eq!(wwa.text, "AZ...None.\nCA...None.");
+        assert_eq!(wwa.forecaster, None);
+    }
+
+    #[test]
+    fn test_section_kind_from_str() {
+        assert_eq!("aviation".parse(), Ok(AfdSectionKind::Aviation));
+        assert_eq!("short-term".parse(), Ok(AfdSectionKind::ShortTerm));
+        assert_eq!("fire_weather".parse(), Ok(AfdSectionKind::FireWeather));
+        assert_eq!("wwa".parse(), Ok(AfdSectionKind::WatchesWarningsAdvisories));
+        assert_eq!(
+            "tides".parse(),
+            Ok(AfdSectionKind::Other("TIDES".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_parse_afd_without_sections() {
+        let error = "Hazardous Weather Outlook\nNo hazards."
+            .parse::<AreaForecastDiscussion>()
+            .unwrap_err();
+        assert!(error.to_string().contains("Hazardous Weather Outlook"));
+    }
+}
@@ -0,0 +1,456 @@
+//! Typed values for the NWS CAP parameters carried in [`Alert::parameters`](crate::models::Alert::parameters).
+//!
+//! The API returns every parameter as a list of untyped JSON values. The types
+//! in this module parse the commonly used ones into structured values, and are
+//! returned by the accessors on [`Alert`](crate::models::Alert).
+//!
+//! See <https://alerts.weather.gov/#technical-notes-v12> for the parameter definitions.
+
+use std::fmt;
+use std::str::FromStr;
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::LatLon;
+use crate::models::AlertReferencesInner;
+
+/// An error returned when an alert parameter value cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAlertParameterError {
+    invalid_value: String,
+}
+
+impl ParseAlertParameterError {
+    fn new(invalid_value: &str) -> Self {
+        Self {
+            invalid_value: invalid_value.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseAlertParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid alert parameter value: {}", self.invalid_value)
+    }
+}
+
+impl std::error::Error for ParseAlertParameterError {}
+
+/// The motion of a hazard, parsed from the `eventMotionDescription` parameter.
+///
+/// Format: `2025-06-02T01:39:00-00:00...storm...263DEG...21KT...33.58,-112.79 33.4,-112.65`
+///
+/// # Examples
+///
+/// ```
+/// use noaa_weather_client::alert_parameters::EventMotion;
+///
+/// let motion: EventMotion = "2025-06-02T01:39:00-00:00...storm...263DEG...21KT...33.58,-112.79"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(motion.direction, 263);
+/// assert_eq!(motion.speed_knots, 21);
+/// assert_eq!(motion.locations[0].latitude, 33.58);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EventMotion {
+    /// The time the motion was observed.
+    pub time: Timestamp,
+    /// What is moving, usually "storm".
+    pub description: String,
+    /// The direction the hazard is moving from, in degrees true.
+    pub direction: u16,
+    /// The speed of the hazard in knots.
+    pub speed_knots: u16,
+    /// The location of the hazard at `time`; one point for a cell, several for a line.
+    pub locations: Vec<LatLon>,
+}
+
+impl FromStr for EventMotion {
+    type Err = ParseAlertParameterError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseAlertParameterError::new(string);
+        let fields: Vec<&str> = string.split("...").collect();
+        let [time, description, direction, speed, locations] = fields.as_slice() else {
+            return Err(invalid());
+        };
+        let locations = locations
+            .split_whitespace()
+            .map(|point| {
+                let (latitude, longitude) = point.split_once(',')?;
+                Some(LatLon {
+                    latitude: latitude.parse().ok()?,
+                    longitude: longitude.parse().ok()?,
+                })
+            })
+            .collect::<Option<Vec<LatLon>>>()
+            .ok_or_else(invalid)?;
+        Ok(Self {
+            time: time.parse().map_err(|_| invalid())?,
+            description: (*description).to_owned(),
+            direction: direction
+                .strip_suffix("DEG")
+                .and_then(|value| value.parse().ok())
+                .ok_or_else(invalid)?,
+            speed_knots: speed
+                .strip_suffix("KT")
+                .and_then(|value| value.parse().ok())
+                .ok_or_else(invalid)?,
+            locations,
+        })
+    }
+}
+
+/// The unit of a [`WindGust`] value.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum WindSpeedUnit {
+    MilesPerHour,
+    Knots,
+}
+
+/// A maximum wind gust, parsed from the `maxWindGust` parameter (e.g., "60 MPH").
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WindGust {
+    pub value: f64,
+    pub unit: WindSpeedUnit,
+}
+
+impl WindGust {
+    /// Returns the gust speed in miles per hour.
+    pub fn mph(&self) -> f64 {
+        match self.unit {
+            WindSpeedUnit::MilesPerHour => self.value,
+            WindSpeedUnit::Knots => self.value * 1.150_779,
+        }
+    }
+}
+
+impl FromStr for WindGust {
+    type Err = ParseAlertParameterError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseAlertParameterError::new(string);
+        let mut tokens = string.split_whitespace().rev();
+        let unit = match tokens.next().map(str::to_ascii_uppercase).as_deref() {
+            Some("MPH") => WindSpeedUnit::MilesPerHour,
+            Some("KT" | "KTS" | "KNOTS") => WindSpeedUnit::Knots,
+            _ => return Err(invalid()),
+        };
+        let value = tokens
+            .next()
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(invalid)?;
+        Ok(Self { value, unit })
+    }
+}
+
+/// Parses a hail size in inches from the `maxHailSize` parameter (e.g., "1.00" or "Up to .75").
+pub fn parse_hail_size(string: &str) -> Result<f64, ParseAlertParameterError> {
+    string
+        .split_whitespace()
+        .last()
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| ParseAlertParameterError::new(string))
+}
+
+/// How a tornado was detected, from the `tornadoDetection` parameter.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum TornadoDetection {
+    RadarIndicated,
+    Observed,
+    Possible,
+    /// A value not covered by the other variants.
+    Other(String),
+}
+
+impl From<&str> for TornadoDetection {
+    fn from(string: &str) -> Self {
+        match string.to_ascii_uppercase().as_str() {
+            "RADAR INDICATED" => Self::RadarIndicated,
+            "OBSERVED" => Self::Observed,
+            "POSSIBLE" => Self::Possible,
+            _ => Self::Other(string.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for TornadoDetection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RadarIndicated => write!(f, "RADAR INDICATED"),
+            Self::Observed => write!(f, "OBSERVED"),
+            Self::Possible => write!(f, "POSSIBLE"),
+            Self::Other(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// An impact-based damage threat tag, from the `thunderstormDamageThreat`,
+/// `tornadoDamageThreat`, and `flashFloodDamageThreat` parameters.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum DamageThreat {
+    Considerable,
+    Destructive,
+    Catastrophic,
+    /// A value not covered by the other variants.
+    Other(String),
+}
+
+impl From<&str> for DamageThreat {
+    fn from(string: &str) -> Self {
+        match string.to_ascii_uppercase().as_str() {
+            "CONSIDERABLE" => Self::Considerable,
+            "DESTRUCTIVE" => Self::Destructive,
+            "CATASTROPHIC" => Self::Catastrophic,
+            _ => Self::Other(string.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for DamageThreat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Considerable => write!(f, "CONSIDERABLE"),
+            Self::Destructive => write!(f, "DESTRUCTIVE"),
+            Self::Catastrophic => write!(f, "CATASTROPHIC"),
+            Self::Other(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// A WMO abbreviated heading, from the `WMOidentifier` parameter (e.g., "WUUS55 KPSR 012300").
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct WmoIdentifier {
+    /// The data type designator and geographic indicator (e.g., "WUUS55").
+    pub data_type: String,
+    /// The four-letter identifier of the issuing station (e.g., "KPSR").
+    pub station: String,
+    /// The issuance day of month, hour, and minute in UTC (`DDHHMM`).
+    pub issued: String,
+    /// The optional BBB indicator for amendments, corrections, or delays (e.g., "CCA").
+    pub bbb: Option<String>,
+}
+
+impl FromStr for WmoIdentifier {
+    type Err = ParseAlertParameterError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = string.split_whitespace().collect();
+        match fields.as_slice() {
+            [data_type, station, issued, rest @ ..]
+                if rest.len() <= 1
+                    && data_type.len() == 6
+                    && station.len() == 4
+                    && issued.len() == 6
+                    && issued.chars().all(|c| c.is_ascii_digit()) =>
+            {
+                Ok(Self {
+                    data_type: (*data_type).to_owned(),
+                    station: (*station).to_owned(),
+                    issued: (*issued).to_owned(),
+                    bbb: rest.first().map(|bbb| (*bbb).to_owned()),
+                })
+            }
+            _ => Err(ParseAlertParameterError::new(string)),
+        }
+    }
+}
+
+impl fmt::Display for WmoIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.data_type, self.station, self.issued)?;
+        if let Some(bbb) = &self.bbb {
+            write!(f, " {bbb}")?;
+        }
+        Ok(())
+    }
+}
+
+/// An AWIPS product identifier, from the `AWIPSidentifier` parameter (e.g., "SVRPSR").
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub struct AwipsIdentifier {
+    /// The three-character product category (e.g., "SVR").
+    pub product_category: String,
+    /// The location identifier, usually the issuing office (e.g., "PSR").
+    pub location: String,
+}
+
+impl FromStr for AwipsIdentifier {
+    type Err = ParseAlertParameterError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim();
+        if !(4..=6).contains(&string.len()) || !string.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(ParseAlertParameterError::new(string));
+        }
+        let (product_category, location) = string.split_at(3);
+        Ok(Self {
+            product_category: product_category.to_owned(),
+            location: location.to_owned(),
+        })
+    }
+}
+
+impl fmt::Display for AwipsIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.product_category, self.location)
+    }
+}
+
+/// Parses the `expiredReferences` parameter into alert references.
+///
+/// The value is a space-separated list of `sender,identifier,sent` triplets
+/// naming prior alerts that have been superseded and are no longer referenced.
+pub fn parse_expired_references(
+    string: &str,
+) -> Result<Vec<AlertReferencesInner>, ParseAlertParameterError> {
+    string
+        .split_whitespace()
+        .map(|reference| {
+            let fields: Vec<&str> = reference.split(',').collect();
+            let [sender, identifier, sent] = fields.as_slice() else {
+                return Err(ParseAlertParameterError::new(reference));
+            };
+            Ok(AlertReferencesInner {
+                at_id: None,
+                identifier: Some((*identifier).to_owned()),
+                sender: Some((*sender).to_owned()),
+                sent: Some((*sent).to_owned()),
+            })
+        })
+        .collect()
+}
+
+/// A dissemination channel an alert should not be sent to, from the `BLOCKCHANNEL` parameter.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum BlockChannel {
+    /// The Emergency Alert System.
+    Eas,
+    /// Non-Weather Emergency Messages on NOAA Weather Radio.
+    Nwem,
+    /// Wireless Emergency Alerts, formerly the Commercial Mobile Alert System.
+    Cmas,
+    /// A value not covered by the other variants.
+    Other(String),
+}
+
+impl From<&str> for BlockChannel {
+    fn from(string: &str) -> Self {
+        match string.to_ascii_uppercase().as_str() {
+            "EAS" => Self::Eas,
+            "NWEM" => Self::Nwem,
+            "CMAS" => Self::Cmas,
+            _ => Self::Other(string.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for BlockChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Eas => write!(f, "EAS"),
+            Self::Nwem => write!(f, "NWEM"),
+            Self::Cmas => write!(f, "CMAS"),
+            Self::Other(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_event_motion_line() {
+        let motion: EventMotion =
+            "2025-06-02T01:39:00-00:00...storm...263DEG...21KT...33.58,-112.79 33.4,-112.65"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            motion.time,
+            "2025-06-02T01:39:00Z".parse::<Timestamp>().unwrap()
+        );
+        assert_eq!(motion.description, "storm");
+        assert_eq!(motion.direction, 263);
+        assert_eq!(motion.speed_knots, 21);
+        assert_eq!(motion.locations.len(), 2);
+        assert_eq!(motion.locations[1].longitude, -112.65);
+    }
+
+    #[test]
+    fn test_parse_event_motion_err() {
+        assert!("storm...263DEG...21KT".parse::<EventMotion>().is_err());
+        assert!(
+            "2025-06-02T01:39:00-00:00...storm...263...21KT...33.58,-112.79"
+                .parse::<EventMotion>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_wind_gust() {
+        let gust: WindGust = "60 MPH".parse().unwrap();
+        assert_eq!(gust.value, 60.0);
+        assert_eq!(gust.unit, WindSpeedUnit::MilesPerHour);
+        let gust: WindGust = "Up to 40 KT".parse().unwrap();
+        assert_eq!(gust.unit, WindSpeedUnit::Knots);
+        assert!((gust.mph() - 46.03).abs() < 0.01);
+        assert!("60".parse::<WindGust>().is_err());
+    }
+
+    #[test]
+    fn test_parse_hail_size() {
+        assert_eq!(parse_hail_size("1.00"), Ok(1.0));
+        assert_eq!(parse_hail_size("Up to .75"), Ok(0.75));
+        assert!(parse_hail_size("").is_err());
+    }
+
+    #[test]
+    fn test_parse_identifiers() {
+        let wmo: WmoIdentifier = "WUUS55 KPSR 012300".parse().unwrap();
+        assert_eq!(wmo.station, "KPSR");
+        assert_eq!(wmo.bbb, None);
+        assert_eq!(wmo.to_string(), "WUUS55 KPSR 012300");
+        let wmo: WmoIdentifier = "WWUS85 KPSR 012300 CCA".parse().unwrap();
+        assert_eq!(wmo.bbb.as_deref(), Some("CCA"));
+
+        let awips: AwipsIdentifier = "SVRPSR".parse().unwrap();
+        assert_eq!(awips.product_category, "SVR");
+        assert_eq!(awips.location, "PSR");
+        assert!("SV".parse::<AwipsIdentifier>().is_err());
+    }
+
+    #[test]
+    fn test_parse_expired_references() {
+        let references = parse_expired_references(
+            "w-nws.webmaster@noaa.gov,urn:oid:2.49.0.1.840.0.abc.001.1,2025-06-01T17:00:00-07:00 \
+             w-nws.webmaster@noaa.gov,urn:oid:2.49.0.1.840.0.def.002.1,2025-06-01T16:00:00-07:00",
+        )
+        .unwrap();
+        assert_eq!(references.len(), 2);
+        assert_eq!(
+            references[0].identifier.as_deref(),
+            Some("urn:oid:2.49.0.1.840.0.abc.001.1")
+        );
+        assert_eq!(
+            references[1].sent.as_deref(),
+            Some("2025-06-01T16:00:00-07:00")
+        );
+    }
+
+    #[test]
+    fn test_parse_enums() {
+        assert_eq!(
+            TornadoDetection::from("RADAR INDICATED"),
+            TornadoDetection::RadarIndicated
+        );
+        assert_eq!(DamageThreat::from("DESTRUCTIVE"), DamageThreat::Destructive);
+        assert_eq!(BlockChannel::from("CMAS"), BlockChannel::Cmas);
+        assert_eq!(
+            BlockChannel::from("IPAWS"),
+            BlockChannel::Other("IPAWS".to_string())
+        );
+    }
+}
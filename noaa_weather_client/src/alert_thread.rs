@@ -0,0 +1,353 @@
+//! Grouping of alerts into threads that follow an event through its updates.
+//!
+//! Each NWS alert update or cancellation is a new message that lists the
+//! messages it replaces in [`Alert::references`]. This module follows those
+//! references to group a set of alerts into threads (original, updates, then
+//! cancellation or expiration), resolves the current state of each thread, and
+//! identifies superseded alerts so callers can show only the latest version.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::alert_thread::{self, AlertThreadState};
+//! use noaa_weather_client::models::{Alert, AlertMessageType, AlertReferencesInner};
+//!
+//! let original = Alert {
+//!     id: Some("urn:oid:1".to_string()),
+//!     sent: Some("2025-06-01T16:00:00-07:00".to_string()),
+//!     message_type: Some(AlertMessageType::Alert),
+//!     ..Alert::new()
+//! };
+//! let cancel = Alert {
+//!     id: Some("urn:oid:2".to_string()),
+//!     sent: Some("2025-06-01T16:30:00-07:00".to_string()),
+//!     message_type: Some(AlertMessageType::Cancel),
+//!     references: Some(vec![AlertReferencesInner {
+//!         identifier: Some("urn:oid:1".to_string()),
+//!         ..AlertReferencesInner::new()
+//!     }]),
+//!     ..Alert::new()
+//! };
+//! let alerts = [original, cancel];
+//!
+//! let threads = alert_thread::group_alerts(&alerts);
+//! assert_eq!(threads.len(), 1);
+//! assert_eq!(threads[0].latest().id.as_deref(), Some("urn:oid:2"));
+//! assert_eq!(
+//!     threads[0].state_at(jiff::Timestamp::now()),
+//!     AlertThreadState::Cancelled
+//! );
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use jiff::Timestamp;
+
+use crate::models::{Alert, AlertMessageType};
+use crate::vtec::VtecAction;
+
+/// The current state of the event an alert thread describes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum AlertThreadState {
+    /// The latest alert is in effect and has not been updated.
+    Active,
+    /// The latest alert is an update to an earlier alert and is in effect.
+    Updated,
+    /// The event was cancelled before it expired.
+    Cancelled,
+    /// The event ran to its end time, or was marked expired by its issuer.
+    Expired,
+}
+
+impl fmt::Display for AlertThreadState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Active => write!(f, "Active"),
+            Self::Updated => write!(f, "Updated"),
+            Self::Cancelled => write!(f, "Cancelled"),
+            Self::Expired => write!(f, "Expired"),
+        }
+    }
+}
+
+/// A chain of alerts connected by their references, oldest first.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertThread<'a> {
+    alerts: Vec<&'a Alert>,
+}
+
+impl<'a> AlertThread<'a> {
+    /// Returns the alerts in the thread, ordered by the time they were sent.
+    pub fn alerts(&self) -> &[&'a Alert] {
+        &self.alerts
+    }
+
+    /// Returns the earliest alert in the thread.
+    pub fn original(&self) -> &'a Alert {
+        self.alerts[0]
+    }
+
+    /// Returns the most recently sent alert in the thread.
+    pub fn latest(&self) -> &'a Alert {
+        self.alerts[self.alerts.len() - 1]
+    }
+
+    /// Returns every alert except the latest, which have all been replaced.
+    pub fn superseded(&self) -> &[&'a Alert] {
+        &self.alerts[..self.alerts.len() - 1]
+    }
+
+    /// Resolves the state of the event as of `now`.
+    ///
+    /// Cancellation and expiration are taken from the latest alert's message
+    /// type and VTEC actions; otherwise the event is expired once the latest
+    /// alert's end time (or expiry time, if no end time is given) has passed.
+    pub fn state_at(&self, now: Timestamp) -> AlertThreadState {
+        let latest = self.latest();
+        let actions: Vec<VtecAction> = latest.vtec().iter().map(|vtec| vtec.action).collect();
+        let all_actions = |action: VtecAction| {
+            !actions.is_empty() && actions.iter().all(|&candidate| candidate == action)
+        };
+        if latest.message_type == Some(AlertMessageType::Cancel) || all_actions(VtecAction::Cancel)
+        {
+            return AlertThreadState::Cancelled;
+        }
+        if all_actions(VtecAction::Expire) {
+            return AlertThreadState::Expired;
+        }
+        let end = latest
+            .ends
+            .clone()
+            .flatten()
+            .or_else(|| latest.expires.clone())
+            .and_then(|end| end.parse::<Timestamp>().ok());
+        if end.is_some_and(|end| end <= now) {
+            return AlertThreadState::Expired;
+        }
+        if self.alerts.len() > 1 || latest.message_type == Some(AlertMessageType::Update) {
+            AlertThreadState::Updated
+        } else {
+            AlertThreadState::Active
+        }
+    }
+}
+
+/// Groups alerts into threads by following their references.
+///
+/// Alerts are connected when one lists the other in its `references`, directly
+/// or through a chain of other alerts in the set. Alerts without an `id` form
+/// threads of their own. Threads are returned in the order their latest alert
+/// was sent, oldest first.
+pub fn group_alerts(alerts: &[Alert]) -> Vec<AlertThread<'_>> {
+    let mut parents: Vec<usize> = (0..alerts.len()).collect();
+    let index_by_id: HashMap<&str, usize> = alerts
+        .iter()
+        .enumerate()
+        .filter_map(|(index, alert)| Some((alert.id.as_deref()?, index)))
+        .collect();
+    for (index, alert) in alerts.iter().enumerate() {
+        for reference in referenced_ids(alert) {
+            if let Some(&referenced) = index_by_id.get(reference.as_str()) {
+                union(&mut parents, index, referenced);
+            }
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<&Alert>> = HashMap::new();
+    for (index, alert) in alerts.iter().enumerate() {
+        let root = find(&mut parents, index);
+        groups.entry(root).or_default().push(alert);
+    }
+    let mut threads: Vec<AlertThread<'_>> = groups
+        .into_values()
+        .map(|mut alerts| {
+            alerts.sort_by_key(|alert| sent_time(alert));
+            AlertThread { alerts }
+        })
+        .collect();
+    threads.sort_by_key(|thread| sent_time(thread.latest()));
+    threads
+}
+
+/// Returns the IDs of alerts that have been replaced by an alert in the set.
+pub fn superseded_ids(alerts: &[Alert]) -> HashSet<String> {
+    alerts.iter().flat_map(referenced_ids).collect()
+}
+
+/// Returns only the latest alert of each thread, dropping superseded versions.
+pub fn latest_alerts(alerts: &[Alert]) -> Vec<&Alert> {
+    group_alerts(alerts)
+        .iter()
+        .map(AlertThread::latest)
+        .collect()
+}
+
+/// Returns the identifiers of the alerts this alert replaces, including expired references.
+///
+/// Expired references are read with [`Alert::expired_references`], so malformed
+/// `expiredReferences` values are skipped.
+fn referenced_ids(alert: &Alert) -> Vec<String> {
+    alert
+        .references
+        .iter()
+        .flatten()
+        .cloned()
+        .chain(alert.expired_references())
+        .filter_map(|reference| reference.identifier)
+        .collect()
+}
+
+/// Parses the time an alert was sent, sorting alerts without one first.
+fn sent_time(alert: &Alert) -> Option<Timestamp> {
+    alert.sent.as_deref()?.parse().ok()
+}
+
+fn find(parents: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while parents[root] != root {
+        root = parents[root];
+    }
+    let mut current = index;
+    while parents[current] != root {
+        let next = parents[current];
+        parents[current] = root;
+        current = next;
+    }
+    root
+}
+
+fn union(parents: &mut [usize], first: usize, second: usize) {
+    let first = find(parents, first);
+    let second = find(parents, second);
+    if first != second {
+        parents[second] = first;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::AlertReferencesInner;
+
+    fn alert(id: &str, sent: &str, message_type: AlertMessageType, references: &[&str]) -> Alert {
+        Alert {
+            id: Some(id.to_owned()),
+            sent: Some(sent.to_owned()),
+            expires: Some("2025-06-02T00:00:00Z".to_owned()),
+            message_type: Some(message_type),
+            references: Some(
+                references
+                    .iter()
+                    .map(|reference| AlertReferencesInner {
+                        identifier: Some((*reference).to_owned()),
+                        ..AlertReferencesInner::new()
+                    })
+                    .collect(),
+            ),
+            ..Alert::new()
+        }
+    }
+
+    fn now() -> Timestamp {
+        "2025-06-01T20:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn test_group_alerts_into_chains() {
+        let alerts = [
+            alert(
+                "c",
+                "2025-06-01T19:00:00Z",
+                AlertMessageType::Update,
+                &["b"],
+            ),
+            alert("a", "2025-06-01T17:00:00Z", AlertMessageType::Alert, &[]),
+            alert("x", "2025-06-01T18:30:00Z", AlertMessageType::Alert, &[]),
+            alert(
+                "b",
+                "2025-06-01T18:00:00Z",
+                AlertMessageType::Update,
+                &["a"],
+            ),
+        ];
+        let threads = group_alerts(&alerts);
+        assert_eq!(threads.len(), 2);
+        assert_eq!(threads[0].latest().id.as_deref(), Some("x"));
+        assert_eq!(threads[0].state_at(now()), AlertThreadState::Active);
+
+        let chain = &threads[1];
+        let ids: Vec<_> = chain.alerts().iter().map(|a| a.id.as_deref()).collect();
+        assert_eq!(ids, [Some("a"), Some("b"), Some("c")]);
+        assert_eq!(chain.original().id.as_deref(), Some("a"));
+        assert_eq!(chain.superseded().len(), 2);
+        assert_eq!(chain.state_at(now()), AlertThreadState::Updated);
+    }
+
+    #[test]
+    fn test_thread_state_cancelled_and_expired() {
+        let alerts = [
+            alert("a", "2025-06-01T17:00:00Z", AlertMessageType::Alert, &[]),
+            alert(
+                "b",
+                "2025-06-01T18:00:00Z",
+                AlertMessageType::Cancel,
+                &["a"],
+            ),
+        ];
+        let threads = group_alerts(&alerts);
+        assert_eq!(threads[0].state_at(now()), AlertThreadState::Cancelled);
+
+        let alerts = [alert(
+            "a",
+            "2025-06-01T17:00:00Z",
+            AlertMessageType::Alert,
+            &[],
+        )];
+        let threads = group_alerts(&alerts);
+        let later: Timestamp = "2025-06-02T01:00:00Z".parse().unwrap();
+        assert_eq!(threads[0].state_at(later), AlertThreadState::Expired);
+    }
+
+    #[test]
+    fn test_latest_alerts_and_superseded_ids() {
+        let alerts = [
+            alert("a", "2025-06-01T17:00:00Z", AlertMessageType::Alert, &[]),
+            alert(
+                "b",
+                "2025-06-01T18:00:00Z",
+                AlertMessageType::Update,
+                &["a"],
+            ),
+            alert("x", "2025-06-01T18:30:00Z", AlertMessageType::Alert, &[]),
+        ];
+        let latest: Vec<_> = latest_alerts(&alerts)
+            .iter()
+            .map(|alert| alert.id.as_deref())
+            .collect();
+        assert_eq!(latest, [Some("b"), Some("x")]);
+        assert_eq!(superseded_ids(&alerts), HashSet::from(["a".to_owned()]));
+    }
+
+    #[test]
+    fn test_expired_references_join_threads() {
+        let mut expiring = alert("c", "2025-06-01T19:00:00Z", AlertMessageType::Alert, &[]);
+        expiring.parameters = Some(
+            [(
+                "expiredReferences".to_owned(),
+                vec![
+                    "w-nws.webmaster@noaa.gov,a,2025-06-01T17:00:00-00:00".into(),
+                    "w-nws.webmaster@noaa.gov,b".into(),
+                ],
+            )]
+            .into(),
+        );
+        let alerts = [
+            alert("a", "2025-06-01T17:00:00Z", AlertMessageType::Alert, &[]),
+            alert("b", "2025-06-01T18:00:00Z", AlertMessageType::Alert, &[]),
+            expiring,
+        ];
+        assert_eq!(superseded_ids(&alerts), HashSet::from(["a".to_owned()]));
+        assert_eq!(group_alerts(&alerts).len(), 2);
+    }
+}
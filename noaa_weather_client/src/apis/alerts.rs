@@ -20,6 +20,16 @@ pub enum ActiveAlertsError {
     UnknownValue(serde_json::Value),
 }
 
+/// Errors that can occur when calling the [`get_active_alerts_atom`] function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ActiveAlertsAtomError {
+    /// Standard NWS API problem detail response.
+    DefaultResponse(models::ProblemDetail),
+    /// An unexpected error occurred (e.g., invalid JSON returned by the API).
+    UnknownValue(serde_json::Value),
+}
+
 /// Errors that can occur when calling the [`get_active_alerts_for_area`] function.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -80,6 +90,16 @@ pub enum GetAlertError {
     UnknownValue(serde_json::Value),
 }
 
+/// Errors that can occur when calling the [`get_alert_cap`] function.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetAlertCapError {
+    /// Standard NWS API problem detail response.
+    DefaultResponse(models::ProblemDetail),
+    /// An unexpected error occurred (e.g., invalid JSON returned by the API).
+    UnknownValue(serde_json::Value),
+}
+
 /// Errors that can occur when calling the [`get_alert_types`] function.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -187,6 +207,136 @@ pub async fn get_active_alerts(
     let uri_str = format!("{}/alerts/active", configuration.base_path);
     let mut req_builder = configuration.client.request(reqwest::Method::GET, &uri_str);
 
+    req_builder = active_alerts_query(req_builder, &params);
+    if let Some(user_agent) = &configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(api_key) = &configuration.api_key {
+        req_builder = req_builder.header(API_KEY_HEADER, api_key.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|header| header.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        match content_type {
+            ContentType::Json => resp.json().await.map_err(Error::from),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `AlertCollectionGeoJson`",
+            ))),
+            ContentType::Xml => Err(Error::from(serde_json::Error::custom(
+                "Received `application/xml` content type response that cannot be converted to `AlertCollectionGeoJson`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `AlertCollectionGeoJson`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ActiveAlertsError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            content,
+            entity,
+            status,
+        }))
+    }
+}
+
+/// Returns all currently active alerts as an Atom feed.
+///
+/// Corresponds to the `/alerts/active` endpoint, requested with an
+/// `Accept: application/atom+xml` header. Accepts the same filters as
+/// [`get_active_alerts`].
+///
+/// # Parameters
+///
+/// * `configuration`: The API client configuration.
+/// * `params`: A [`ActiveAlertsParams`] struct containing the query parameters.
+///
+/// # Returns
+///
+/// A `Result` containing a [`models::AlertAtomFeed`] on success, with one
+/// entry per active alert matching the criteria.
+///
+/// # Errors
+///
+/// Returns an [`Error<ActiveAlertsAtomError>`] if the request fails or the response
+/// cannot be parsed.
+pub async fn get_active_alerts_atom(
+    configuration: &configuration::Configuration,
+    params: ActiveAlertsParams<'_>,
+) -> Result<models::AlertAtomFeed, Error<ActiveAlertsAtomError>> {
+    let uri_str = format!("{}/alerts/active", configuration.base_path);
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::GET, &uri_str)
+        .header(reqwest::header::ACCEPT, "application/atom+xml");
+    req_builder = active_alerts_query(req_builder, &params);
+
+    if let Some(user_agent) = &configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(api_key) = &configuration.api_key {
+        req_builder = req_builder.header(API_KEY_HEADER, api_key.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|header| header.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Xml => {
+                let mut deserializer = quick_xml::de::Deserializer::from_str(&content);
+                let feed =
+                    models::AlertAtomFeed::deserialize(&mut deserializer).map_err(Error::Xml)?;
+                Ok(feed)
+            }
+            ContentType::Json => Err(Error::from(serde_json::Error::custom(
+                "Received `application/json` content type response that cannot be converted to `AlertAtomFeed`",
+            ))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `AlertAtomFeed`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `AlertAtomFeed`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<ActiveAlertsAtomError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            content,
+            entity,
+            status,
+        }))
+    }
+}
+
+/// Adds the [`ActiveAlertsParams`] filters to an `/alerts/active` request.
+fn active_alerts_query(
+    mut req_builder: reqwest::RequestBuilder,
+    params: &ActiveAlertsParams<'_>,
+) -> reqwest::RequestBuilder {
     if let Some(param_value) = &params.status {
         req_builder = match "csv" {
             "multi" => req_builder.query(
@@ -373,47 +523,7 @@ pub async fn get_active_alerts(
             )]),
         };
     }
-    if let Some(user_agent) = &configuration.user_agent {
-        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
-    }
-    if let Some(api_key) = &configuration.api_key {
-        req_builder = req_builder.header(API_KEY_HEADER, api_key.clone());
-    }
-
-    let req = req_builder.build()?;
-    let resp = configuration.client.execute(req).await?;
-    let status = resp.status();
-    let content_type = resp
-        .headers()
-        .get(reqwest::header::CONTENT_TYPE)
-        .and_then(|header| header.to_str().ok())
-        .unwrap_or("application/octet-stream");
-    let content_type = super::ContentType::from(content_type);
-
-    if !status.is_client_error() && !status.is_server_error() {
-        match content_type {
-            ContentType::Json => resp.json().await.map_err(Error::from),
-            ContentType::Text => Err(Error::from(serde_json::Error::custom(
-                "Received `text/plain` content type response that cannot be converted to `AlertCollectionGeoJson`",
-            ))),
-            ContentType::Xml => Err(Error::from(serde_json::Error::custom(
-                "Received `application/xml` content type response that cannot be converted to `AlertCollectionGeoJson`",
-            ))),
-            ContentType::Unsupported(unknown_type) => {
-                Err(Error::from(serde_json::Error::custom(format!(
-                    "Received `{unknown_type}` content type response that cannot be converted to `AlertCollectionGeoJson`"
-                ))))
-            }
-        }
-    } else {
-        let content = resp.text().await?;
-        let entity: Option<ActiveAlertsError> = serde_json::from_str(&content).ok();
-        Err(Error::ResponseError(ResponseContent {
-            content,
-            entity,
-            status,
-        }))
-    }
+    req_builder
 }
 
 /// Returns active alerts for the given area (state or marine area).
@@ -1052,6 +1162,89 @@ pub async fn get_alert(
     }
 }
 
+/// Returns an alert by the alert ID in CAP 1.2 XML format.
+///
+/// Corresponds to the `/alerts/{id}` endpoint, requested with an
+/// `Accept: application/cap+xml` header. The result can be converted into the
+/// JSON [`models::Alert`] model with [`From`].
+///
+/// # Parameters
+///
+/// * `configuration`: The API client configuration.
+/// * `id`: The unique identifier of the alert.
+///
+/// # Returns
+///
+/// A `Result` containing a [`models::CapAlert`] on success,
+/// detailing the specific alert.
+///
+/// # Errors
+///
+/// Returns an [`Error<GetAlertCapError>`] if the request fails, the alert ID is not found,
+/// or the response cannot be parsed.
+pub async fn get_alert_cap(
+    configuration: &configuration::Configuration,
+    id: &str,
+) -> Result<models::CapAlert, Error<GetAlertCapError>> {
+    let uri_str = format!(
+        "{}/alerts/{id}",
+        configuration.base_path,
+        id = crate::apis::urlencode(id)
+    );
+    let mut req_builder = configuration
+        .client
+        .request(reqwest::Method::GET, &uri_str)
+        .header(reqwest::header::ACCEPT, "application/cap+xml");
+
+    if let Some(user_agent) = &configuration.user_agent {
+        req_builder = req_builder.header(reqwest::header::USER_AGENT, user_agent.clone());
+    }
+    if let Some(api_key) = &configuration.api_key {
+        req_builder = req_builder.header(API_KEY_HEADER, api_key.clone());
+    }
+
+    let req = req_builder.build()?;
+    let resp = configuration.client.execute(req).await?;
+
+    let status = resp.status();
+    let content_type = resp
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|header| header.to_str().ok())
+        .unwrap_or("application/octet-stream");
+    let content_type = super::ContentType::from(content_type);
+
+    if !status.is_client_error() && !status.is_server_error() {
+        let content = resp.text().await?;
+        match content_type {
+            ContentType::Xml => {
+                let mut deserializer = quick_xml::de::Deserializer::from_str(&content);
+                let alert = models::CapAlert::deserialize(&mut deserializer).map_err(Error::Xml)?;
+                Ok(alert)
+            }
+            ContentType::Json => Err(Error::from(serde_json::Error::custom(
+                "Received `application/json` content type response that cannot be converted to `CapAlert`",
+            ))),
+            ContentType::Text => Err(Error::from(serde_json::Error::custom(
+                "Received `text/plain` content type response that cannot be converted to `CapAlert`",
+            ))),
+            ContentType::Unsupported(unknown_type) => {
+                Err(Error::from(serde_json::Error::custom(format!(
+                    "Received `{unknown_type}` content type response that cannot be converted to `CapAlert`"
+                ))))
+            }
+        }
+    } else {
+        let content = resp.text().await?;
+        let entity: Option<GetAlertCapError> = serde_json::from_str(&content).ok();
+        Err(Error::ResponseError(ResponseContent {
+            content,
+            entity,
+            status,
+        }))
+    }
+}
+
 /// Returns a list of alert types recognized by the NWS API.
 ///
 /// Corresponds to the `/alerts/types` endpoint.
@@ -1119,3 +1312,97 @@ pub async fn get_alert_types(
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{header, method, path, query_param};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const ATOM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom" xmlns:cap="urn:oasis:names:tc:emergency:cap:1.2" xml:lang="en-US">
+    <id>https://api.weather.gov/alerts/active?area=AZ</id>
+    <generator>NWS CAP Server</generator>
+    <updated>2025-06-01T16:35:00-07:00</updated>
+    <author>
+        <name>w-nws.webmaster@noaa.gov</name>
+    </author>
+    <title>Current watches, warnings, and advisories for Arizona issued by the National Weather Service</title>
+    <link href="https://api.weather.gov/alerts/active?area=AZ"/>
+    <entry>
+        <id>https://api.weather.gov/alerts/urn:oid:2.49.0.1.840.0.2</id>
+        <updated>2025-06-01T16:30:00-07:00</updated>
+        <published>2025-06-01T16:30:00-07:00</published>
+        <author>
+            <name>w-nws.webmaster@noaa.gov</name>
+        </author>
+        <link rel="alternate" href="https://api.weather.gov/alerts/urn:oid:2.49.0.1.840.0.2"/>
+        <summary>At 430 PM MST, a severe thunderstorm was located near Phoenix.</summary>
+        <title>Severe Thunderstorm Warning issued June 1 at 4:30PM MST by NWS Phoenix AZ</title>
+        <cap:event>Severe Thunderstorm Warning</cap:event>
+        <cap:sent>2025-06-01T16:30:00-07:00</cap:sent>
+        <cap:effective>2025-06-01T16:30:00-07:00</cap:effective>
+        <cap:onset>2025-06-01T16:30:00-07:00</cap:onset>
+        <cap:expires>2025-06-01T17:00:00-07:00</cap:expires>
+        <cap:status>Actual</cap:status>
+        <cap:msgType>Alert</cap:msgType>
+        <cap:category>Met</cap:category>
+        <cap:urgency>Immediate</cap:urgency>
+        <cap:severity>Severe</cap:severity>
+        <cap:certainty>Observed</cap:certainty>
+        <cap:areaDesc>Maricopa, AZ</cap:areaDesc>
+        <cap:polygon>33.2,-112.5 33.6,-112.5 33.6,-111.9 33.2,-112.5</cap:polygon>
+        <cap:geocode>
+            <valueName>SAME</valueName>
+            <value>004013</value>
+        </cap:geocode>
+        <cap:parameter>
+            <valueName>maxWindGust</valueName>
+            <value>60 MPH</value>
+        </cap:parameter>
+    </entry>
+</feed>"#;
+
+    #[tokio::test]
+    async fn test_get_active_alerts_atom() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/alerts/active"))
+            .and(query_param("area", "AZ"))
+            .and(header("accept", "application/atom+xml"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw(ATOM, "application/atom+xml"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let configuration = configuration::Configuration::new(None, Some(server.uri()), None, None);
+        let params = ActiveAlertsParams {
+            area: Some(vec![AreaCode::StateTerritoryCode(
+                models::StateTerritoryCode::Az,
+            )]),
+            ..Default::default()
+        };
+        let feed = get_active_alerts_atom(&configuration, params)
+            .await
+            .unwrap();
+        assert_eq!(feed.generator.as_deref(), Some("NWS CAP Server"));
+
+        let entries = feed.entry.unwrap();
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(
+            entry.id.as_deref(),
+            Some("https://api.weather.gov/alerts/urn:oid:2.49.0.1.840.0.2")
+        );
+        assert_eq!(
+            entry.title.as_deref(),
+            Some("Severe Thunderstorm Warning issued June 1 at 4:30PM MST by NWS Phoenix AZ")
+        );
+        assert_eq!(entry.event.as_deref(), Some("Severe Thunderstorm Warning"));
+        assert_eq!(entry.severity.as_deref(), Some("Severe"));
+        assert_eq!(entry.area_desc.as_deref(), Some("Maricopa, AZ"));
+        let parameter = &entry.parameter.as_ref().unwrap()[0];
+        assert_eq!(parameter.value_name.as_deref(), Some("maxWindGust"));
+        assert_eq!(parameter.value.as_deref(), Some("60 MPH"));
+    }
+}
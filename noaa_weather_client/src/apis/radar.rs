@@ -119,7 +119,9 @@ pub struct RadarDataQueueQueryParams<'a> {
 ///
 /// A `Result` containing a [`serde_json::Value`] on success, representing the profiler metadata.
 ///
-/// *Note: The exact structure of the returned JSON is unknown.*
+/// *Note: The exact structure of the returned JSON is unknown. It can be converted with
+/// `serde_json::from_value` into a best-effort [`models::RadarWindProfiler`], which keeps
+/// any fields it does not model in its `additional` map.*
 ///
 /// # Errors
 ///
@@ -0,0 +1,436 @@
+//! Decoder for daily climate report (`CLI`) text products.
+//!
+//! Climate reports summarize a climate site's day: the observed high and low
+//! temperatures with their normals and records, precipitation and snowfall
+//! totals for the day, month, and season, and heating and cooling degree
+//! days. Each row lists the observed value followed by the record, normal,
+//! departure from normal, and last year's value:
+//!
+//! ```text
+//! WEATHER ITEM   OBSERVED TIME   RECORD YEAR NORMAL DEPARTURE LAST
+//!                 VALUE   (LST)  VALUE       VALUE  FROM      YEAR
+//! TEMPERATURE (F)
+//!  YESTERDAY
+//!   MAXIMUM         95    308 PM 104    1950  89      6       91
+//! ```
+//!
+//! Missing values (`MM`) are `None` and trace amounts (`T`) are [`TRACE`].
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::climate::ClimateReport;
+//!
+//! let text = "...THE PHOENIX CLIMATE SUMMARY FOR OCTOBER 18 2025...\n\nTEMPERATURE (F)\n YESTERDAY\n  MAXIMUM         95    308 PM 104    1950  89      6       91\n";
+//! let report: ClimateReport = text.parse().unwrap();
+//! assert_eq!(report.station.as_deref(), Some("PHOENIX"));
+//! let maximum = report.maximum_temperature().unwrap();
+//! assert_eq!(maximum.observed, Some(95.0));
+//! assert_eq!(maximum.record_year, Some(1950));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use jiff::civil::Date;
+use serde::{Deserialize, Serialize};
+
+use crate::product_header;
+
+/// The value used for trace amounts of precipitation or snowfall.
+pub const TRACE: f64 = 0.0001;
+
+/// An error returned when a text product is not a daily climate report.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseClimateReportError {
+    invalid_value: String,
+}
+
+impl ParseClimateReportError {
+    fn new(value: &str) -> Self {
+        Self {
+            invalid_value: value
+                .lines()
+                .map(str::trim)
+                .find(|line| !line.is_empty())
+                .unwrap_or_default()
+                .to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseClimateReportError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid climate report value: no climate summary found in product starting with {:?}",
+            self.invalid_value
+        )
+    }
+}
+
+impl std::error::Error for ParseClimateReportError {}
+
+/// A parsed daily climate report.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClimateReport {
+    /// The climate site name, e.g., `PHOENIX`.
+    pub station: Option<String>,
+    /// The day the report summarizes.
+    pub date: Option<Date>,
+    /// The `MAXIMUM`, `MINIMUM`, and `AVERAGE` temperature rows.
+    pub temperature: Vec<ClimateValue>,
+    /// The precipitation rows, e.g., `YESTERDAY`, `MONTH TO DATE`, `SINCE JAN 1`.
+    pub precipitation: Vec<ClimateValue>,
+    /// The snowfall rows, including `SNOW DEPTH`.
+    pub snowfall: Vec<ClimateValue>,
+    /// The heating degree day rows.
+    pub heating_degree_days: Vec<ClimateValue>,
+    /// The cooling degree day rows.
+    pub cooling_degree_days: Vec<ClimateValue>,
+}
+
+impl ClimateReport {
+    /// Returns the observed maximum temperature row.
+    pub fn maximum_temperature(&self) -> Option<&ClimateValue> {
+        find(&self.temperature, "MAXIMUM")
+    }
+
+    /// Returns the observed minimum temperature row.
+    pub fn minimum_temperature(&self) -> Option<&ClimateValue> {
+        find(&self.temperature, "MINIMUM")
+    }
+
+    /// Returns the precipitation row for the day the report summarizes.
+    pub fn daily_precipitation(&self) -> Option<&ClimateValue> {
+        find(&self.precipitation, "YESTERDAY").or_else(|| find(&self.precipitation, "TODAY"))
+    }
+
+    /// Returns the snowfall row for the day the report summarizes.
+    pub fn daily_snowfall(&self) -> Option<&ClimateValue> {
+        find(&self.snowfall, "YESTERDAY").or_else(|| find(&self.snowfall, "TODAY"))
+    }
+}
+
+impl FromStr for ClimateReport {
+    type Err = ParseClimateReportError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let mut report = Self::default();
+        let mut section = None;
+        let mut found_summary = false;
+        for line in string.lines() {
+            let trimmed = line.trim();
+            if let Some((station, date)) = parse_summary_line(trimmed) {
+                report.station = Some(station);
+                report.date = date;
+                found_summary = true;
+                continue;
+            }
+            if let Some(next) = Section::from_line(trimmed, section) {
+                section = next;
+                continue;
+            }
+            let Some(current) = section else {
+                continue;
+            };
+            let Some(value) = ClimateValue::parse(trimmed) else {
+                continue;
+            };
+            match current {
+                Section::Temperature => report.temperature.push(value),
+                Section::Precipitation => report.precipitation.push(value),
+                Section::Snowfall => report.snowfall.push(value),
+                Section::HeatingDegreeDays => report.heating_degree_days.push(value),
+                Section::CoolingDegreeDays => report.cooling_degree_days.push(value),
+                Section::DegreeDays => {}
+            }
+        }
+        if !found_summary {
+            return Err(ParseClimateReportError::new(string));
+        }
+        Ok(report)
+    }
+}
+
+/// A row of a climate report.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ClimateValue {
+    /// The row label, e.g., `MAXIMUM` or `MONTH TO DATE`.
+    pub label: String,
+    /// The observed value.
+    pub observed: Option<f64>,
+    /// The local time of the observed value, e.g., `308 PM`.
+    pub time: Option<String>,
+    /// The record value.
+    pub record: Option<f64>,
+    /// The year of the record value.
+    pub record_year: Option<i16>,
+    /// Whether the observed value set or tied the record.
+    pub record_set: bool,
+    /// The normal value.
+    pub normal: Option<f64>,
+    /// The departure of the observed value from normal.
+    pub departure: Option<f64>,
+    /// Last year's value.
+    pub last_year: Option<f64>,
+}
+
+impl ClimateValue {
+    /// Parses a row, returning `None` for lines without values.
+    fn parse(line: &str) -> Option<Self> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let mut label_length = tokens.iter().position(|token| is_value(token))?;
+        if label_length == 0 {
+            return None;
+        }
+        // The day in labels such as `SINCE JAN 1` is part of the label.
+        if tokens[0] == "SINCE" && label_length == 2 {
+            label_length += 1;
+        }
+        let (label, values) = tokens.split_at_checked(label_length)?;
+        let (observed, mut rest) = values.split_first()?;
+        let record_set = observed.ends_with('R');
+
+        let mut time = None;
+        if let [clock, meridiem, remaining @ ..] = rest
+            && matches!(*meridiem, "AM" | "PM")
+        {
+            time = Some(format!("{clock} {meridiem}"));
+            rest = remaining;
+        } else if let [missing, remaining @ ..] = rest
+            && *missing == "MM"
+            && remaining.len() >= 5
+        {
+            rest = remaining;
+        }
+
+        let mut value = Self {
+            label: label.join(" "),
+            observed: parse_value(observed),
+            time,
+            record_set,
+            ..Default::default()
+        };
+        let (record, normals) = match rest {
+            [record, year, normals @ ..] if parse_year(year).is_some() => {
+                (Some((record, year)), normals)
+            }
+            [record, year, normals @ ..] if normals.len() == 3 && *year == "MM" => {
+                (Some((record, year)), normals)
+            }
+            _ => (None, rest),
+        };
+        if let Some((record, year)) = record {
+            value.record = parse_value(record);
+            value.record_year = parse_year(year);
+        }
+        let mut normals = normals.iter().map(|token| parse_value(token));
+        value.normal = normals.next().flatten();
+        value.departure = normals.next().flatten();
+        value.last_year = normals.next().flatten();
+        Some(value)
+    }
+}
+
+/// The report sections that hold rows this module decodes.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Section {
+    Temperature,
+    Precipitation,
+    Snowfall,
+    DegreeDays,
+    HeatingDegreeDays,
+    CoolingDegreeDays,
+}
+
+impl Section {
+    /// Returns the section a heading line starts, `Some(None)` for a heading
+    /// of a section that is not decoded, or `None` for a line that is not a heading.
+    fn from_line(line: &str, current: Option<Self>) -> Option<Option<Self>> {
+        let in_degree_days = matches!(
+            current,
+            Some(Self::DegreeDays | Self::HeatingDegreeDays | Self::CoolingDegreeDays)
+        );
+        if line.starts_with("TEMPERATURE") {
+            Some(Some(Self::Temperature))
+        } else if line.starts_with("PRECIPITATION") {
+            Some(Some(Self::Precipitation))
+        } else if line.starts_with("SNOWFALL") {
+            Some(Some(Self::Snowfall))
+        } else if line.starts_with("DEGREE DAYS") {
+            Some(Some(Self::DegreeDays))
+        } else if in_degree_days && line == "HEATING" {
+            Some(Some(Self::HeatingDegreeDays))
+        } else if in_degree_days && line == "COOLING" {
+            Some(Some(Self::CoolingDegreeDays))
+        } else if [
+            "WIND",
+            "SKY COVER",
+            "WEATHER CONDITIONS",
+            "RELATIVE HUMIDITY",
+            "THE ",
+            "SUNRISE",
+        ]
+        .iter()
+        .any(|heading| line.starts_with(heading))
+        {
+            Some(None)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parses the `...THE PHOENIX CLIMATE SUMMARY FOR OCTOBER 18 2025...` line.
+fn parse_summary_line(line: &str) -> Option<(String, Option<Date>)> {
+    let line = line.trim_matches('.');
+    let rest = line.strip_prefix("THE ")?;
+    let (station, date) = rest.split_once(" CLIMATE SUMMARY FOR ")?;
+    Some((station.trim().to_string(), parse_date(date)))
+}
+
+/// Parses a date such as `OCTOBER 18 2025`.
+fn parse_date(date: &str) -> Option<Date> {
+    let mut tokens = date.split_whitespace();
+    let month = product_header::parse_month(tokens.next()?)?;
+    let day = tokens.next()?.trim_end_matches(',').parse().ok()?;
+    let year = tokens.next()?.parse().ok()?;
+    Date::new(year, month, day).ok()
+}
+
+/// Returns the first row with the given label.
+fn find<'a>(values: &'a [ClimateValue], label: &str) -> Option<&'a ClimateValue> {
+    values.iter().find(|value| value.label == label)
+}
+
+/// Returns true for a value token: a number, `MM`, or `T`.
+fn is_value(token: &str) -> bool {
+    matches!(token, "MM" | "T") || parse_value(token).is_some()
+}
+
+/// Parses a value, treating `MM` as missing, `T` as [`TRACE`], and ignoring a trailing record flag.
+fn parse_value(token: &str) -> Option<f64> {
+    match token.trim_end_matches('R') {
+        "T" => Some(TRACE),
+        "MM" => None,
+        value => value.parse().ok(),
+    }
+}
+
+/// Parses a four-digit record year.
+fn parse_year(token: &str) -> Option<i16> {
+    (token.len() == 4)
+        .then(|| token.parse().ok())
+        .flatten()
+        .filter(|year| (1800..=2200).contains(year))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLI: &str = "
+000
+CDUS45 KPSR 190731
+CLIPHX
+
+CLIMATE REPORT
+NATIONAL WEATHER SERVICE PHOENIX AZ
+1231 AM MST SUN OCT 19 2025
+
+...................................
+
+...THE PHOENIX CLIMATE SUMMARY FOR OCTOBER 18 2025...
+VALID TODAY AS OF 0000 PM LOCAL TIME.
+
+CLIMATE NORMAL PERIOD 1991 TO 2020
+CLIMATE RECORD PERIOD 1896 TO 2025
+
+WEATHER ITEM   OBSERVED TIME   RECORD YEAR NORMAL DEPARTURE LAST
+                VALUE   (LST)  VALUE       VALUE  FROM      YEAR
+                                                  NORMAL
+...................................................................
+TEMPERATURE (F)
+ YESTERDAY
+  MAXIMUM        104R   308 PM 104    1950  89     15       91
+  MINIMUM         72    605 AM  55    1949  65      7       66
+  AVERAGE         88                        77     11       79
+
+PRECIPITATION (IN)
+  YESTERDAY        T             0.85 1972   0.02  -0.02     0.00
+  MONTH TO DATE    0.00                      0.39  -0.39     0.14
+  SINCE SEP 1      0.12                      1.40  -1.28     1.10
+  SINCE JAN 1      MM                        5.78     MM     4.60
+
+SNOWFALL (IN)
+  YESTERDAY        0.0           MM   MM     0.0    0.0      0.0
+  SNOW DEPTH       0
+
+DEGREE DAYS
+ HEATING
+  YESTERDAY        0                          0       0        0
+  MONTH TO DATE    0                          3      -3        0
+ COOLING
+  YESTERDAY       23                         12      11       14
+  MONTH TO DATE  312                        210     102      300
+..................................................................
+
+WIND (MPH)
+  HIGHEST WIND SPEED    15   HIGHEST WIND DIRECTION    W (270)
+
+THE PHOENIX CLIMATE NORMALS FOR TODAY
+                         NORMAL    RECORD    YEAR
+ MAXIMUM TEMPERATURE (F)   89       104      1950
+$$
+";
+
+    #[test]
+    fn test_parse_climate_report() {
+        let report: ClimateReport = CLI.parse().unwrap();
+        assert_eq!(report.station.as_deref(), Some("PHOENIX"));
+        assert_eq!(report.date, Some(jiff::civil::date(2025, 10, 18)));
+
+        let maximum = report.maximum_temperature().unwrap();
+        assert_eq!(maximum.observed, Some(104.0));
+        assert!(maximum.record_set);
+        assert_eq!(maximum.time.as_deref(), Some("308 PM"));
+        assert_eq!(maximum.record, Some(104.0));
+        assert_eq!(maximum.record_year, Some(1950));
+        assert_eq!(maximum.normal, Some(89.0));
+        assert_eq!(maximum.departure, Some(15.0));
+        assert_eq!(maximum.last_year, Some(91.0));
+
+        let average = &report.temperature[2];
+        assert_eq!(average.label, "AVERAGE");
+        assert_eq!(average.record, None);
+        assert_eq!(
+            (average.normal, average.departure, average.last_year),
+            (Some(77.0), Some(11.0), Some(79.0))
+        );
+
+        assert_eq!(report.precipitation.len(), 4);
+        let daily = report.daily_precipitation().unwrap();
+        assert_eq!(daily.observed, Some(TRACE));
+        assert_eq!(daily.record_year, Some(1972));
+        assert_eq!(report.precipitation[2].label, "SINCE SEP 1");
+        assert_eq!(report.precipitation[2].departure, Some(-1.28));
+        assert_eq!(report.precipitation[3].observed, None);
+        assert_eq!(report.precipitation[3].departure, None);
+        assert_eq!(report.precipitation[3].last_year, Some(4.6));
+
+        let snowfall = report.daily_snowfall().unwrap();
+        assert_eq!((snowfall.record, snowfall.record_year), (None, None));
+        assert_eq!(snowfall.normal, Some(0.0));
+        assert_eq!(report.snowfall[1].label, "SNOW DEPTH");
+
+        assert_eq!(report.heating_degree_days.len(), 2);
+        assert_eq!(report.cooling_degree_days[0].observed, Some(23.0));
+        assert_eq!(report.cooling_degree_days[1].departure, Some(102.0));
+    }
+
+    #[test]
+    fn test_parse_not_a_climate_report() {
+        assert!("Area Forecast Discussion".parse::<ClimateReport>().is_err());
+    }
+}
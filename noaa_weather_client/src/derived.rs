@@ -0,0 +1,364 @@
+//! Meteorological quantities derived from temperature, dewpoint, wind, and pressure.
+//!
+//! NWS often returns `null` for the heat index, wind chill, and relative
+//! humidity of an [`Observation`], and never reports the wet-bulb temperature,
+//! apparent temperature, density altitude, or cloud base. The functions in this
+//! module compute them using the formulas NWS itself publishes:
+//!
+//! - [`heat_index`]: the Rothfusz regression with the NWS adjustments.
+//! - [`wind_chill`]: the 2001 NWS wind chill index.
+//! - [`relative_humidity`]: the Magnus approximation (Alduchov and Eskridge coefficients).
+//! - [`wet_bulb`]: the Stull (2011) empirical formula.
+//! - [`density_altitude`]: the NWS density altitude formula.
+//! - [`cloud_base`]: the dewpoint spread method for convective cloud bases.
+//!
+//! All functions take and return metric units: degrees Celsius, kilometres
+//! per hour, hectopascals, and metres. [`DerivedQuantities`] computes them all
+//! for an observation, and [`Observation::fill_derived`] fills the missing
+//! observation fields.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::derived;
+//!
+//! let humidity = derived::relative_humidity(30.0, 20.0);
+//! assert_eq!(humidity.round(), 55.0);
+//!
+//! let heat_index = derived::heat_index(32.2, 50.0).unwrap();
+//! assert_eq!(heat_index.round(), 35.0);
+//!
+//! // Wind chill is not defined above 10 °C.
+//! assert_eq!(derived::wind_chill(15.0, 30.0), None);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{Observation, UnitCodeType, ValueUnit, WmoUnitCode};
+use crate::utils::units::{self, FEET_PER_METER, HECTOPASCALS_PER_INCH_OF_MERCURY};
+
+const CLOUD_BASE_METERS_PER_DEGREE: f64 = 125.0;
+const HEAT_INDEX_THRESHOLD_FAHRENHEIT: f64 = 80.0;
+const WIND_CHILL_MAX_TEMPERATURE_CELSIUS: f64 = 10.0;
+const WIND_CHILL_MIN_SPEED_KMH: f64 = 4.8;
+
+/// Quantities derived from an observation, in metric units.
+///
+/// A field is `None` when the inputs it needs are missing or when the
+/// quantity is not defined for the conditions (e.g., wind chill above 10 °C).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DerivedQuantities {
+    /// Relative humidity in percent.
+    pub relative_humidity: Option<f64>,
+    /// Heat index in degrees Celsius.
+    pub heat_index: Option<f64>,
+    /// Wind chill in degrees Celsius.
+    pub wind_chill: Option<f64>,
+    /// Wet-bulb temperature in degrees Celsius.
+    pub wet_bulb: Option<f64>,
+    /// Apparent ("feels like") temperature in degrees Celsius.
+    pub apparent_temperature: Option<f64>,
+    /// Density altitude in metres.
+    pub density_altitude: Option<f64>,
+    /// Estimated convective cloud base in metres above ground level.
+    pub cloud_base: Option<f64>,
+}
+
+impl DerivedQuantities {
+    /// Computes the derived quantities for an observation.
+    ///
+    /// A reported relative humidity is used as is; otherwise it is computed
+    /// from the temperature and dewpoint. Density altitude uses the station
+    /// elevation and the `barometric_pressure` (altimeter setting) field.
+    pub fn from_observation(observation: &Observation) -> Self {
+        let temperature = observation.temperature.as_ref().and_then(celsius);
+        let dewpoint = observation.dewpoint.as_ref().and_then(celsius);
+        let wind_speed = observation
+            .wind_speed
+            .as_ref()
+            .and_then(kilometers_per_hour);
+        let altimeter = observation
+            .barometric_pressure
+            .as_ref()
+            .and_then(hectopascals);
+        let elevation = observation
+            .elevation
+            .as_ref()
+            .and_then(units::length_in_meters);
+        let humidity = observation
+            .relative_humidity
+            .as_ref()
+            .and_then(percent)
+            .or_else(|| Some(relative_humidity(temperature?, dewpoint?)));
+
+        Self {
+            relative_humidity: humidity,
+            heat_index: temperature
+                .zip(humidity)
+                .and_then(|(temperature, humidity)| heat_index(temperature, humidity)),
+            wind_chill: temperature
+                .zip(wind_speed)
+                .and_then(|(temperature, speed)| wind_chill(temperature, speed)),
+            wet_bulb: temperature
+                .zip(humidity)
+                .map(|(temperature, humidity)| wet_bulb(temperature, humidity)),
+            apparent_temperature: temperature
+                .map(|temperature| apparent_temperature(temperature, humidity, wind_speed)),
+            density_altitude: elevation.zip(altimeter).zip(temperature).map(
+                |((elevation, altimeter), temperature)| {
+                    density_altitude(elevation, altimeter, temperature)
+                },
+            ),
+            cloud_base: temperature
+                .zip(dewpoint)
+                .map(|(temperature, dewpoint)| cloud_base(temperature, dewpoint)),
+        }
+    }
+
+    /// Fills the `relative_humidity`, `heat_index`, and `wind_chill` fields of
+    /// `observation` that NWS returned as `null`.
+    ///
+    /// Fields that already have a value are left unchanged. Values are stored
+    /// in the WMO units the API itself uses.
+    pub fn fill_observation(&self, observation: &mut Observation) {
+        units::fill(
+            &mut observation.relative_humidity,
+            self.relative_humidity,
+            WmoUnitCode::PerCent,
+        );
+        units::fill(
+            &mut observation.heat_index,
+            self.heat_index,
+            WmoUnitCode::DegreeCelsius,
+        );
+        units::fill(
+            &mut observation.wind_chill,
+            self.wind_chill,
+            WmoUnitCode::DegreeCelsius,
+        );
+    }
+}
+
+/// Returns the relative humidity in percent for a temperature and dewpoint in degrees Celsius.
+pub fn relative_humidity(temperature_celsius: f64, dewpoint_celsius: f64) -> f64 {
+    let humidity = 100.0
+        * (magnus(dewpoint_celsius) - magnus(temperature_celsius))
+            .exp()
+            .min(1.0);
+    humidity.max(0.0)
+}
+
+/// Returns the heat index in degrees Celsius, or `None` below 80 °F (26.7 °C)
+/// where NWS does not report it.
+pub fn heat_index(temperature_celsius: f64, relative_humidity: f64) -> Option<f64> {
+    let t = celsius_to_fahrenheit(temperature_celsius);
+    if t < HEAT_INDEX_THRESHOLD_FAHRENHEIT {
+        return None;
+    }
+    let rh = relative_humidity;
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    if (simple + t) / 2.0 < HEAT_INDEX_THRESHOLD_FAHRENHEIT {
+        return Some(fahrenheit_to_celsius(simple));
+    }
+    let mut index = -42.379 + 2.049_015_23 * t + 10.143_331_27 * rh
+        - 0.224_755_41 * t * rh
+        - 0.006_837_83 * t * t
+        - 0.054_817_17 * rh * rh
+        + 0.001_228_74 * t * t * rh
+        + 0.000_852_82 * t * rh * rh
+        - 0.000_001_99 * t * t * rh * rh;
+    if rh < 13.0 && (80.0..=112.0).contains(&t) {
+        index -= (13.0 - rh) / 4.0 * ((17.0 - (t - 95.0).abs()) / 17.0).sqrt();
+    } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+        index += (rh - 85.0) / 10.0 * ((87.0 - t) / 5.0);
+    }
+    Some(fahrenheit_to_celsius(index))
+}
+
+/// Returns the wind chill in degrees Celsius, or `None` above 10 °C (50 °F) or
+/// for winds below 4.8 km/h (3 mph) where it is not defined.
+pub fn wind_chill(temperature_celsius: f64, wind_speed_kmh: f64) -> Option<f64> {
+    if temperature_celsius > WIND_CHILL_MAX_TEMPERATURE_CELSIUS
+        || wind_speed_kmh < WIND_CHILL_MIN_SPEED_KMH
+    {
+        return None;
+    }
+    let wind = wind_speed_kmh.powf(0.16);
+    Some(13.12 + 0.6215 * temperature_celsius - 11.37 * wind + 0.3965 * temperature_celsius * wind)
+}
+
+/// Returns the wet-bulb temperature in degrees Celsius at standard sea level pressure.
+///
+/// The Stull formula is accurate to within 1 °C for relative humidities from
+/// 5 to 99 percent and temperatures from -20 to 50 °C.
+pub fn wet_bulb(temperature_celsius: f64, relative_humidity: f64) -> f64 {
+    let t = temperature_celsius;
+    let rh = relative_humidity;
+    t * (0.151_977 * (rh + 8.313_659).sqrt()).atan() + (t + rh).atan() - (rh - 1.676_331).atan()
+        + 0.003_918_38 * rh.powf(1.5) * (0.023_101 * rh).atan()
+        - 4.686_035
+}
+
+/// Returns the apparent ("feels like") temperature in degrees Celsius.
+///
+/// This is the heat index when it is defined, the wind chill when it is
+/// defined, and the temperature otherwise.
+pub fn apparent_temperature(
+    temperature_celsius: f64,
+    relative_humidity: Option<f64>,
+    wind_speed_kmh: Option<f64>,
+) -> f64 {
+    relative_humidity
+        .and_then(|humidity| heat_index(temperature_celsius, humidity))
+        .or_else(|| wind_speed_kmh.and_then(|speed| wind_chill(temperature_celsius, speed)))
+        .unwrap_or(temperature_celsius)
+}
+
+/// Returns the density altitude in metres for a station elevation in metres,
+/// an altimeter setting in hectopascals, and a temperature in degrees Celsius.
+pub fn density_altitude(
+    elevation_meters: f64,
+    altimeter_hpa: f64,
+    temperature_celsius: f64,
+) -> f64 {
+    let station_pressure =
+        altimeter_hpa * ((288.0 - 0.0065 * elevation_meters) / 288.0).powf(5.2561);
+    let pressure_inches = station_pressure / HECTOPASCALS_PER_INCH_OF_MERCURY;
+    let temperature_rankine = celsius_to_fahrenheit(temperature_celsius) + 459.67;
+    let feet = 145_442.16 * (1.0 - (17.326 * pressure_inches / temperature_rankine).powf(0.235));
+    feet / FEET_PER_METER
+}
+
+/// Returns the estimated base of convective clouds in metres above ground level.
+pub fn cloud_base(temperature_celsius: f64, dewpoint_celsius: f64) -> f64 {
+    (temperature_celsius - dewpoint_celsius).max(0.0) * CLOUD_BASE_METERS_PER_DEGREE
+}
+
+/// Returns the exponent of the Magnus saturation vapour pressure formula.
+fn magnus(temperature_celsius: f64) -> f64 {
+    17.625 * temperature_celsius / (243.04 + temperature_celsius)
+}
+
+fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+    celsius * 9.0 / 5.0 + 32.0
+}
+
+fn fahrenheit_to_celsius(fahrenheit: f64) -> f64 {
+    (fahrenheit - 32.0) * 5.0 / 9.0
+}
+
+fn wmo_unit(value_unit: &ValueUnit) -> Option<(f64, WmoUnitCode)> {
+    match value_unit.unit_code.as_ref()? {
+        UnitCodeType::Wmo(unit) => Some((value_unit.value?, *unit)),
+        UnitCodeType::Nws(_) => None,
+    }
+}
+
+fn celsius(value_unit: &ValueUnit) -> Option<f64> {
+    match wmo_unit(value_unit)? {
+        (value, WmoUnitCode::DegreeCelsius | WmoUnitCode::DegreesCelsius8) => Some(value),
+        (value, WmoUnitCode::Kelvin) => Some(value - 273.15),
+        _ => None,
+    }
+}
+
+fn kilometers_per_hour(value_unit: &ValueUnit) -> Option<f64> {
+    match wmo_unit(value_unit)? {
+        (value, WmoUnitCode::KilometresPerHour) => Some(value),
+        (value, WmoUnitCode::MetresPerSecond) => Some(value * 3.6),
+        (value, WmoUnitCode::Knot) => Some(value * 1.852),
+        _ => None,
+    }
+}
+
+fn hectopascals(value_unit: &ValueUnit) -> Option<f64> {
+    match wmo_unit(value_unit)? {
+        (value, WmoUnitCode::Pascal) => Some(value / 100.0),
+        (value, WmoUnitCode::Hectopascal) => Some(value),
+        _ => None,
+    }
+}
+
+fn percent(value_unit: &ValueUnit) -> Option<f64> {
+    match wmo_unit(value_unit)? {
+        (value, WmoUnitCode::PerCent) => Some(value),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value(value: f64, unit: WmoUnitCode) -> Option<ValueUnit> {
+        Some(ValueUnit {
+            unit_code: Some(UnitCodeType::Wmo(unit)),
+            value: Some(value),
+            ..Default::default()
+        })
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_formulas() {
+        assert_close(relative_humidity(30.0, 20.0), 55.1, 0.1);
+        assert_close(relative_humidity(10.0, 10.0), 100.0, 1e-9);
+
+        // NWS heat index chart: 90 °F at 50% is 95 °F, 96 °F at 65% is 121 °F.
+        assert_close(heat_index(32.22, 50.0).unwrap(), 35.0, 0.3);
+        assert_close(heat_index(35.56, 65.0).unwrap(), 49.4, 0.6);
+        assert_eq!(heat_index(20.0, 90.0), None);
+
+        assert_close(wind_chill(-10.0, 20.0).unwrap(), -17.9, 0.1);
+        assert_eq!(wind_chill(-10.0, 2.0), None);
+
+        // Stull (2011): 20 °C at 50% is 13.7 °C.
+        assert_close(wet_bulb(20.0, 50.0), 13.7, 0.1);
+
+        assert_close(density_altitude(0.0, 1013.25, 15.0), 0.0, 10.0);
+        assert_close(density_altitude(1609.0, 1013.25, 30.0), 2487.0, 10.0);
+
+        assert_close(cloud_base(25.0, 15.0), 1250.0, 1e-9);
+        assert_eq!(apparent_temperature(15.0, Some(50.0), Some(30.0)), 15.0);
+    }
+
+    #[test]
+    fn test_fill_observation() {
+        let mut observation = Observation {
+            elevation: value(331.0, WmoUnitCode::Metre),
+            temperature: value(-5.0, WmoUnitCode::DegreeCelsius),
+            dewpoint: value(-12.0, WmoUnitCode::DegreeCelsius),
+            wind_speed: value(25.0, WmoUnitCode::KilometresPerHour),
+            barometric_pressure: value(102_000.0, WmoUnitCode::Pascal),
+            heat_index: Some(ValueUnit {
+                unit_code: Some(UnitCodeType::Wmo(WmoUnitCode::DegreeCelsius)),
+                value: None,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let derived = DerivedQuantities::from_observation(&observation);
+        assert_close(derived.relative_humidity.unwrap(), 58.4, 0.5);
+        assert_eq!(derived.heat_index, None);
+        assert_eq!(derived.apparent_temperature, derived.wind_chill);
+        assert_close(derived.cloud_base.unwrap(), 875.0, 1e-9);
+        assert!(derived.density_altitude.unwrap() < 331.0);
+
+        observation.fill_derived();
+        assert_eq!(
+            observation.relative_humidity.as_ref().and_then(percent),
+            derived.relative_humidity
+        );
+        assert_eq!(
+            observation.wind_chill.as_ref().and_then(celsius),
+            derived.wind_chill
+        );
+        assert_eq!(observation.heat_index.unwrap().value, None);
+    }
+}
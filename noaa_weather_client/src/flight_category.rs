@@ -0,0 +1,164 @@
+//! Flight categories computed from ceiling and visibility.
+//!
+//! The FAA flight categories summarize how restrictive the ceiling (the
+//! lowest broken, overcast, or obscured layer) and the visibility are:
+//!
+//! | Category | Ceiling                 | Visibility              |
+//! | -------- | ----------------------- | ----------------------- |
+//! | `LIFR`   | below 500 ft            | below 1 statute mile    |
+//! | `IFR`    | 500 to below 1,000 ft   | 1 to below 3 miles      |
+//! | `MVFR`   | 1,000 to 3,000 ft       | 3 to 5 miles            |
+//! | `VFR`    | above 3,000 ft          | above 5 miles           |
+//!
+//! The category is the more restrictive of the two. It is available for
+//! observations through [`Observation::flight_category`](crate::models::Observation::flight_category)
+//! and for TAF groups through [`TafConditions::flight_category`](crate::taf::TafConditions::flight_category).
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::flight_category::FlightCategory;
+//!
+//! assert_eq!(
+//!     FlightCategory::from_ceiling_and_visibility(Some(2500.0), Some(10.0)),
+//!     FlightCategory::Mvfr
+//! );
+//! assert_eq!(
+//!     FlightCategory::from_ceiling_and_visibility(None, Some(0.5)),
+//!     FlightCategory::Lifr
+//! );
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/// A flight category, ordered from the most to the least restrictive.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+pub enum FlightCategory {
+    /// Low instrument flight rules.
+    #[serde(rename = "LIFR")]
+    Lifr,
+    /// Instrument flight rules.
+    #[serde(rename = "IFR")]
+    Ifr,
+    /// Marginal visual flight rules.
+    #[serde(rename = "MVFR")]
+    Mvfr,
+    /// Visual flight rules.
+    #[serde(rename = "VFR")]
+    Vfr,
+}
+
+impl FlightCategory {
+    /// Returns the category for a ceiling in feet above ground level, or `None` for no ceiling.
+    pub fn from_ceiling(ceiling_feet: Option<f64>) -> Self {
+        match ceiling_feet {
+            Some(ceiling) if ceiling < 500.0 => Self::Lifr,
+            Some(ceiling) if ceiling < 1000.0 => Self::Ifr,
+            Some(ceiling) if ceiling <= 3000.0 => Self::Mvfr,
+            _ => Self::Vfr,
+        }
+    }
+
+    /// Returns the category for a visibility in statute miles, or `None` for an unrestricted visibility.
+    pub fn from_visibility(visibility_statute_miles: Option<f64>) -> Self {
+        match visibility_statute_miles {
+            Some(visibility) if visibility < 1.0 => Self::Lifr,
+            Some(visibility) if visibility < 3.0 => Self::Ifr,
+            Some(visibility) if visibility <= 5.0 => Self::Mvfr,
+            _ => Self::Vfr,
+        }
+    }
+
+    /// Returns the more restrictive of the ceiling and visibility categories.
+    pub fn from_ceiling_and_visibility(
+        ceiling_feet: Option<f64>,
+        visibility_statute_miles: Option<f64>,
+    ) -> Self {
+        Self::from_ceiling(ceiling_feet).min(Self::from_visibility(visibility_statute_miles))
+    }
+}
+
+impl fmt::Display for FlightCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lifr => write!(f, "LIFR"),
+            Self::Ifr => write!(f, "IFR"),
+            Self::Mvfr => write!(f, "MVFR"),
+            Self::Vfr => write!(f, "VFR"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        MetarSkyCoverage, Observation, ObservationCloudLayersInner, UnitCodeType, ValueUnit,
+        WmoUnitCode,
+    };
+
+    fn meters(value: f64) -> ValueUnit {
+        ValueUnit {
+            unit_code: Some(UnitCodeType::Wmo(WmoUnitCode::Metre)),
+            value: Some(value),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_category_boundaries() {
+        assert_eq!(
+            FlightCategory::from_ceiling(Some(499.0)),
+            FlightCategory::Lifr
+        );
+        assert_eq!(
+            FlightCategory::from_ceiling(Some(500.0)),
+            FlightCategory::Ifr
+        );
+        assert_eq!(
+            FlightCategory::from_ceiling(Some(1000.0)),
+            FlightCategory::Mvfr
+        );
+        assert_eq!(
+            FlightCategory::from_ceiling(Some(3000.0)),
+            FlightCategory::Mvfr
+        );
+        assert_eq!(
+            FlightCategory::from_ceiling(Some(3100.0)),
+            FlightCategory::Vfr
+        );
+        assert_eq!(FlightCategory::from_ceiling(None), FlightCategory::Vfr);
+        assert_eq!(
+            FlightCategory::from_visibility(Some(2.5)),
+            FlightCategory::Ifr
+        );
+        assert_eq!(
+            FlightCategory::from_visibility(Some(5.0)),
+            FlightCategory::Mvfr
+        );
+        assert_eq!(
+            FlightCategory::from_visibility(Some(6.0)),
+            FlightCategory::Vfr
+        );
+    }
+
+    #[test]
+    fn test_observation_flight_category() {
+        let mut observation = Observation {
+            visibility: Some(meters(16_090.0)),
+            cloud_layers: Some(Some(vec![
+                ObservationCloudLayersInner::new(meters(240.0), MetarSkyCoverage::Few),
+                ObservationCloudLayersInner::new(meters(610.0), MetarSkyCoverage::Bkn),
+            ])),
+            ..Default::default()
+        };
+        assert_eq!(observation.flight_category(), Some(FlightCategory::Mvfr));
+
+        observation.visibility = Some(meters(1200.0));
+        assert_eq!(observation.flight_category(), Some(FlightCategory::Lifr));
+
+        assert_eq!(Observation::default().flight_category(), None);
+    }
+}
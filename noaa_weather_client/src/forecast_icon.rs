@@ -0,0 +1,391 @@
+//! Parser for forecast icon URLs.
+//!
+//! The `icon` of a gridpoint forecast period is a URL that encodes the time
+//! of day and one or two condition codes, each with an optional chance of
+//! precipitation. Periods whose conditions change have two codes:
+//!
+//! ```text
+//! https://api.weather.gov/icons/land/day/tsra,40/rain,20?size=medium
+//!                                ^    ^   ^    ^  ^    ^
+//!                                set  |   code |  code |
+//!                                     |        chance  chance
+//!                                     time of day
+//! ```
+//!
+//! The codes are listed at <https://api.weather.gov/icons>. Icons are
+//! available for forecast periods through
+//! [`Gridpoint12hForecastPeriod::forecast_icon`](crate::models::Gridpoint12hForecastPeriod::forecast_icon)
+//! and [`GridpointHourlyForecastPeriod::forecast_icon`](crate::models::GridpointHourlyForecastPeriod::forecast_icon).
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::forecast_icon::{ForecastIcon, IconCode};
+//!
+//! let icon: ForecastIcon = "https://api.weather.gov/icons/land/day/tsra,40/rain,20?size=medium"
+//!     .parse()
+//!     .unwrap();
+//! assert!(icon.is_daytime);
+//! assert_eq!(icon.conditions[0].code, IconCode::Tsra);
+//! assert_eq!(icon.conditions[0].code.description(), "Thunderstorm (high cloud cover)");
+//! assert_eq!(icon.conditions[1].probability, Some(20));
+//! assert_eq!(icon.max_probability(), Some(40));
+//! ```
+
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+/// An error returned when parsing an invalid forecast icon URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseForecastIconError {
+    invalid_value: String,
+}
+
+impl ParseForecastIconError {
+    fn new(invalid_value: &str) -> Self {
+        Self {
+            invalid_value: invalid_value.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseForecastIconError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid forecast icon value: {}", self.invalid_value)
+    }
+}
+
+impl std::error::Error for ParseForecastIconError {}
+
+/// A parsed forecast icon URL.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForecastIcon {
+    /// The icon set, e.g., `land`.
+    pub set: String,
+    /// Whether the icon is for the day rather than the night.
+    pub is_daytime: bool,
+    /// The conditions in the order they occur during the period.
+    pub conditions: Vec<IconCondition>,
+}
+
+impl ForecastIcon {
+    /// Returns the highest chance of precipitation across the conditions.
+    pub fn max_probability(&self) -> Option<u8> {
+        self.conditions
+            .iter()
+            .filter_map(|condition| condition.probability)
+            .max()
+    }
+}
+
+/// Formats the icon as its path, e.g., `/icons/land/day/tsra,40/rain,20`.
+impl fmt::Display for ForecastIcon {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "/icons/{}/{}",
+            self.set,
+            if self.is_daytime { "day" } else { "night" }
+        )?;
+        for condition in &self.conditions {
+            write!(f, "/{}", condition.code)?;
+            if let Some(probability) = condition.probability {
+                write!(f, ",{probability}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Parses an icon URL or path, with or without the query string.
+impl FromStr for ForecastIcon {
+    type Err = ParseForecastIconError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let error = || ParseForecastIconError::new(string);
+        let path = string.trim().split(['?', '#']).next().unwrap_or_default();
+        let (_, path) = path.split_once("icons/").ok_or_else(error)?;
+        let mut parts = path.split('/').filter(|part| !part.is_empty());
+        let set = parts.next().ok_or_else(error)?;
+        let is_daytime = match parts.next() {
+            Some("day") => true,
+            Some("night") => false,
+            _ => return Err(error()),
+        };
+        let conditions = parts
+            .map(|part| {
+                let (code, probability) = match part.split_once(',') {
+                    Some((code, probability)) => (
+                        code,
+                        Some(
+                            probability
+                                .parse::<u8>()
+                                .ok()
+                                .filter(|probability| *probability <= 100)
+                                .ok_or_else(error)?,
+                        ),
+                    ),
+                    None => (part, None),
+                };
+                if code.is_empty() {
+                    return Err(error());
+                }
+                Ok(IconCondition {
+                    code: IconCode::from_code(code),
+                    probability,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if conditions.is_empty() {
+            return Err(error());
+        }
+        Ok(Self {
+            set: set.to_owned(),
+            is_daytime,
+            conditions,
+        })
+    }
+}
+
+/// A condition shown by a forecast icon.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IconCondition {
+    /// The condition code.
+    pub code: IconCode,
+    /// The chance of precipitation in percent.
+    pub probability: Option<u8>,
+}
+
+/// A forecast icon condition code.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub enum IconCode {
+    Skc,
+    Few,
+    Sct,
+    Bkn,
+    Ovc,
+    WindSkc,
+    WindFew,
+    WindSct,
+    WindBkn,
+    WindOvc,
+    Snow,
+    RainSnow,
+    RainSleet,
+    SnowSleet,
+    Fzra,
+    RainFzra,
+    SnowFzra,
+    Sleet,
+    Rain,
+    RainShowers,
+    RainShowersHi,
+    Tsra,
+    TsraSct,
+    TsraHi,
+    Tornado,
+    Hurricane,
+    TropicalStorm,
+    Dust,
+    Smoke,
+    Haze,
+    Hot,
+    Cold,
+    Blizzard,
+    Fog,
+    /// Any other code, as written in the URL.
+    Other(String),
+}
+
+impl IconCode {
+    /// Returns the code for a URL path part such as `tsra_hi`, or
+    /// [`IconCode::Other`] if it is not a known code.
+    pub fn from_code(code: &str) -> Self {
+        let code = code.trim().to_ascii_lowercase();
+        match code.as_str() {
+            "skc" => Self::Skc,
+            "few" => Self::Few,
+            "sct" => Self::Sct,
+            "bkn" => Self::Bkn,
+            "ovc" => Self::Ovc,
+            "wind_skc" => Self::WindSkc,
+            "wind_few" => Self::WindFew,
+            "wind_sct" => Self::WindSct,
+            "wind_bkn" => Self::WindBkn,
+            "wind_ovc" => Self::WindOvc,
+            "snow" => Self::Snow,
+            "rain_snow" => Self::RainSnow,
+            "rain_sleet" => Self::RainSleet,
+            "snow_sleet" => Self::SnowSleet,
+            "fzra" => Self::Fzra,
+            "rain_fzra" => Self::RainFzra,
+            "snow_fzra" => Self::SnowFzra,
+            "sleet" => Self::Sleet,
+            "rain" => Self::Rain,
+            "rain_showers" => Self::RainShowers,
+            "rain_showers_hi" => Self::RainShowersHi,
+            "tsra" => Self::Tsra,
+            "tsra_sct" => Self::TsraSct,
+            "tsra_hi" => Self::TsraHi,
+            "tornado" => Self::Tornado,
+            "hurricane" => Self::Hurricane,
+            "tropical_storm" => Self::TropicalStorm,
+            "dust" => Self::Dust,
+            "smoke" => Self::Smoke,
+            "haze" => Self::Haze,
+            "hot" => Self::Hot,
+            "cold" => Self::Cold,
+            "blizzard" => Self::Blizzard,
+            "fog" => Self::Fog,
+            _ => Self::Other(code),
+        }
+    }
+
+    /// Returns the description of the code, as listed at <https://api.weather.gov/icons>.
+    pub fn description(&self) -> &str {
+        match self {
+            Self::Skc => "Fair/clear",
+            Self::Few => "A few clouds",
+            Self::Sct => "Partly cloudy",
+            Self::Bkn => "Mostly cloudy",
+            Self::Ovc => "Overcast",
+            Self::WindSkc => "Fair/clear and windy",
+            Self::WindFew => "A few clouds and windy",
+            Self::WindSct => "Partly cloudy and windy",
+            Self::WindBkn => "Mostly cloudy and windy",
+            Self::WindOvc => "Overcast and windy",
+            Self::Snow => "Snow",
+            Self::RainSnow => "Rain/snow",
+            Self::RainSleet => "Rain/sleet",
+            Self::SnowSleet => "Snow/sleet",
+            Self::Fzra => "Freezing rain",
+            Self::RainFzra => "Rain/freezing rain",
+            Self::SnowFzra => "Freezing rain/snow",
+            Self::Sleet => "Sleet",
+            Self::Rain => "Rain",
+            Self::RainShowers => "Rain showers (high cloud cover)",
+            Self::RainShowersHi => "Rain showers (low cloud cover)",
+            Self::Tsra => "Thunderstorm (high cloud cover)",
+            Self::TsraSct => "Thunderstorm (medium cloud cover)",
+            Self::TsraHi => "Thunderstorm (low cloud cover)",
+            Self::Tornado => "Tornado",
+            Self::Hurricane => "Hurricane conditions",
+            Self::TropicalStorm => "Tropical storm conditions",
+            Self::Dust => "Dust",
+            Self::Smoke => "Smoke",
+            Self::Haze => "Haze",
+            Self::Hot => "Hot",
+            Self::Cold => "Cold",
+            Self::Blizzard => "Blizzard",
+            Self::Fog => "Fog/mist",
+            Self::Other(code) => code,
+        }
+    }
+}
+
+impl fmt::Display for IconCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Skc => write!(f, "skc"),
+            Self::Few => write!(f, "few"),
+            Self::Sct => write!(f, "sct"),
+            Self::Bkn => write!(f, "bkn"),
+            Self::Ovc => write!(f, "ovc"),
+            Self::WindSkc => write!(f, "wind_skc"),
+            Self::WindFew => write!(f, "wind_few"),
+            Self::WindSct => write!(f, "wind_sct"),
+            Self::WindBkn => write!(f, "wind_bkn"),
+            Self::WindOvc => write!(f, "wind_ovc"),
+            Self::Snow => write!(f, "snow"),
+            Self::RainSnow => write!(f, "rain_snow"),
+            Self::RainSleet => write!(f, "rain_sleet"),
+            Self::SnowSleet => write!(f, "snow_sleet"),
+            Self::Fzra => write!(f, "fzra"),
+            Self::RainFzra => write!(f, "rain_fzra"),
+            Self::SnowFzra => write!(f, "snow_fzra"),
+            Self::Sleet => write!(f, "sleet"),
+            Self::Rain => write!(f, "rain"),
+            Self::RainShowers => write!(f, "rain_showers"),
+            Self::RainShowersHi => write!(f, "rain_showers_hi"),
+            Self::Tsra => write!(f, "tsra"),
+            Self::TsraSct => write!(f, "tsra_sct"),
+            Self::TsraHi => write!(f, "tsra_hi"),
+            Self::Tornado => write!(f, "tornado"),
+            Self::Hurricane => write!(f, "hurricane"),
+            Self::TropicalStorm => write!(f, "tropical_storm"),
+            Self::Dust => write!(f, "dust"),
+            Self::Smoke => write!(f, "smoke"),
+            Self::Haze => write!(f, "haze"),
+            Self::Hot => write!(f, "hot"),
+            Self::Cold => write!(f, "cold"),
+            Self::Blizzard => write!(f, "blizzard"),
+            Self::Fog => write!(f, "fog"),
+            Self::Other(code) => write!(f, "{code}"),
+        }
+    }
+}
+
+impl FromStr for IconCode {
+    type Err = Infallible;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_code(string))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_split_period_icon() {
+        let icon: ForecastIcon =
+            "https://api.weather.gov/icons/land/night/rain_showers,60/tsra_hi?size=small"
+                .parse()
+                .unwrap();
+        assert_eq!(icon.set, "land");
+        assert!(!icon.is_daytime);
+        assert_eq!(
+            icon.conditions,
+            [
+                IconCondition {
+                    code: IconCode::RainShowers,
+                    probability: Some(60),
+                },
+                IconCondition {
+                    code: IconCode::TsraHi,
+                    probability: None,
+                },
+            ]
+        );
+        assert_eq!(
+            icon.to_string(),
+            "/icons/land/night/rain_showers,60/tsra_hi"
+        );
+    }
+
+    #[test]
+    fn test_parse_unknown_code() {
+        let icon: ForecastIcon = "/icons/land/day/volcano".parse().unwrap();
+        assert_eq!(icon.conditions[0].code, IconCode::Other("volcano".into()));
+        assert_eq!(icon.conditions[0].code.description(), "volcano");
+        assert_eq!(icon.max_probability(), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_icons() {
+        for value in [
+            "https://api.weather.gov/points/33.45,-112.07",
+            "/icons/land/evening/skc",
+            "/icons/land/day",
+            "/icons/land/day/rain,120",
+            "/icons/land/day/,20",
+        ] {
+            assert!(value.parse::<ForecastIcon>().is_err(), "{value}");
+        }
+    }
+}
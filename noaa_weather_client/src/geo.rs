@@ -0,0 +1,464 @@
+//! Conversions between the crate's geometry models and the georust ecosystem.
+//!
+//! Enabled by the `geo` feature. [`GeoJsonGeometry`] and its variants convert
+//! to and from [`geojson::Geometry`] and [`geo_types::Geometry`], so alert
+//! polygons, zone boundaries, and station points can be handed to other
+//! georust crates. [`LatLon`] converts to and from [`geo_types::Point`].
+//!
+//! Conversions into the crate's models always succeed except for geometry
+//! collections, which the NWS API does not use. Conversions into `geo_types`
+//! fail if a position has fewer than two coordinates; Z and M values are
+//! dropped.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::models::{GeoJsonGeometry, GeoJsonPolygon};
+//!
+//! let zone = GeoJsonGeometry::GeoJsonPolygon(Box::new(GeoJsonPolygon {
+//!     coordinates: vec![vec![
+//!         vec![-112.5, 33.0],
+//!         vec![-111.5, 33.0],
+//!         vec![-111.5, 34.0],
+//!         vec![-112.5, 33.0],
+//!     ]],
+//!     ..Default::default()
+//! }));
+//!
+//! let polygon = geo_types::Geometry::try_from(&zone).unwrap();
+//! assert!(matches!(polygon, geo_types::Geometry::Polygon(_)));
+//!
+//! let feature_geometry = geojson::Geometry::from(&zone);
+//! assert_eq!(GeoJsonGeometry::try_from(feature_geometry).unwrap(), zone);
+//! ```
+
+use std::fmt;
+
+use geo_types::{Coord, LineString, Polygon};
+
+use crate::geometry::LatLon;
+use crate::models::{
+    GeoJsonGeometry, GeoJsonLineString, GeoJsonMultiLineString, GeoJsonMultiPoint,
+    GeoJsonMultiPolygon, GeoJsonPoint, GeoJsonPolygon,
+};
+
+/// An error returned when a geometry cannot be converted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryConversionError {
+    invalid_value: String,
+}
+
+impl GeometryConversionError {
+    fn new(invalid_value: &str) -> Self {
+        Self {
+            invalid_value: invalid_value.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for GeometryConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Unsupported geometry: {}", self.invalid_value)
+    }
+}
+
+impl std::error::Error for GeometryConversionError {}
+
+impl From<LatLon> for geo_types::Point<f64> {
+    fn from(point: LatLon) -> Self {
+        geo_types::Point::new(point.longitude, point.latitude)
+    }
+}
+
+impl From<geo_types::Point<f64>> for LatLon {
+    fn from(point: geo_types::Point<f64>) -> Self {
+        LatLon {
+            latitude: point.y(),
+            longitude: point.x(),
+        }
+    }
+}
+
+impl TryFrom<&GeoJsonPoint> for geo_types::Point<f64> {
+    type Error = GeometryConversionError;
+
+    fn try_from(point: &GeoJsonPoint) -> Result<Self, Self::Error> {
+        coord(&point.coordinates).map(geo_types::Point)
+    }
+}
+
+impl From<geo_types::Point<f64>> for GeoJsonPoint {
+    fn from(point: geo_types::Point<f64>) -> Self {
+        GeoJsonPoint {
+            coordinates: position(point.0),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<&GeoJsonPolygon> for geo_types::Polygon<f64> {
+    type Error = GeometryConversionError;
+
+    fn try_from(polygon: &GeoJsonPolygon) -> Result<Self, Self::Error> {
+        to_polygon(&polygon.coordinates)
+    }
+}
+
+impl From<geo_types::Polygon<f64>> for GeoJsonPolygon {
+    fn from(polygon: geo_types::Polygon<f64>) -> Self {
+        GeoJsonPolygon {
+            coordinates: rings(&polygon),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<&GeoJsonMultiPolygon> for geo_types::MultiPolygon<f64> {
+    type Error = GeometryConversionError;
+
+    fn try_from(multi_polygon: &GeoJsonMultiPolygon) -> Result<Self, Self::Error> {
+        multi_polygon
+            .coordinates
+            .iter()
+            .map(|polygon| to_polygon(polygon))
+            .collect::<Result<_, _>>()
+            .map(geo_types::MultiPolygon)
+    }
+}
+
+impl From<geo_types::MultiPolygon<f64>> for GeoJsonMultiPolygon {
+    fn from(multi_polygon: geo_types::MultiPolygon<f64>) -> Self {
+        GeoJsonMultiPolygon {
+            coordinates: multi_polygon.iter().map(rings).collect(),
+            ..Default::default()
+        }
+    }
+}
+
+impl TryFrom<&GeoJsonGeometry> for geo_types::Geometry<f64> {
+    type Error = GeometryConversionError;
+
+    fn try_from(geometry: &GeoJsonGeometry) -> Result<Self, Self::Error> {
+        Ok(match geometry {
+            GeoJsonGeometry::GeoJsonPoint(point) => {
+                geo_types::Geometry::Point(point.as_ref().try_into()?)
+            }
+            GeoJsonGeometry::GeoJsonLineString(line_string) => {
+                geo_types::Geometry::LineString(to_line_string(&line_string.coordinates)?)
+            }
+            GeoJsonGeometry::GeoJsonPolygon(polygon) => {
+                geo_types::Geometry::Polygon(polygon.as_ref().try_into()?)
+            }
+            GeoJsonGeometry::GeoJsonMultiPoint(multi_point) => {
+                geo_types::Geometry::MultiPoint(geo_types::MultiPoint(
+                    multi_point
+                        .coordinates
+                        .iter()
+                        .map(|position| coord(position).map(geo_types::Point))
+                        .collect::<Result<_, _>>()?,
+                ))
+            }
+            GeoJsonGeometry::GeoJsonMultiLineString(multi_line_string) => {
+                geo_types::Geometry::MultiLineString(geo_types::MultiLineString(
+                    multi_line_string
+                        .coordinates
+                        .iter()
+                        .map(|line_string| to_line_string(line_string))
+                        .collect::<Result<_, _>>()?,
+                ))
+            }
+            GeoJsonGeometry::GeoJsonMultiPolygon(multi_polygon) => {
+                geo_types::Geometry::MultiPolygon(multi_polygon.as_ref().try_into()?)
+            }
+        })
+    }
+}
+
+impl TryFrom<GeoJsonGeometry> for geo_types::Geometry<f64> {
+    type Error = GeometryConversionError;
+
+    fn try_from(geometry: GeoJsonGeometry) -> Result<Self, Self::Error> {
+        geo_types::Geometry::try_from(&geometry)
+    }
+}
+
+impl TryFrom<geo_types::Geometry<f64>> for GeoJsonGeometry {
+    type Error = GeometryConversionError;
+
+    /// Converts a `geo_types` geometry. Lines, rectangles, and triangles become
+    /// line strings and polygons; geometry collections are not supported.
+    fn try_from(geometry: geo_types::Geometry<f64>) -> Result<Self, Self::Error> {
+        Ok(match geometry {
+            geo_types::Geometry::Point(point) => {
+                GeoJsonGeometry::GeoJsonPoint(Box::new(point.into()))
+            }
+            geo_types::Geometry::Line(line) => {
+                GeoJsonGeometry::GeoJsonLineString(Box::new(GeoJsonLineString {
+                    coordinates: vec![position(line.start), position(line.end)],
+                    ..Default::default()
+                }))
+            }
+            geo_types::Geometry::LineString(line_string) => {
+                GeoJsonGeometry::GeoJsonLineString(Box::new(GeoJsonLineString {
+                    coordinates: positions(&line_string),
+                    ..Default::default()
+                }))
+            }
+            geo_types::Geometry::Polygon(polygon) => {
+                GeoJsonGeometry::GeoJsonPolygon(Box::new(polygon.into()))
+            }
+            geo_types::Geometry::MultiPoint(multi_point) => {
+                GeoJsonGeometry::GeoJsonMultiPoint(Box::new(GeoJsonMultiPoint {
+                    coordinates: multi_point.iter().map(|point| position(point.0)).collect(),
+                    ..Default::default()
+                }))
+            }
+            geo_types::Geometry::MultiLineString(multi_line_string) => {
+                GeoJsonGeometry::GeoJsonMultiLineString(Box::new(GeoJsonMultiLineString {
+                    coordinates: multi_line_string.iter().map(positions).collect(),
+                    ..Default::default()
+                }))
+            }
+            geo_types::Geometry::MultiPolygon(multi_polygon) => {
+                GeoJsonGeometry::GeoJsonMultiPolygon(Box::new(multi_polygon.into()))
+            }
+            geo_types::Geometry::Rect(rect) => {
+                GeoJsonGeometry::GeoJsonPolygon(Box::new(rect.to_polygon().into()))
+            }
+            geo_types::Geometry::Triangle(triangle) => {
+                GeoJsonGeometry::GeoJsonPolygon(Box::new(triangle.to_polygon().into()))
+            }
+            geo_types::Geometry::GeometryCollection(_) => {
+                return Err(GeometryConversionError::new("GeometryCollection"));
+            }
+        })
+    }
+}
+
+impl From<GeoJsonGeometry> for geojson::Geometry {
+    fn from(geometry: GeoJsonGeometry) -> Self {
+        let (value, bbox) = match geometry {
+            GeoJsonGeometry::GeoJsonPoint(point) => {
+                (geojson::Value::Point(point.coordinates), point.bbox)
+            }
+            GeoJsonGeometry::GeoJsonLineString(line_string) => (
+                geojson::Value::LineString(line_string.coordinates),
+                line_string.bbox,
+            ),
+            GeoJsonGeometry::GeoJsonPolygon(polygon) => {
+                (geojson::Value::Polygon(polygon.coordinates), polygon.bbox)
+            }
+            GeoJsonGeometry::GeoJsonMultiPoint(multi_point) => (
+                geojson::Value::MultiPoint(multi_point.coordinates),
+                multi_point.bbox,
+            ),
+            GeoJsonGeometry::GeoJsonMultiLineString(multi_line_string) => (
+                geojson::Value::MultiLineString(multi_line_string.coordinates),
+                multi_line_string.bbox,
+            ),
+            GeoJsonGeometry::GeoJsonMultiPolygon(multi_polygon) => (
+                geojson::Value::MultiPolygon(multi_polygon.coordinates),
+                multi_polygon.bbox,
+            ),
+        };
+        geojson::Geometry {
+            bbox,
+            ..geojson::Geometry::new(value)
+        }
+    }
+}
+
+impl From<&GeoJsonGeometry> for geojson::Geometry {
+    fn from(geometry: &GeoJsonGeometry) -> Self {
+        geometry.clone().into()
+    }
+}
+
+impl TryFrom<geojson::Geometry> for GeoJsonGeometry {
+    type Error = GeometryConversionError;
+
+    /// Converts a `geojson` geometry, keeping its bounding box. Geometry
+    /// collections are not supported.
+    fn try_from(geometry: geojson::Geometry) -> Result<Self, Self::Error> {
+        let bbox = geometry.bbox;
+        Ok(match geometry.value {
+            geojson::Value::Point(coordinates) => {
+                GeoJsonGeometry::GeoJsonPoint(Box::new(GeoJsonPoint {
+                    coordinates,
+                    bbox,
+                    ..Default::default()
+                }))
+            }
+            geojson::Value::LineString(coordinates) => {
+                GeoJsonGeometry::GeoJsonLineString(Box::new(GeoJsonLineString {
+                    coordinates,
+                    bbox,
+                    ..Default::default()
+                }))
+            }
+            geojson::Value::Polygon(coordinates) => {
+                GeoJsonGeometry::GeoJsonPolygon(Box::new(GeoJsonPolygon {
+                    coordinates,
+                    bbox,
+                    ..Default::default()
+                }))
+            }
+            geojson::Value::MultiPoint(coordinates) => {
+                GeoJsonGeometry::GeoJsonMultiPoint(Box::new(GeoJsonMultiPoint {
+                    coordinates,
+                    bbox,
+                    ..Default::default()
+                }))
+            }
+            geojson::Value::MultiLineString(coordinates) => {
+                GeoJsonGeometry::GeoJsonMultiLineString(Box::new(GeoJsonMultiLineString {
+                    coordinates,
+                    bbox,
+                    ..Default::default()
+                }))
+            }
+            geojson::Value::MultiPolygon(coordinates) => {
+                GeoJsonGeometry::GeoJsonMultiPolygon(Box::new(GeoJsonMultiPolygon {
+                    coordinates,
+                    bbox,
+                    ..Default::default()
+                }))
+            }
+            geojson::Value::GeometryCollection(_) => {
+                return Err(GeometryConversionError::new("GeometryCollection"));
+            }
+        })
+    }
+}
+
+fn coord(position: &[f64]) -> Result<Coord<f64>, GeometryConversionError> {
+    match position {
+        [x, y, ..] => Ok(Coord { x: *x, y: *y }),
+        _ => Err(GeometryConversionError::new(&format!(
+            "position {position:?} has fewer than two coordinates"
+        ))),
+    }
+}
+
+fn to_line_string(positions: &[Vec<f64>]) -> Result<LineString<f64>, GeometryConversionError> {
+    positions
+        .iter()
+        .map(|position| coord(position))
+        .collect::<Result<_, _>>()
+        .map(LineString)
+}
+
+fn to_polygon(rings: &[Vec<Vec<f64>>]) -> Result<Polygon<f64>, GeometryConversionError> {
+    let mut rings = rings.iter().map(|ring| to_line_string(ring));
+    let exterior = rings
+        .next()
+        .transpose()?
+        .unwrap_or_else(|| LineString(Vec::new()));
+    Ok(Polygon::new(exterior, rings.collect::<Result<_, _>>()?))
+}
+
+fn position(coord: Coord<f64>) -> Vec<f64> {
+    vec![coord.x, coord.y]
+}
+
+fn positions(line_string: &LineString<f64>) -> Vec<Vec<f64>> {
+    line_string.coords().copied().map(position).collect()
+}
+
+fn rings(polygon: &Polygon<f64>) -> Vec<Vec<Vec<f64>>> {
+    if polygon.exterior().0.is_empty() {
+        return Vec::new();
+    }
+    std::iter::once(polygon.exterior())
+        .chain(polygon.interiors())
+        .map(positions)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(west: f64, south: f64, east: f64, north: f64) -> Vec<Vec<f64>> {
+        vec![
+            vec![west, south],
+            vec![east, south],
+            vec![east, north],
+            vec![west, north],
+            vec![west, south],
+        ]
+    }
+
+    #[test]
+    fn test_geo_types_round_trip() {
+        let geometry = GeoJsonGeometry::GeoJsonMultiPolygon(Box::new(GeoJsonMultiPolygon {
+            coordinates: vec![
+                vec![
+                    square(-113.0, 33.0, -111.0, 34.0),
+                    square(-112.2, 33.3, -111.9, 33.6),
+                ],
+                vec![square(-111.0, 32.0, -110.0, 33.0)],
+            ],
+            ..Default::default()
+        }));
+        let converted = geo_types::Geometry::try_from(&geometry).unwrap();
+        let geo_types::Geometry::MultiPolygon(multi_polygon) = &converted else {
+            panic!("expected a multipolygon");
+        };
+        assert_eq!(multi_polygon.0.len(), 2);
+        assert_eq!(multi_polygon.0[0].interiors().len(), 1);
+        assert_eq!(GeoJsonGeometry::try_from(converted).unwrap(), geometry);
+    }
+
+    #[test]
+    fn test_geo_types_drops_extra_dimensions() {
+        let point = GeoJsonPoint {
+            coordinates: vec![-112.07, 33.45, 331.0],
+            ..Default::default()
+        };
+        let converted = geo_types::Point::try_from(&point).unwrap();
+        assert_eq!(converted, geo_types::Point::new(-112.07, 33.45));
+        assert_eq!(
+            LatLon::from(converted),
+            LatLon {
+                latitude: 33.45,
+                longitude: -112.07
+            }
+        );
+
+        let point = GeoJsonPoint {
+            coordinates: vec![-112.07],
+            ..Default::default()
+        };
+        assert!(geo_types::Point::try_from(&point).is_err());
+    }
+
+    #[test]
+    fn test_geo_types_rect_and_collection() {
+        let rect = geo_types::Rect::new(Coord { x: -113.0, y: 33.0 }, Coord { x: -111.0, y: 34.0 });
+        let geometry = GeoJsonGeometry::try_from(geo_types::Geometry::Rect(rect)).unwrap();
+        let GeoJsonGeometry::GeoJsonPolygon(polygon) = geometry else {
+            panic!("expected a polygon");
+        };
+        assert_eq!(polygon.coordinates[0].len(), 5);
+
+        let collection = geo_types::Geometry::GeometryCollection(Default::default());
+        assert!(GeoJsonGeometry::try_from(collection).is_err());
+    }
+
+    #[test]
+    fn test_geojson_round_trip_keeps_bbox() {
+        let geometry = GeoJsonGeometry::GeoJsonPolygon(Box::new(GeoJsonPolygon {
+            coordinates: vec![square(-113.0, 33.0, -111.0, 34.0)],
+            bbox: Some(vec![-113.0, 33.0, -111.0, 34.0]),
+            ..Default::default()
+        }));
+        let converted = geojson::Geometry::from(&geometry);
+        assert_eq!(converted.bbox, Some(vec![-113.0, 33.0, -111.0, 34.0]));
+        assert!(matches!(converted.value, geojson::Value::Polygon(_)));
+        assert_eq!(GeoJsonGeometry::try_from(converted).unwrap(), geometry);
+
+        let collection = geojson::Geometry::new(geojson::Value::GeometryCollection(Vec::new()));
+        assert!(GeoJsonGeometry::try_from(collection).is_err());
+    }
+}
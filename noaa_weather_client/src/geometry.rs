@@ -0,0 +1,286 @@
+//! Geometry helpers for locating points within alert and zone areas.
+//!
+//! Alerts carry a polygon when the issuer drew one (storm-based warnings), and
+//! otherwise only list the zones and counties they cover in
+//! [`Alert::affected_zones`]. [`ZoneGeometryCache`] answers whether an alert
+//! covers a location using the polygon when present, and otherwise fetches and
+//! caches the zone boundaries with [`zones::get_zone`].
+//!
+//! Coordinates follow GeoJSON order: positions are `[longitude, latitude]`.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::geometry::LatLon;
+//! use noaa_weather_client::models::{GeoJsonGeometry, GeoJsonPolygon};
+//!
+//! let square = GeoJsonGeometry::GeoJsonPolygon(Box::new(GeoJsonPolygon {
+//!     coordinates: vec![vec![
+//!         vec![-112.5, 33.0],
+//!         vec![-111.5, 33.0],
+//!         vec![-111.5, 34.0],
+//!         vec![-112.5, 34.0],
+//!         vec![-112.5, 33.0],
+//!     ]],
+//!     ..Default::default()
+//! }));
+//! assert!(square.contains(LatLon { latitude: 33.45, longitude: -112.07 }));
+//! assert!(!square.contains(LatLon { latitude: 32.22, longitude: -110.97 }));
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::apis::Error;
+use crate::apis::configuration::Configuration;
+use crate::apis::zones::{self, ZoneError};
+use crate::models::{Alert, AlertCollectionGeoJson, GeoJsonGeometry, NwsZoneType};
+
+/// A latitude/longitude pair in decimal degrees.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LatLon {
+    pub latitude: f64,
+    pub longitude: f64,
+}
+
+/// Returns true if the point lies inside the polygon described by `rings`.
+///
+/// The first ring is the exterior boundary and any further rings are holes.
+pub fn polygon_contains(rings: &[Vec<Vec<f64>>], point: LatLon) -> bool {
+    let Some((exterior, holes)) = rings.split_first() else {
+        return false;
+    };
+    ring_contains(exterior, point) && !holes.iter().any(|hole| ring_contains(hole, point))
+}
+
+/// Returns true if the point lies inside the ring, using the even-odd rule.
+fn ring_contains(ring: &[Vec<f64>], point: LatLon) -> bool {
+    let (x, y) = (point.longitude, point.latitude);
+    let mut inside = false;
+    let positions: Vec<(f64, f64)> = ring
+        .iter()
+        .filter_map(|position| Some((*position.first()?, *position.get(1)?)))
+        .collect();
+    for (index, &(x1, y1)) in positions.iter().enumerate() {
+        let (x2, y2) = positions[(index + 1) % positions.len()];
+        if (y1 > y) != (y2 > y) && x < (x2 - x1) * (y - y1) / (y2 - y1) + x1 {
+            inside = !inside;
+        }
+    }
+    inside
+}
+
+/// Extracts the zone type and ID from an `affectedZones` URL such as
+/// `https://api.weather.gov/zones/forecast/AZZ540`.
+pub fn parse_zone_url(url: &str) -> Option<(NwsZoneType, &str)> {
+    let mut segments = url.trim_end_matches('/').rsplit('/');
+    let id = segments.next()?;
+    let zone_type = segments.next()?.parse().ok()?;
+    (segments.next()? == "zones").then_some((zone_type, id))
+}
+
+/// Caches zone boundaries fetched from the API for alert coverage checks.
+///
+/// The cache is safe to share between tasks. Zones that have no geometry are
+/// cached too, so each zone is requested at most once per cache in sequential use.
+#[derive(Debug, Default)]
+pub struct ZoneGeometryCache {
+    zones: Mutex<HashMap<String, Option<Arc<GeoJsonGeometry>>>>,
+}
+
+impl ZoneGeometryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the boundary of a zone, fetching it with [`zones::get_zone`] on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error<ZoneError>`] if the zone request fails. Failures are not cached.
+    pub async fn zone_geometry(
+        &self,
+        configuration: &Configuration,
+        zone_type: NwsZoneType,
+        id: &str,
+    ) -> Result<Option<Arc<GeoJsonGeometry>>, Error<ZoneError>> {
+        let key = format!("{zone_type}/{id}");
+        if let Some(geometry) = self.lock().get(&key) {
+            return Ok(geometry.clone());
+        }
+        let zone = zones::get_zone(configuration, zone_type, id, None).await?;
+        let geometry = zone.geometry.map(|geometry| Arc::new(*geometry));
+        self.lock().insert(key, geometry.clone());
+        Ok(geometry)
+    }
+
+    /// Returns true if an alert covers the point.
+    ///
+    /// Uses the alert's own `geometry` when present. Otherwise the boundaries of
+    /// the alert's `affectedZones` are fetched and checked in turn.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error<ZoneError>`] if a zone boundary has to be fetched and the request fails.
+    pub async fn alert_covers(
+        &self,
+        configuration: &Configuration,
+        alert: &Alert,
+        geometry: Option<&GeoJsonGeometry>,
+        point: LatLon,
+    ) -> Result<bool, Error<ZoneError>> {
+        if let Some(geometry) = geometry {
+            return Ok(geometry.contains(point));
+        }
+        let zone_urls = alert.affected_zones.iter().flatten();
+        for (zone_type, id) in zone_urls.filter_map(|url| parse_zone_url(url)) {
+            let zone_geometry = self.zone_geometry(configuration, zone_type, id).await?;
+            if zone_geometry.is_some_and(|geometry| geometry.contains(point)) {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Filters an alert collection down to the alerts that cover the point.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error<ZoneError>`] if a zone boundary has to be fetched and the request fails.
+    pub async fn alerts_covering<'a>(
+        &self,
+        configuration: &Configuration,
+        alerts: &'a AlertCollectionGeoJson,
+        point: LatLon,
+    ) -> Result<Vec<&'a Alert>, Error<ZoneError>> {
+        let mut covering = Vec::new();
+        for feature in &alerts.features {
+            let Some(alert) = feature.properties.as_deref() else {
+                continue;
+            };
+            if self
+                .alert_covers(configuration, alert, feature.geometry.as_deref(), point)
+                .await?
+            {
+                covering.push(alert);
+            }
+        }
+        Ok(covering)
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Option<Arc<GeoJsonGeometry>>>> {
+        self.zones
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{GeoJsonMultiPolygon, GeoJsonPolygon};
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    fn square(west: f64, south: f64, east: f64, north: f64) -> Vec<Vec<f64>> {
+        vec![
+            vec![west, south],
+            vec![east, south],
+            vec![east, north],
+            vec![west, north],
+            vec![west, south],
+        ]
+    }
+
+    const PHOENIX: LatLon = LatLon {
+        latitude: 33.45,
+        longitude: -112.07,
+    };
+
+    #[test]
+    fn test_polygon_with_hole() {
+        let rings = vec![
+            square(-113.0, 33.0, -111.0, 34.0),
+            square(-112.2, 33.3, -111.9, 33.6),
+        ];
+        assert!(!polygon_contains(&rings, PHOENIX));
+        assert!(polygon_contains(
+            &rings,
+            LatLon {
+                latitude: 33.8,
+                longitude: -112.5
+            }
+        ));
+    }
+
+    #[test]
+    fn test_multi_polygon_contains() {
+        let geometry = GeoJsonGeometry::GeoJsonMultiPolygon(Box::new(GeoJsonMultiPolygon {
+            coordinates: vec![
+                vec![square(-111.0, 32.0, -110.0, 33.0)],
+                vec![square(-113.0, 33.0, -111.0, 34.0)],
+            ],
+            ..Default::default()
+        }));
+        assert!(geometry.contains(PHOENIX));
+    }
+
+    #[test]
+    fn test_parse_zone_url() {
+        assert_eq!(
+            parse_zone_url("https://api.weather.gov/zones/forecast/AZZ540"),
+            Some((NwsZoneType::Forecast, "AZZ540"))
+        );
+        assert_eq!(
+            parse_zone_url("https://api.weather.gov/zones/county/AZC013"),
+            Some((NwsZoneType::County, "AZC013"))
+        );
+        assert_eq!(parse_zone_url("https://api.weather.gov/points/1,2"), None);
+    }
+
+    #[tokio::test]
+    async fn test_alert_covers_falls_back_to_zones() {
+        let server = MockServer::start().await;
+        let zone = serde_json::json!({
+            "type": "Feature",
+            "geometry": GeoJsonGeometry::GeoJsonPolygon(Box::new(GeoJsonPolygon {
+                coordinates: vec![square(-113.0, 33.0, -111.0, 34.0)],
+                ..Default::default()
+            })),
+            "properties": {}
+        });
+        Mock::given(method("GET"))
+            .and(path("/zones/forecast/AZZ540"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(zone))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let configuration = Configuration::new(None, Some(server.uri()), None, None);
+        let alert = Alert {
+            affected_zones: Some(vec![format!("{}/zones/forecast/AZZ540", server.uri())]),
+            ..Alert::new()
+        };
+        let cache = ZoneGeometryCache::new();
+        for _ in 0..2 {
+            assert!(
+                cache
+                    .alert_covers(&configuration, &alert, None, PHOENIX)
+                    .await
+                    .unwrap()
+            );
+        }
+        let tucson = LatLon {
+            latitude: 32.22,
+            longitude: -110.97,
+        };
+        assert!(
+            !cache
+                .alert_covers(&configuration, &alert, None, tucson)
+                .await
+                .unwrap()
+        );
+    }
+}
@@ -1,8 +1,32 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
+pub mod afd;
+pub mod alert_parameters;
+pub mod alert_thread;
 pub mod apis;
+pub mod climate;
+pub mod derived;
+pub mod flight_category;
+pub mod forecast_icon;
+#[cfg(feature = "geo")]
+pub mod geo;
+pub mod geometry;
+pub mod lsr;
+pub mod metar;
 pub mod models;
+pub mod navaid;
+pub mod product_diff;
+pub mod product_header;
+pub mod product_search;
+pub mod same;
+pub mod segment;
+pub mod sigmet;
+pub mod taf;
+pub mod ugc;
 pub mod utils;
+pub mod vtec;
+pub mod wkt;
+pub mod zone_forecast;
 
 pub use apis::configuration::Configuration;
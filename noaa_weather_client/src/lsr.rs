@@ -0,0 +1,430 @@
+//! Decoder for Local Storm Report (LSR) text products.
+//!
+//! LSR products list reports of tornadoes, hail, damaging winds, flooding,
+//! and other significant weather. Each report takes two fixed-column lines,
+//! optionally followed by indented remarks:
+//!
+//! ```text
+//! ..TIME...   ...EVENT...      ...CITY LOCATION...     ...LAT.LON...
+//! ..DATE...   ....MAG....      ..COUNTY LOCATION..ST.. ...SOURCE....
+//!             ..REMARKS..
+//!
+//! 0530 PM     Hail             3 NW Mesa               33.45N 111.87W
+//! 10/18/2025  E1.75 INCH       Maricopa           AZ   Trained Spotter
+//!
+//!             Quarter to golf ball size hail.
+//! ```
+//!
+//! Report times are in the local time zone of the product's issuance time.
+//! See NWS Directive 10-517 for the format.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::lsr::{LsrEventType, parse_storm_reports};
+//!
+//! let text = "\
+//! 0530 PM     Hail             3 NW Mesa               33.45N 111.87W
+//! 10/18/2025  E1.75 INCH       Maricopa           AZ   Trained Spotter
+//! ";
+//! let reports = parse_storm_reports(text);
+//! assert_eq!(reports[0].event, LsrEventType::Hail);
+//! assert_eq!(reports[0].magnitude.as_ref().unwrap().value, 1.75);
+//! assert_eq!(reports[0].longitude, Some(-111.87));
+//! ```
+
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use jiff::Timestamp;
+use jiff::civil::{Date, DateTime, Time};
+use jiff::tz::Offset;
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::product_header::{self, ProductHeader};
+
+const EVENT_COLUMN: usize = 12;
+const LOCATION_COLUMN: usize = 29;
+const STATE_COLUMN: usize = 48;
+const LAT_LON_COLUMN: usize = 53;
+
+/// A single report from a Local Storm Report product.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StormReport {
+    /// When the event occurred, if the product's time zone is known.
+    pub time: Option<Timestamp>,
+    /// When the event occurred, in the product's local time.
+    pub local_time: DateTime,
+    /// The type of event.
+    pub event: LsrEventType,
+    /// The hail size, wind speed, rainfall, snowfall, or other magnitude.
+    pub magnitude: Option<Magnitude>,
+    /// The location, e.g., `3 NW Mesa`.
+    pub location: String,
+    /// The latitude in decimal degrees.
+    pub latitude: Option<f64>,
+    /// The longitude in decimal degrees, negative for west.
+    pub longitude: Option<f64>,
+    /// The county, parish, or marine zone name.
+    pub county: String,
+    /// The two-letter state or marine area abbreviation.
+    pub state: String,
+    /// Who made the report, e.g., `Trained Spotter`.
+    pub source: String,
+    /// The remarks, joined into a single line.
+    pub remarks: Option<String>,
+}
+
+/// The magnitude of a storm report.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Magnitude {
+    /// Whether the magnitude was measured or estimated.
+    pub qualifier: Option<MagnitudeQualifier>,
+    /// The value in `unit`.
+    pub value: f64,
+    /// The unit as written, e.g., `INCH` or `MPH`.
+    pub unit: String,
+}
+
+impl fmt::Display for Magnitude {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.qualifier {
+            Some(MagnitudeQualifier::Estimated) => write!(f, "E")?,
+            Some(MagnitudeQualifier::Measured) => write!(f, "M")?,
+            Some(MagnitudeQualifier::Unknown) => write!(f, "U")?,
+            None => {}
+        }
+        write!(f, "{}", self.value)?;
+        if !self.unit.is_empty() {
+            write!(f, " {}", self.unit)?;
+        }
+        Ok(())
+    }
+}
+
+impl Magnitude {
+    /// Parses a magnitude such as `E1.75 INCH` or `M65 MPH`.
+    fn parse(string: &str) -> Option<Self> {
+        let string = string.trim();
+        let (qualifier, rest) = match string.split_at_checked(1) {
+            Some(("E", rest)) => (Some(MagnitudeQualifier::Estimated), rest),
+            Some(("M", rest)) => (Some(MagnitudeQualifier::Measured), rest),
+            Some(("U", rest)) => (Some(MagnitudeQualifier::Unknown), rest),
+            _ => (None, string),
+        };
+        let (value, unit) = rest.split_once(' ').unwrap_or((rest, ""));
+        Some(Self {
+            qualifier,
+            value: value.parse().ok()?,
+            unit: unit.trim().to_string(),
+        })
+    }
+}
+
+/// Whether a storm report magnitude was measured or estimated.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MagnitudeQualifier {
+    Estimated,
+    Measured,
+    Unknown,
+}
+
+/// The type of event in a storm report.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub enum LsrEventType {
+    Tornado,
+    FunnelCloud,
+    Waterspout,
+    Hail,
+    ThunderstormWindGust,
+    ThunderstormWindDamage,
+    NonThunderstormWindGust,
+    NonThunderstormWindDamage,
+    MarineThunderstormWind,
+    FlashFlood,
+    Flood,
+    CoastalFlood,
+    Rain,
+    HeavyRain,
+    Snow,
+    HeavySnow,
+    Blizzard,
+    IceStorm,
+    FreezingRain,
+    Sleet,
+    Lightning,
+    DustStorm,
+    Wildfire,
+    /// Any other event, as written in the product.
+    Other(String),
+}
+
+impl fmt::Display for LsrEventType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tornado => write!(f, "TORNADO"),
+            Self::FunnelCloud => write!(f, "FUNNEL CLOUD"),
+            Self::Waterspout => write!(f, "WATERSPOUT"),
+            Self::Hail => write!(f, "HAIL"),
+            Self::ThunderstormWindGust => write!(f, "TSTM WND GST"),
+            Self::ThunderstormWindDamage => write!(f, "TSTM WND DMG"),
+            Self::NonThunderstormWindGust => write!(f, "NON-TSTM WND GST"),
+            Self::NonThunderstormWindDamage => write!(f, "NON-TSTM WND DMG"),
+            Self::MarineThunderstormWind => write!(f, "MARINE TSTM WIND"),
+            Self::FlashFlood => write!(f, "FLASH FLOOD"),
+            Self::Flood => write!(f, "FLOOD"),
+            Self::CoastalFlood => write!(f, "COASTAL FLOOD"),
+            Self::Rain => write!(f, "RAIN"),
+            Self::HeavyRain => write!(f, "HEAVY RAIN"),
+            Self::Snow => write!(f, "SNOW"),
+            Self::HeavySnow => write!(f, "HEAVY SNOW"),
+            Self::Blizzard => write!(f, "BLIZZARD"),
+            Self::IceStorm => write!(f, "ICE STORM"),
+            Self::FreezingRain => write!(f, "FREEZING RAIN"),
+            Self::Sleet => write!(f, "SLEET"),
+            Self::Lightning => write!(f, "LIGHTNING"),
+            Self::DustStorm => write!(f, "DUST STORM"),
+            Self::Wildfire => write!(f, "WILDFIRE"),
+            Self::Other(event) => write!(f, "{event}"),
+        }
+    }
+}
+
+impl FromStr for LsrEventType {
+    type Err = Infallible;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let event = string.trim().to_ascii_uppercase();
+        Ok(match event.as_str() {
+            "TORNADO" => Self::Tornado,
+            "FUNNEL CLOUD" => Self::FunnelCloud,
+            "WATERSPOUT" => Self::Waterspout,
+            "HAIL" => Self::Hail,
+            "TSTM WND GST" => Self::ThunderstormWindGust,
+            "TSTM WND DMG" => Self::ThunderstormWindDamage,
+            "NON-TSTM WND GST" => Self::NonThunderstormWindGust,
+            "NON-TSTM WND DMG" => Self::NonThunderstormWindDamage,
+            "MARINE TSTM WIND" => Self::MarineThunderstormWind,
+            "FLASH FLOOD" => Self::FlashFlood,
+            "FLOOD" => Self::Flood,
+            "COASTAL FLOOD" => Self::CoastalFlood,
+            "RAIN" => Self::Rain,
+            "HEAVY RAIN" => Self::HeavyRain,
+            "SNOW" => Self::Snow,
+            "HEAVY SNOW" => Self::HeavySnow,
+            "BLIZZARD" => Self::Blizzard,
+            "ICE STORM" => Self::IceStorm,
+            "FREEZING RAIN" => Self::FreezingRain,
+            "SLEET" => Self::Sleet,
+            "LIGHTNING" => Self::Lightning,
+            "DUST STORM" => Self::DustStorm,
+            "WILDFIRE" => Self::Wildfire,
+            _ => Self::Other(event),
+        })
+    }
+}
+
+/// Finds and parses every storm report in a Local Storm Report product.
+///
+/// Report times are resolved with the time zone of the product's issuance
+/// time; when the text has no product header, [`StormReport::time`] is `None`.
+/// Lines that do not form a report are skipped.
+pub fn parse_storm_reports(text: &str) -> Vec<StormReport> {
+    let offset = text
+        .parse::<ProductHeader>()
+        .ok()
+        .and_then(|header| header.local_issuance_time)
+        .and_then(|line| {
+            let zone = line.split_whitespace().nth(2)?;
+            Offset::from_hours(product_header::time_zone_offset(zone)?).ok()
+        });
+
+    let lines: Vec<&str> = text.lines().map(str::trim_end).collect();
+    let mut reports: Vec<StormReport> = Vec::new();
+    let mut index = 0;
+    while index < lines.len() {
+        let Some(mut report) = lines
+            .get(index + 1)
+            .and_then(|second| parse_report(lines[index], second, offset))
+        else {
+            index += 1;
+            continue;
+        };
+        index += 2;
+        let mut remarks = Vec::new();
+        while let Some(line) = lines.get(index) {
+            let trimmed = line.trim();
+            if is_report_start(line) || trimmed == "&&" || trimmed == "$$" {
+                break;
+            }
+            if !trimmed.is_empty() {
+                remarks.push(trimmed);
+            }
+            index += 1;
+        }
+        report.remarks = (!remarks.is_empty()).then(|| remarks.join(" "));
+        reports.push(report);
+    }
+    reports
+}
+
+/// Parses the two fixed-column lines of a report.
+fn parse_report(first: &str, second: &str, offset: Option<Offset>) -> Option<StormReport> {
+    if !is_report_start(first) {
+        return None;
+    }
+    let (hour, minute) = product_header::parse_clock_time(&first[..4], &first[5..7])?;
+    let date = parse_date(column(second, 0, EVENT_COLUMN))?;
+    let local_time = DateTime::from_parts(date, Time::new(hour, minute, 0, 0).ok()?);
+    let (latitude, longitude) = parse_lat_lon(column(first, LAT_LON_COLUMN, first.len()))
+        .map_or((None, None), |(latitude, longitude)| {
+            (Some(latitude), Some(longitude))
+        });
+
+    Some(StormReport {
+        time: offset.and_then(|offset| offset.to_timestamp(local_time).ok()),
+        local_time,
+        event: column(first, EVENT_COLUMN, LOCATION_COLUMN).parse().ok()?,
+        magnitude: Magnitude::parse(column(second, EVENT_COLUMN, LOCATION_COLUMN)),
+        location: column(first, LOCATION_COLUMN, LAT_LON_COLUMN).to_string(),
+        latitude,
+        longitude,
+        county: column(second, LOCATION_COLUMN, STATE_COLUMN).to_string(),
+        state: column(second, STATE_COLUMN, LAT_LON_COLUMN).to_string(),
+        source: column(second, LAT_LON_COLUMN, second.len()).to_string(),
+        remarks: None,
+    })
+}
+
+/// Returns true for the first line of a report, which starts with an `HHMM AM` time.
+fn is_report_start(line: &str) -> bool {
+    let bytes = line.as_bytes();
+    bytes.len() > LOCATION_COLUMN
+        && bytes[..4].iter().all(u8::is_ascii_digit)
+        && bytes[4] == b' '
+        && matches!(&bytes[5..7], b"AM" | b"PM")
+}
+
+/// Returns the trimmed text between two byte columns, clamped to the line length.
+fn column(line: &str, start: usize, end: usize) -> &str {
+    let end = end.min(line.len());
+    line.get(start.min(end)..end).unwrap_or_default().trim()
+}
+
+/// Parses an `MM/DD/YYYY` date.
+fn parse_date(string: &str) -> Option<Date> {
+    let mut parts = string.split('/');
+    let month = parts.next()?.parse().ok()?;
+    let day = parts.next()?.parse().ok()?;
+    let year = parts.next()?.parse().ok()?;
+    Date::new(year, month, day).ok()
+}
+
+/// Parses a `33.45N 111.87W` latitude and longitude.
+fn parse_lat_lon(string: &str) -> Option<(f64, f64)> {
+    let (latitude, longitude) = string.split_once(' ')?;
+    Some((
+        parse_coordinate(latitude, 'N', 'S')?,
+        parse_coordinate(longitude, 'E', 'W')?,
+    ))
+}
+
+/// Parses a coordinate with a hemisphere suffix, negating it for the `negative` hemisphere.
+fn parse_coordinate(string: &str, positive: char, negative: char) -> Option<f64> {
+    let string = string.trim();
+    match string.strip_suffix(positive) {
+        Some(value) => value.parse().ok(),
+        None => string
+            .strip_suffix(negative)?
+            .parse::<f64>()
+            .ok()
+            .map(|value| -value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LSR: &str = "
+000
+NWUS55 KPSR 190045
+LSRPSR
+
+Preliminary Local Storm Report...Summary
+National Weather Service Phoenix AZ
+545 PM MST Sat Oct 18 2025
+
+..TIME...   ...EVENT...      ...CITY LOCATION...     ...LAT.LON...
+..DATE...   ....MAG....      ..COUNTY LOCATION..ST.. ...SOURCE....
+            ..REMARKS..
+
+0530 PM     Hail             3 NW Mesa               33.45N 111.87W
+10/18/2025  E1.75 INCH       Maricopa           AZ   Trained Spotter
+
+            Quarter to golf ball size hail
+            covering the ground.
+
+0545 PM     Tstm Wnd Gst     Phoenix Sky Harbor Air  33.43N 112.01W
+10/18/2025  M65 MPH          Maricopa           AZ   ASOS
+
+0600 PM     Flash Flood      2 E Gila Bend           32.95N 112.68W
+10/18/2025                   Maricopa           AZ   Public
+
+&&
+
+$$
+";
+
+    #[test]
+    fn test_parse_storm_reports() {
+        let reports = parse_storm_reports(LSR);
+        assert_eq!(reports.len(), 3);
+
+        let hail = &reports[0];
+        assert_eq!(hail.event, LsrEventType::Hail);
+        assert_eq!(hail.time, Some("2025-10-19T00:30:00Z".parse().unwrap()));
+        assert_eq!(hail.local_time.to_string(), "2025-10-18T17:30:00");
+        let magnitude = hail.magnitude.as_ref().unwrap();
+        assert_eq!(magnitude.qualifier, Some(MagnitudeQualifier::Estimated));
+        assert_eq!(magnitude.to_string(), "E1.75 INCH");
+        assert_eq!(hail.location, "3 NW Mesa");
+        assert_eq!(
+            (hail.latitude, hail.longitude),
+            (Some(33.45), Some(-111.87))
+        );
+        assert_eq!(hail.county, "Maricopa");
+        assert_eq!(hail.state, "AZ");
+        assert_eq!(hail.source, "Trained Spotter");
+        assert_eq!(
+            hail.remarks.as_deref(),
+            Some("Quarter to golf ball size hail covering the ground.")
+        );
+
+        let gust = &reports[1];
+        assert_eq!(gust.event, LsrEventType::ThunderstormWindGust);
+        assert_eq!(gust.magnitude.as_ref().unwrap().value, 65.0);
+        assert_eq!(gust.source, "ASOS");
+        assert_eq!(gust.remarks, None);
+
+        assert_eq!(reports[2].event, LsrEventType::FlashFlood);
+        assert_eq!(reports[2].magnitude, None);
+    }
+
+    #[test]
+    fn test_event_type_round_trip() {
+        let event: LsrEventType = "Non-Tstm Wnd Dmg".parse().unwrap();
+        assert_eq!(event, LsrEventType::NonThunderstormWindDamage);
+        assert_eq!(event.to_string(), "NON-TSTM WND DMG");
+        for event in ["RAIN", "HEAVY RAIN"] {
+            assert_eq!(event.parse::<LsrEventType>().unwrap().to_string(), event);
+        }
+        assert_eq!(
+            "Rip Currents".parse::<LsrEventType>().unwrap(),
+            LsrEventType::Other("RIP CURRENTS".to_string())
+        );
+    }
+}
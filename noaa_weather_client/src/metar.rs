@@ -0,0 +1,895 @@
+//! Parsing for raw METAR and SPECI reports.
+//!
+//! [`Observation::raw_message`](crate::models::Observation::raw_message) holds
+//! the original METAR, which carries details the decoded JSON lacks: remarks
+//! such as peak wind, sea-level pressure, hourly precipitation (`P0012`),
+//! pressure tendency, and the precise temperatures of the `T` group.
+//! [`Metar`] decodes the main body and these common remarks, and
+//! [`Metar::fill_observation`] uses them to fill fields NWS returned as `null`.
+//!
+//! See the Federal Meteorological Handbook No. 1 (FMH-1), chapter 12, for the
+//! report format.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::metar::Metar;
+//!
+//! let metar: Metar = "KPHX 011751Z 27015G25KT 10SM FEW080 SCT150 38/M02 A2990 RMK AO2 PK WND 26032/1720 SLP102 P0000 T03781022"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(metar.station, "KPHX");
+//! assert_eq!(metar.wind.unwrap().to_string(), "27015G25KT");
+//! assert_eq!(metar.remarks.sea_level_pressure_hpa, Some(1010.2));
+//! assert_eq!(metar.remarks.temperature, Some(37.8));
+//! assert_eq!(metar.remarks.peak_wind.unwrap().speed_knots, 32.0);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::metar_phenomenon::{Intensity, Modifier, Weather};
+use crate::models::{
+    MetarPhenomenon, MetarSkyCoverage, Observation, ObservationCloudLayersInner, WmoUnitCode,
+};
+use crate::taf::{CloudCover, CloudLayer, Visibility, Wind};
+use crate::utils::units::{
+    HECTOPASCALS_PER_INCH_OF_MERCURY, KNOTS_PER_METER_PER_SECOND, METERS_PER_FOOT,
+    METERS_PER_STATUTE_MILE, fill, value_unit,
+};
+
+const KILOMETERS_PER_HOUR_PER_KNOT: f64 = 1.852;
+const MILLIMETERS_PER_INCH: f64 = 25.4;
+
+/// An error returned when a METAR report cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseMetarError {
+    invalid_value: String,
+}
+
+impl ParseMetarError {
+    fn new(invalid_value: &str) -> Self {
+        Self {
+            invalid_value: invalid_value.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseMetarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid METAR value: {}", self.invalid_value)
+    }
+}
+
+impl std::error::Error for ParseMetarError {}
+
+/// A decoded METAR or SPECI report.
+///
+/// Winds are given in knots, visibility in statute miles, cloud bases in feet,
+/// temperatures in degrees Celsius, and pressures in hectopascals. Groups the
+/// parser does not recognize, such as runway visual range, are skipped.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Metar {
+    /// Whether the report is a special (`SPECI`) rather than routine report.
+    pub special: bool,
+    /// The ICAO identifier of the station (e.g., "KPHX").
+    pub station: String,
+    /// The day of the month of the observation.
+    pub day: u8,
+    /// The UTC hour of the observation.
+    pub hour: u8,
+    /// The UTC minute of the observation.
+    pub minute: u8,
+    /// Whether the report is fully automated (`AUTO`).
+    pub automated: bool,
+    /// Whether the report is a correction (`COR`).
+    pub corrected: bool,
+    pub wind: Option<Wind>,
+    /// The range of a variable wind direction in degrees (e.g., `240V300`).
+    pub wind_variable_between: Option<(u16, u16)>,
+    pub visibility: Option<Visibility>,
+    /// Whether `CAVOK` (ceiling and visibility OK) was reported.
+    pub cavok: bool,
+    pub weather: Vec<MetarPhenomenon>,
+    pub clouds: Vec<CloudLayer>,
+    pub temperature: Option<f64>,
+    pub dewpoint: Option<f64>,
+    /// The altimeter setting, from an `A` (inches of mercury) or `Q` (hectopascals) group.
+    pub altimeter_hpa: Option<f64>,
+    pub remarks: MetarRemarks,
+}
+
+/// The commonly used remarks of a METAR report.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetarRemarks {
+    /// The station type (`AO1` without or `AO2` with a precipitation discriminator).
+    pub station_type: Option<String>,
+    pub peak_wind: Option<PeakWind>,
+    /// The sea-level pressure from the `SLPppp` group.
+    pub sea_level_pressure_hpa: Option<f64>,
+    /// The precipitation in the past hour from the `Prrrr` group.
+    pub hourly_precipitation_inches: Option<f64>,
+    /// The 3- or 6-hour precipitation from the `6RRRR` group, depending on the report time.
+    pub period_precipitation_inches: Option<f64>,
+    /// The 24-hour precipitation from the `7RRRR` group.
+    pub precipitation_24_hour_inches: Option<f64>,
+    /// The temperature to tenths of a degree from the `T` group.
+    pub temperature: Option<f64>,
+    /// The dewpoint to tenths of a degree from the `T` group.
+    pub dewpoint: Option<f64>,
+    /// The 6-hour maximum temperature from the `1snTTT` group.
+    pub max_temperature_6_hour: Option<f64>,
+    /// The 6-hour minimum temperature from the `2snTTT` group.
+    pub min_temperature_6_hour: Option<f64>,
+    /// The 24-hour maximum temperature from the `4snTTTsnTTT` group.
+    pub max_temperature_24_hour: Option<f64>,
+    /// The 24-hour minimum temperature from the `4snTTTsnTTT` group.
+    pub min_temperature_24_hour: Option<f64>,
+    pub pressure_tendency: Option<PressureTendency>,
+    /// The remarks section as reported, without the `RMK` keyword.
+    pub text: String,
+}
+
+/// The peak wind since the last routine report (`PK WND dddff/hhmm`).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PeakWind {
+    pub direction: u16,
+    pub speed_knots: f64,
+    /// The hour of the peak wind, when it differs from the hour of the report.
+    pub hour: Option<u8>,
+    pub minute: u8,
+}
+
+/// The 3-hour pressure tendency (`5appp`).
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PressureTendency {
+    /// The WMO code (0-8) describing the characteristic of the pressure change.
+    pub characteristic: u8,
+    /// The pressure change in hectopascals, negative when the pressure fell.
+    pub change_hpa: f64,
+}
+
+impl FromStr for Metar {
+    type Err = ParseMetarError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let string = string.trim().trim_end_matches('=');
+        let (body, remarks) = match string.split_once(" RMK") {
+            Some((body, remarks)) => (body, Some(remarks.trim())),
+            None => (string, None),
+        };
+        let mut tokens = body.split_whitespace().peekable();
+
+        let special = match tokens.peek() {
+            Some(&"SPECI") => {
+                tokens.next();
+                true
+            }
+            Some(&"METAR") => {
+                tokens.next();
+                false
+            }
+            _ => false,
+        };
+        let station = tokens
+            .next()
+            .filter(|station| {
+                station.len() == 4 && station.chars().all(|c| c.is_ascii_alphanumeric())
+            })
+            .ok_or_else(|| ParseMetarError::new(string))?
+            .to_owned();
+        let time = tokens.next().ok_or_else(|| ParseMetarError::new(string))?;
+        let (day, hour, minute) = parse_report_time(time)?;
+
+        let mut metar = Metar {
+            special,
+            station,
+            day,
+            hour,
+            minute,
+            automated: false,
+            corrected: false,
+            wind: None,
+            wind_variable_between: None,
+            visibility: None,
+            cavok: false,
+            weather: Vec::new(),
+            clouds: Vec::new(),
+            temperature: None,
+            dewpoint: None,
+            altimeter_hpa: None,
+            remarks: remarks.map(MetarRemarks::parse).unwrap_or_default(),
+        };
+
+        while let Some(token) = tokens.next() {
+            match token {
+                "AUTO" => metar.automated = true,
+                "COR" => metar.corrected = true,
+                "CAVOK" => metar.cavok = true,
+                _ => {
+                    if let Some(wind) = parse_wind(token) {
+                        metar.wind = Some(wind);
+                    } else if let Some(range) = parse_variable_wind(token) {
+                        metar.wind_variable_between = Some(range);
+                    } else if let Some(whole) = token
+                        .parse::<u8>()
+                        .ok()
+                        .filter(|_| tokens.peek().is_some_and(|next| next.ends_with("SM")))
+                    {
+                        // A whole number followed by a fraction, e.g., "1 1/2SM".
+                        let fraction = tokens.next().unwrap_or_default();
+                        metar.visibility = parse_visibility(fraction).map(|mut visibility| {
+                            visibility.statute_miles += f64::from(whole);
+                            visibility
+                        });
+                    } else if let Some(visibility) = parse_visibility(token) {
+                        metar.visibility = Some(visibility);
+                    } else if let Some(layer) = parse_cloud_layer(token) {
+                        metar.clouds.push(layer);
+                    } else if let Some((temperature, dewpoint)) = parse_temperatures(token) {
+                        metar.temperature = temperature;
+                        metar.dewpoint = dewpoint;
+                    } else if let Some(altimeter) = parse_altimeter(token) {
+                        metar.altimeter_hpa = Some(altimeter);
+                    } else if let Some(weather) = parse_weather(token) {
+                        metar.weather.extend(weather);
+                    }
+                }
+            }
+        }
+        Ok(metar)
+    }
+}
+
+impl Metar {
+    /// Returns the temperature, preferring the tenths of a degree from the `T` remark group.
+    pub fn precise_temperature(&self) -> Option<f64> {
+        self.remarks.temperature.or(self.temperature)
+    }
+
+    /// Returns the dewpoint, preferring the tenths of a degree from the `T` remark group.
+    pub fn precise_dewpoint(&self) -> Option<f64> {
+        self.remarks.dewpoint.or(self.dewpoint)
+    }
+
+    /// Returns the `6RRRR` precipitation period in hours: 3 for reports near
+    /// 03, 09, 15, and 21 UTC and 6 for reports near 00, 06, 12, and 18 UTC.
+    pub fn period_precipitation_hours(&self) -> Option<u8> {
+        let nearest_hour = (self.hour + u8::from(self.minute >= 30)) % 24;
+        match nearest_hour % 6 {
+            0 => Some(6),
+            3 => Some(3),
+            _ => None,
+        }
+    }
+
+    /// Fills the fields of `observation` that NWS returned as `null` with values from this report.
+    ///
+    /// Fields that already have a value are left unchanged. Values are stored
+    /// in the WMO units the API itself uses (e.g., `wmoUnit:km_h-1` for wind speed).
+    pub fn fill_observation(&self, observation: &mut Observation) {
+        fill(
+            &mut observation.temperature,
+            self.precise_temperature(),
+            WmoUnitCode::DegreeCelsius,
+        );
+        fill(
+            &mut observation.dewpoint,
+            self.precise_dewpoint(),
+            WmoUnitCode::DegreeCelsius,
+        );
+        if let Some(wind) = self.wind {
+            fill(
+                &mut observation.wind_direction,
+                wind.direction.map(f64::from),
+                WmoUnitCode::DegreeAngle,
+            );
+            fill(
+                &mut observation.wind_speed,
+                Some(wind.speed_knots * KILOMETERS_PER_HOUR_PER_KNOT),
+                WmoUnitCode::KilometresPerHour,
+            );
+            fill(
+                &mut observation.wind_gust,
+                wind.gust_knots
+                    .map(|gust| gust * KILOMETERS_PER_HOUR_PER_KNOT),
+                WmoUnitCode::KilometresPerHour,
+            );
+        }
+        fill(
+            &mut observation.barometric_pressure,
+            self.altimeter_hpa.map(|altimeter| altimeter * 100.0),
+            WmoUnitCode::Pascal,
+        );
+        fill(
+            &mut observation.sea_level_pressure,
+            self.remarks
+                .sea_level_pressure_hpa
+                .map(|pressure| pressure * 100.0),
+            WmoUnitCode::Pascal,
+        );
+        fill(
+            &mut observation.visibility,
+            self.visibility
+                .map(|visibility| visibility.statute_miles * METERS_PER_STATUTE_MILE),
+            WmoUnitCode::Metre,
+        );
+        fill(
+            &mut observation.max_temperature_last24_hours,
+            self.remarks.max_temperature_24_hour,
+            WmoUnitCode::DegreeCelsius,
+        );
+        fill(
+            &mut observation.min_temperature_last24_hours,
+            self.remarks.min_temperature_24_hour,
+            WmoUnitCode::DegreeCelsius,
+        );
+        fill(
+            &mut observation.precipitation_last_hour,
+            self.remarks
+                .hourly_precipitation_inches
+                .map(|inches| inches * MILLIMETERS_PER_INCH),
+            WmoUnitCode::Millimetre,
+        );
+        let period_precipitation = self
+            .remarks
+            .period_precipitation_inches
+            .map(|inches| inches * MILLIMETERS_PER_INCH);
+        match self.period_precipitation_hours() {
+            Some(3) => fill(
+                &mut observation.precipitation_last3_hours,
+                period_precipitation,
+                WmoUnitCode::Millimetre,
+            ),
+            Some(6) => fill(
+                &mut observation.precipitation_last6_hours,
+                period_precipitation,
+                WmoUnitCode::Millimetre,
+            ),
+            _ => {}
+        }
+        if observation
+            .present_weather
+            .as_ref()
+            .is_none_or(Vec::is_empty)
+            && !self.weather.is_empty()
+        {
+            observation.present_weather = Some(self.weather.clone());
+        }
+        if observation
+            .cloud_layers
+            .as_ref()
+            .is_none_or(|layers| layers.as_ref().is_none_or(Vec::is_empty))
+        {
+            let layers: Vec<ObservationCloudLayersInner> = self
+                .clouds
+                .iter()
+                .filter_map(|layer| {
+                    let amount = match layer.cover {
+                        CloudCover::SkyClear => MetarSkyCoverage::Skc,
+                        CloudCover::Clear => MetarSkyCoverage::Clr,
+                        CloudCover::Few => MetarSkyCoverage::Few,
+                        CloudCover::Scattered => MetarSkyCoverage::Sct,
+                        CloudCover::Broken => MetarSkyCoverage::Bkn,
+                        CloudCover::Overcast => MetarSkyCoverage::Ovc,
+                        CloudCover::VerticalVisibility => MetarSkyCoverage::Vv,
+                        CloudCover::NoSignificantCloud => return None,
+                    };
+                    let base = value_unit(
+                        layer.base_feet.map(|base| base * METERS_PER_FOOT),
+                        WmoUnitCode::Metre,
+                    );
+                    Some(ObservationCloudLayersInner::new(base, amount))
+                })
+                .collect();
+            if !layers.is_empty() {
+                observation.cloud_layers = Some(Some(layers));
+            }
+        }
+    }
+}
+
+impl MetarRemarks {
+    fn parse(remarks: &str) -> Self {
+        let mut parsed = MetarRemarks {
+            text: remarks.to_owned(),
+            ..Default::default()
+        };
+        let mut tokens = remarks.split_whitespace().peekable();
+        while let Some(token) = tokens.next() {
+            if token == "PK" && tokens.peek() == Some(&"WND") {
+                tokens.next();
+                parsed.peak_wind = tokens.next().and_then(parse_peak_wind);
+                continue;
+            }
+            if token == "AO1" || token == "AO2" {
+                parsed.station_type = Some(token.to_owned());
+                continue;
+            }
+            if let Some(pressure) = token.strip_prefix("SLP").and_then(parse_digits) {
+                let tenths = f64::from(pressure) / 10.0;
+                parsed.sea_level_pressure_hpa = Some(if pressure < 500 {
+                    1000.0 + tenths
+                } else {
+                    900.0 + tenths
+                });
+                continue;
+            }
+            if !(token.len() == 5 || token.len() == 9) || !token.is_ascii() {
+                continue;
+            }
+            let (group, value) = token.split_at(1);
+            match (group, value.len()) {
+                ("P", 4) => parsed.hourly_precipitation_inches = parse_hundredths(value),
+                ("6", 4) => parsed.period_precipitation_inches = parse_hundredths(value),
+                ("7", 4) => parsed.precipitation_24_hour_inches = parse_hundredths(value),
+                ("1", 4) => parsed.max_temperature_6_hour = parse_signed_tenths(value),
+                ("2", 4) => parsed.min_temperature_6_hour = parse_signed_tenths(value),
+                ("5", 4) => parsed.pressure_tendency = parse_pressure_tendency(value),
+                ("T", 8) => {
+                    let (temperature, dewpoint) = value.split_at(4);
+                    parsed.temperature = parse_signed_tenths(temperature);
+                    parsed.dewpoint = parse_signed_tenths(dewpoint);
+                }
+                ("4", 8) => {
+                    let (max, min) = value.split_at(4);
+                    parsed.max_temperature_24_hour = parse_signed_tenths(max);
+                    parsed.min_temperature_24_hour = parse_signed_tenths(min);
+                }
+                _ => {}
+            }
+        }
+        parsed
+    }
+}
+
+/// Parses a `DDHHMMZ` report time.
+fn parse_report_time(time: &str) -> Result<(u8, u8, u8), ParseMetarError> {
+    let digits = time
+        .strip_suffix('Z')
+        .filter(|digits| digits.len() == 6 && digits.is_ascii())
+        .ok_or_else(|| ParseMetarError::new(time))?;
+    let field = |range: std::ops::Range<usize>, max: u8| {
+        parse_digits(&digits[range])
+            .and_then(|value| u8::try_from(value).ok())
+            .filter(|value| *value <= max)
+            .ok_or_else(|| ParseMetarError::new(time))
+    };
+    let day = field(0..2, 31)?;
+    Ok((day, field(2..4, 23)?, field(4..6, 59)?))
+}
+
+/// Parses a wind group such as `27015G25KT`, `VRB03KT`, or `00000KT`.
+fn parse_wind(token: &str) -> Option<Wind> {
+    let (group, to_knots): (&str, fn(f64) -> f64) = if let Some(group) = token.strip_suffix("KT") {
+        (group, |speed| speed)
+    } else if let Some(group) = token.strip_suffix("MPS") {
+        (group, |speed| speed * KNOTS_PER_METER_PER_SECOND)
+    } else if let Some(group) = token.strip_suffix("KMH") {
+        (group, |speed| speed / KILOMETERS_PER_HOUR_PER_KNOT)
+    } else {
+        return None;
+    };
+    if group.len() < 5 || !group.is_ascii() {
+        return None;
+    }
+    let (direction, speeds) = group.split_at(3);
+    let direction = match direction {
+        "VRB" => None,
+        direction => Some(u16::try_from(parse_digits(direction)?).ok()?),
+    };
+    let (speed, gust) = match speeds.split_once('G') {
+        Some((speed, gust)) => (speed, Some(gust)),
+        None => (speeds, None),
+    };
+    Some(Wind {
+        direction,
+        speed_knots: to_knots(f64::from(parse_digits(speed)?)),
+        gust_knots: match gust {
+            Some(gust) => Some(to_knots(f64::from(parse_digits(gust)?))),
+            None => None,
+        },
+    })
+}
+
+/// Parses a variable wind direction group such as `240V300`.
+fn parse_variable_wind(token: &str) -> Option<(u16, u16)> {
+    let (from, to) = token.split_once('V')?;
+    if from.len() != 3 || to.len() != 3 {
+        return None;
+    }
+    Some((
+        u16::try_from(parse_digits(from)?).ok()?,
+        u16::try_from(parse_digits(to)?).ok()?,
+    ))
+}
+
+/// Parses a visibility group such as `10SM`, `P6SM`, `M1/4SM`, `3/4SM`, or `9999` meters.
+fn parse_visibility(token: &str) -> Option<Visibility> {
+    let Some(miles) = token.strip_suffix("SM") else {
+        return (token.len() == 4)
+            .then(|| Visibility::from_measurement(token, "m", None))
+            .flatten();
+    };
+    let (miles, greater_than, less_than) = if let Some(miles) = miles.strip_prefix('P') {
+        (miles, true, false)
+    } else if let Some(miles) = miles.strip_prefix('M') {
+        (miles, false, true)
+    } else {
+        (miles, false, false)
+    };
+    let statute_miles = match miles.split_once('/') {
+        Some((numerator, denominator)) => {
+            f64::from(parse_digits(numerator)?) / f64::from(parse_digits(denominator)?)
+        }
+        None => f64::from(parse_digits(miles)?),
+    };
+    Some(Visibility {
+        statute_miles,
+        greater_than,
+        less_than,
+    })
+}
+
+/// Parses a sky condition group such as `BKN015`, `OVC008CB`, `VV003`, or `CLR`.
+fn parse_cloud_layer(token: &str) -> Option<CloudLayer> {
+    let split = if token.starts_with("VV") { 2 } else { 3 };
+    if token.len() < split || !token.is_ascii() {
+        return None;
+    }
+    let (cover, rest) = token.split_at(split);
+    let cover = CloudCover::from_code(cover)?;
+    if rest.is_empty() {
+        return Some(CloudLayer {
+            cover,
+            base_feet: None,
+        });
+    }
+    let base = rest
+        .trim_end_matches("CB")
+        .trim_end_matches("TCU")
+        .trim_end_matches("///");
+    Some(CloudLayer {
+        cover,
+        base_feet: match base {
+            "" | "///" => None,
+            base => Some(f64::from(parse_digits(base)?) * 100.0),
+        },
+    })
+}
+
+/// Parses a temperature and dewpoint group such as `38/M02`.
+fn parse_temperatures(token: &str) -> Option<(Option<f64>, Option<f64>)> {
+    let (temperature, dewpoint) = token.split_once('/')?;
+    let parse = |value: &str| -> Option<Option<f64>> {
+        match value {
+            "" | "//" => Some(None),
+            value => {
+                let (sign, digits) = match value.strip_prefix('M') {
+                    Some(digits) => (-1.0, digits),
+                    None => (1.0, value),
+                };
+                (digits.len() == 2).then_some(())?;
+                Some(Some(sign * f64::from(parse_digits(digits)?)))
+            }
+        }
+    };
+    Some((parse(temperature)?, parse(dewpoint)?))
+}
+
+/// Parses an altimeter group (`A2992` inches of mercury or `Q1013` hectopascals) into hectopascals.
+fn parse_altimeter(token: &str) -> Option<f64> {
+    if token.len() != 5 {
+        return None;
+    }
+    if let Some(inches) = token.strip_prefix('A') {
+        return Some(f64::from(parse_digits(inches)?) / 100.0 * HECTOPASCALS_PER_INCH_OF_MERCURY);
+    }
+    token
+        .strip_prefix('Q')
+        .and_then(parse_digits)
+        .map(f64::from)
+}
+
+/// Parses a present weather group such as `-SHRA`, `+TSRA`, `VCSH`, or `FZFG`.
+///
+/// A thunderstorm with precipitation is returned as a thunderstorm followed
+/// by the precipitation types, each carrying the full group as its raw string.
+fn parse_weather(token: &str) -> Option<Vec<MetarPhenomenon>> {
+    let (intensity, in_vicinity, rest) = if let Some(rest) = token.strip_prefix('-') {
+        (Some(Intensity::Light), false, rest)
+    } else if let Some(rest) = token.strip_prefix('+') {
+        (Some(Intensity::Heavy), false, rest)
+    } else if let Some(rest) = token.strip_prefix("VC") {
+        (None, true, rest)
+    } else {
+        (None, false, token)
+    };
+    if rest.is_empty() || rest.len() % 2 != 0 || !rest.is_ascii() {
+        return None;
+    }
+    let codes: Vec<&str> = (0..rest.len())
+        .step_by(2)
+        .map(|start| &rest[start..start + 2])
+        .collect();
+    let mut codes = codes.as_slice();
+    let thunderstorm = codes.first() == Some(&"TS");
+    if thunderstorm {
+        codes = &codes[1..];
+    }
+    let modifier = codes.first().and_then(|code| weather_modifier(code));
+    if modifier.is_some() {
+        codes = &codes[1..];
+    }
+    let weather: Vec<Weather> = codes
+        .iter()
+        .map(|code| weather_phenomenon(code))
+        .collect::<Option<_>>()?;
+    if weather.is_empty() && !thunderstorm && modifier != Some(Modifier::Showers) {
+        return None;
+    }
+
+    let phenomenon = |weather: Weather, modifier: Option<Modifier>| MetarPhenomenon {
+        intensity,
+        modifier,
+        weather,
+        raw_string: token.to_owned(),
+        in_vicinity: in_vicinity.then_some(true),
+    };
+    let mut phenomena = Vec::new();
+    if thunderstorm {
+        phenomena.push(phenomenon(Weather::Thunderstorms, None));
+    }
+    if weather.is_empty() && modifier == Some(Modifier::Showers) {
+        // `VCSH` reports showers of unknown type in the vicinity.
+        phenomena.push(phenomenon(Weather::Unknown, modifier));
+    }
+    phenomena.extend(
+        weather
+            .into_iter()
+            .map(|weather| phenomenon(weather, modifier)),
+    );
+    Some(phenomena)
+}
+
+fn weather_modifier(code: &str) -> Option<Modifier> {
+    match code {
+        "MI" => Some(Modifier::Shallow),
+        "PR" => Some(Modifier::Partial),
+        "BC" => Some(Modifier::Patches),
+        "DR" => Some(Modifier::LowDrifting),
+        "BL" => Some(Modifier::Blowing),
+        "SH" => Some(Modifier::Showers),
+        "FZ" => Some(Modifier::Freezing),
+        _ => None,
+    }
+}
+
+fn weather_phenomenon(code: &str) -> Option<Weather> {
+    match code {
+        "DZ" => Some(Weather::Drizzle),
+        "RA" => Some(Weather::Rain),
+        "SN" => Some(Weather::Snow),
+        "SG" => Some(Weather::SnowGrains),
+        "IC" => Some(Weather::IceCrystals),
+        "PL" => Some(Weather::IcePellets),
+        "GR" => Some(Weather::Hail),
+        "GS" => Some(Weather::SnowPellets),
+        "UP" => Some(Weather::Unknown),
+        "BR" => Some(Weather::FogMist),
+        "FG" => Some(Weather::Fog),
+        "FU" => Some(Weather::Smoke),
+        "VA" => Some(Weather::VolcanicAsh),
+        "DU" => Some(Weather::Dust),
+        "SA" => Some(Weather::Sand),
+        "HZ" => Some(Weather::Haze),
+        "PY" => Some(Weather::Spray),
+        "PO" => Some(Weather::DustWhirls),
+        "SQ" => Some(Weather::Squalls),
+        "FC" => Some(Weather::FunnelCloud),
+        "SS" => Some(Weather::SandStorm),
+        "DS" => Some(Weather::DustStorm),
+        _ => None,
+    }
+}
+
+/// Parses a peak wind group such as `26032/1720` or `26032/20`.
+fn parse_peak_wind(group: &str) -> Option<PeakWind> {
+    let (wind, time) = group.split_once('/')?;
+    if wind.len() < 5 || !wind.is_ascii() {
+        return None;
+    }
+    let (direction, speed) = wind.split_at(3);
+    if !time.is_ascii() {
+        return None;
+    }
+    let (hour, minute) = match time.len() {
+        2 => (None, time),
+        4 => {
+            let (hour, minute) = time.split_at(2);
+            (Some(u8::try_from(parse_digits(hour)?).ok()?), minute)
+        }
+        _ => return None,
+    };
+    Some(PeakWind {
+        direction: u16::try_from(parse_digits(direction)?).ok()?,
+        speed_knots: f64::from(parse_digits(speed)?),
+        hour,
+        minute: u8::try_from(parse_digits(minute)?).ok()?,
+    })
+}
+
+/// Parses a pressure tendency `appp` value.
+fn parse_pressure_tendency(value: &str) -> Option<PressureTendency> {
+    let (characteristic, change) = value.split_at(1);
+    let characteristic = u8::try_from(parse_digits(characteristic)?).ok()?;
+    if characteristic > 8 {
+        return None;
+    }
+    let change = f64::from(parse_digits(change)?) / 10.0;
+    Some(PressureTendency {
+        characteristic,
+        // Characteristics 5 through 8 describe a pressure lower than 3 hours ago.
+        change_hpa: if characteristic >= 5 { -change } else { change },
+    })
+}
+
+/// Parses an `snTTT` temperature in tenths of a degree, where a sign digit of 1 means negative.
+fn parse_signed_tenths(value: &str) -> Option<f64> {
+    let (sign, tenths) = value.split_at_checked(1)?;
+    let tenths = f64::from(parse_digits(tenths)?) / 10.0;
+    match sign {
+        "0" => Some(tenths),
+        "1" => Some(-tenths),
+        _ => None,
+    }
+}
+
+/// Parses a precipitation amount in hundredths of an inch.
+fn parse_hundredths(value: &str) -> Option<f64> {
+    parse_digits(value).map(|hundredths| f64::from(hundredths) / 100.0)
+}
+
+/// Parses a string made up only of ASCII digits, rejecting missing values such as `////`.
+fn parse_digits(digits: &str) -> Option<u32> {
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    digits.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{UnitCodeType, ValueUnit};
+
+    const METAR: &str = "KPHX 011751Z 27015G25KT 240V300 1 1/2SM -TSRA BR FEW040 BKN080CB OVC150 38/M02 A2990 RMK AO2 PK WND 26032/1720 SLP102 P0012 60025 T03781022 10383 21011 401001150 58012";
+
+    #[test]
+    fn test_parse_body() {
+        let metar: Metar = METAR.parse().unwrap();
+        assert!(!metar.special);
+        assert_eq!((metar.day, metar.hour, metar.minute), (1, 17, 51));
+        assert_eq!(metar.wind.unwrap().to_string(), "27015G25KT");
+        assert_eq!(metar.wind_variable_between, Some((240, 300)));
+        assert_eq!(metar.visibility.unwrap().to_string(), "1 1/2SM");
+        assert_eq!(metar.weather.len(), 3);
+        assert_eq!(metar.weather[0].weather, Weather::Thunderstorms);
+        assert_eq!(metar.weather[1].weather, Weather::Rain);
+        assert_eq!(metar.weather[1].intensity, Some(Intensity::Light));
+        assert_eq!(metar.weather[2].weather, Weather::FogMist);
+        assert_eq!(metar.clouds.len(), 3);
+        assert_eq!(metar.clouds[1].to_string(), "BKN080");
+        assert_eq!(metar.temperature, Some(38.0));
+        assert_eq!(metar.dewpoint, Some(-2.0));
+        assert!((metar.altimeter_hpa.unwrap() - 1012.5).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_parse_remarks() {
+        let remarks = METAR.parse::<Metar>().unwrap().remarks;
+        assert_eq!(remarks.station_type.as_deref(), Some("AO2"));
+        assert_eq!(
+            remarks.peak_wind,
+            Some(PeakWind {
+                direction: 260,
+                speed_knots: 32.0,
+                hour: Some(17),
+                minute: 20,
+            })
+        );
+        assert_eq!(remarks.sea_level_pressure_hpa, Some(1010.2));
+        assert_eq!(remarks.hourly_precipitation_inches, Some(0.12));
+        assert_eq!(remarks.period_precipitation_inches, Some(0.25));
+        assert_eq!(remarks.temperature, Some(37.8));
+        assert_eq!(remarks.dewpoint, Some(-2.2));
+        assert_eq!(remarks.max_temperature_6_hour, Some(38.3));
+        assert_eq!(remarks.min_temperature_6_hour, Some(-1.1));
+        assert_eq!(remarks.max_temperature_24_hour, Some(10.0));
+        assert_eq!(remarks.min_temperature_24_hour, Some(-15.0));
+        assert_eq!(
+            remarks.pressure_tendency,
+            Some(PressureTendency {
+                characteristic: 8,
+                change_hpa: -1.2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_special_cases() {
+        let metar: Metar = "SPECI KDEN 020215Z AUTO VRB03KT M1/4SM +SN FZFG VV002 M05/M07 A2992"
+            .parse()
+            .unwrap();
+        assert!(metar.special);
+        assert!(metar.automated);
+        assert_eq!(metar.wind.unwrap().to_string(), "VRB03KT");
+        assert_eq!(metar.visibility.unwrap().to_string(), "M1/4SM");
+        assert_eq!(metar.weather[1].modifier, Some(Modifier::Freezing));
+        assert_eq!(metar.clouds[0].to_string(), "VV002");
+        assert_eq!(metar.temperature, Some(-5.0));
+        assert_eq!(metar.remarks, MetarRemarks::default());
+
+        let metar: Metar = "EGLL 011220Z 24008MPS 9999 VCSH SCT030 18/12 Q1015 NOSIG"
+            .parse()
+            .unwrap();
+        assert_eq!(metar.wind.unwrap().to_string(), "24016KT");
+        assert_eq!(metar.visibility.unwrap().to_string(), "P6SM");
+        assert_eq!(metar.weather[0].modifier, Some(Modifier::Showers));
+        assert_eq!(metar.weather[0].in_vicinity, Some(true));
+        assert_eq!(metar.altimeter_hpa, Some(1015.0));
+
+        assert!("KPHX".parse::<Metar>().is_err());
+        assert!("KPHX 011751 27015KT".parse::<Metar>().is_err());
+    }
+
+    #[test]
+    fn test_parse_non_ascii() {
+        assert!(
+            "KABQ aéébZ 00000KT 10SM CLR 20/M05 A3001"
+                .parse::<Metar>()
+                .is_err()
+        );
+
+        let metar: Metar = "KABQ 011751Z 00000KT 10SM CLR 20/M05 A3001 RMK PK WND 26032/aéb"
+            .parse()
+            .unwrap();
+        assert_eq!(metar.remarks.peak_wind, None);
+    }
+
+    #[test]
+    fn test_fill_observation() {
+        let mut observation = Observation {
+            raw_message: Some(METAR.to_owned()),
+            temperature: Some(ValueUnit {
+                unit_code: Some(UnitCodeType::Wmo(WmoUnitCode::DegreeCelsius)),
+                value: None,
+                ..Default::default()
+            }),
+            wind_speed: Some(ValueUnit {
+                unit_code: Some(UnitCodeType::Wmo(WmoUnitCode::KilometresPerHour)),
+                value: Some(30.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        observation.fill_from_raw_message().unwrap();
+
+        assert_eq!(observation.temperature.unwrap().value, Some(37.8));
+        assert_eq!(observation.wind_speed.unwrap().value, Some(30.0));
+        assert_eq!(observation.wind_direction.unwrap().value, Some(270.0));
+        assert_eq!(
+            observation.sea_level_pressure.unwrap().value,
+            Some(101_020.0)
+        );
+        let hourly = observation.precipitation_last_hour.unwrap().value.unwrap();
+        assert!((hourly - 3.048).abs() < 1e-9);
+        assert!(observation.precipitation_last6_hours.is_some());
+        assert!(observation.precipitation_last3_hours.is_none());
+        assert_eq!(observation.present_weather.unwrap().len(), 3);
+        let layers = observation.cloud_layers.unwrap().unwrap();
+        assert_eq!(layers[1].amount, MetarSkyCoverage::Bkn);
+    }
+}
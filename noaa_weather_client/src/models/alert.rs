@@ -1,4 +1,9 @@
+use crate::alert_parameters::{
+    self, AwipsIdentifier, BlockChannel, DamageThreat, EventMotion, TornadoDetection, WindGust,
+    WmoIdentifier,
+};
 use crate::models;
+use crate::vtec;
 use serde::{Deserialize, Serialize};
 
 /// Alert : An object representing a public alert message. Unless otherwise noted, the fields in this object correspond to the National Weather Service CAP v1.2 specification, which extends the OASIS Common Alerting Protocol (CAP) v1.2 specification and USA Integrated Public Alert and Warning System (IPAWS) Profile v1.0. Refer to this documentation for more complete information. <http://docs.oasis-open.org/emergency/cap/v1.2/CAP-v1.2-os.html> <http://docs.oasis-open.org/emergency/cap/v1.2/ipaws-profile/v1.0/cs01/cap-v1.2-ipaws-profile-cs01.html> <https://alerts.weather.gov/#technical-notes-v12>
@@ -142,6 +147,102 @@ impl Alert {
             event_code: None,
         }
     }
+
+    /// Returns the string values of the given parameter, or an empty iterator if it is absent.
+    pub fn parameter_values(&self, name: &str) -> impl Iterator<Item = &str> {
+        self.parameters
+            .as_ref()
+            .and_then(|parameters| parameters.get(name))
+            .into_iter()
+            .flatten()
+            .filter_map(|value| value.as_str())
+    }
+
+    /// Returns the first string value of the given parameter.
+    pub fn parameter(&self, name: &str) -> Option<&str> {
+        self.parameter_values(name).next()
+    }
+
+    /// Parses the VTEC strings in the alert's `VTEC` parameter.
+    ///
+    /// Returns an empty list when the alert has no VTEC parameter. Values that
+    /// are not valid P-VTEC strings are skipped.
+    pub fn vtec(&self) -> Vec<vtec::PrimaryVtec> {
+        self.parameter_values("VTEC")
+            .filter_map(|value| value.parse().ok())
+            .collect()
+    }
+
+    /// The NWS headline of the alert, from the `NWSheadline` parameter.
+    pub fn nws_headline(&self) -> Option<&str> {
+        self.parameter("NWSheadline")
+    }
+
+    /// The motion of the hazard, from the `eventMotionDescription` parameter.
+    pub fn event_motion(&self) -> Option<EventMotion> {
+        self.parameter("eventMotionDescription")?.parse().ok()
+    }
+
+    /// The maximum expected wind gust, from the `maxWindGust` parameter.
+    pub fn max_wind_gust(&self) -> Option<WindGust> {
+        self.parameter("maxWindGust")?.parse().ok()
+    }
+
+    /// The maximum expected hail size in inches, from the `maxHailSize` parameter.
+    pub fn max_hail_size(&self) -> Option<f64> {
+        alert_parameters::parse_hail_size(self.parameter("maxHailSize")?).ok()
+    }
+
+    /// How the tornado was detected, from the `tornadoDetection` parameter.
+    pub fn tornado_detection(&self) -> Option<TornadoDetection> {
+        self.parameter("tornadoDetection")
+            .map(TornadoDetection::from)
+    }
+
+    /// The thunderstorm damage threat, from the `thunderstormDamageThreat` parameter.
+    pub fn thunderstorm_damage_threat(&self) -> Option<DamageThreat> {
+        self.parameter("thunderstormDamageThreat")
+            .map(DamageThreat::from)
+    }
+
+    /// The tornado damage threat, from the `tornadoDamageThreat` parameter.
+    pub fn tornado_damage_threat(&self) -> Option<DamageThreat> {
+        self.parameter("tornadoDamageThreat")
+            .map(DamageThreat::from)
+    }
+
+    /// The flash flood damage threat, from the `flashFloodDamageThreat` parameter.
+    pub fn flash_flood_damage_threat(&self) -> Option<DamageThreat> {
+        self.parameter("flashFloodDamageThreat")
+            .map(DamageThreat::from)
+    }
+
+    /// The WMO heading of the product the alert was issued in, from the `WMOidentifier` parameter.
+    pub fn wmo_identifier(&self) -> Option<WmoIdentifier> {
+        self.parameter("WMOidentifier")?.parse().ok()
+    }
+
+    /// The AWIPS identifier of the product the alert was issued in, from the `AWIPSidentifier` parameter.
+    pub fn awips_identifier(&self) -> Option<AwipsIdentifier> {
+        self.parameter("AWIPSidentifier")?.parse().ok()
+    }
+
+    /// Prior alerts that this alert has expired, from the `expiredReferences` parameter.
+    ///
+    /// Returns an empty list when the parameter is absent. Malformed references are skipped.
+    pub fn expired_references(&self) -> Vec<models::AlertReferencesInner> {
+        self.parameter_values("expiredReferences")
+            .filter_map(|value| alert_parameters::parse_expired_references(value).ok())
+            .flatten()
+            .collect()
+    }
+
+    /// Dissemination channels the alert should not be sent to, from the `BLOCKCHANNEL` parameter.
+    pub fn block_channels(&self) -> Vec<BlockChannel> {
+        self.parameter_values("BLOCKCHANNEL")
+            .map(BlockChannel::from)
+            .collect()
+    }
 }
 
 /// The code denoting the category of the subject event of the alert message.
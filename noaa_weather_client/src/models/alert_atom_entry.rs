@@ -12,6 +12,8 @@ pub struct AlertAtomEntry {
     pub published: Option<String>,
     #[serde(rename = "author", skip_serializing_if = "Option::is_none")]
     pub author: Option<Box<models::AlertAtomEntryAuthor>>,
+    #[serde(rename = "title", skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
     #[serde(rename = "summary", skip_serializing_if = "Option::is_none")]
     pub summary: Option<String>,
     #[serde(rename = "event", skip_serializing_if = "Option::is_none")]
@@ -20,6 +22,8 @@ pub struct AlertAtomEntry {
     pub sent: Option<String>,
     #[serde(rename = "effective", skip_serializing_if = "Option::is_none")]
     pub effective: Option<String>,
+    #[serde(rename = "onset", skip_serializing_if = "Option::is_none")]
+    pub onset: Option<String>,
     #[serde(rename = "expires", skip_serializing_if = "Option::is_none")]
     pub expires: Option<String>,
     #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
@@ -52,10 +56,12 @@ impl AlertAtomEntry {
             updated: None,
             published: None,
             author: None,
+            title: None,
             summary: None,
             event: None,
             sent: None,
             effective: None,
+            onset: None,
             expires: None,
             status: None,
             msg_type: None,
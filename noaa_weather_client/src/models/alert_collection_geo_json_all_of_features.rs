@@ -3,12 +3,18 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct AlertCollectionGeoJsonAllOfFeatures {
+    /// The alert polygon, if the issuer drew one; otherwise the alert covers its `affectedZones`.
+    #[serde(rename = "geometry", default, skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<Box<models::GeoJsonGeometry>>,
     #[serde(rename = "properties", skip_serializing_if = "Option::is_none")]
     pub properties: Option<Box<models::Alert>>,
 }
 
 impl AlertCollectionGeoJsonAllOfFeatures {
     pub fn new() -> AlertCollectionGeoJsonAllOfFeatures {
-        AlertCollectionGeoJsonAllOfFeatures { properties: None }
+        AlertCollectionGeoJsonAllOfFeatures {
+            geometry: None,
+            properties: None,
+        }
     }
 }
@@ -1,3 +1,5 @@
+use crate::same::{self, SameArea};
+use crate::ugc::UgcCode;
 use serde::{Deserialize, Serialize};
 
 /// AlertGeocode : Lists of codes for NWS public zones and counties affected by the alert.
@@ -19,4 +21,23 @@ impl AlertGeocode {
             same: None,
         }
     }
+
+    /// Decodes the SAME codes into counties using the bundled county table, skipping
+    /// any that are malformed or unknown.
+    pub fn same_areas(&self) -> Vec<SameArea> {
+        self.same
+            .iter()
+            .flatten()
+            .filter_map(|code| same::lookup(code))
+            .collect()
+    }
+
+    /// Parses the UGC identifiers into typed codes, skipping any that are malformed.
+    pub fn ugc_codes(&self) -> Vec<UgcCode> {
+        self.ugc
+            .iter()
+            .flatten()
+            .filter_map(|code| code.parse().ok())
+            .collect()
+    }
 }
@@ -0,0 +1,287 @@
+use std::collections::HashMap;
+
+use crate::alert_parameters;
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// CapAlert : An alert message in OASIS Common Alerting Protocol (CAP) v1.2 XML format, as returned by the `/alerts/{id}` endpoint for `application/cap+xml` requests. <http://docs.oasis-open.org/emergency/cap/v1.2/CAP-v1.2-os.html>
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CapAlert {
+    /// The identifier of the alert message.
+    #[serde(rename = "identifier", skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    /// The identifier of the sender of the alert message.
+    #[serde(rename = "sender", skip_serializing_if = "Option::is_none")]
+    pub sender: Option<String>,
+    /// The time and date of the origination of the alert message.
+    #[serde(rename = "sent", skip_serializing_if = "Option::is_none")]
+    pub sent: Option<String>,
+    #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<models::AlertStatus>,
+    #[serde(rename = "msgType", skip_serializing_if = "Option::is_none")]
+    pub msg_type: Option<models::AlertMessageType>,
+    #[serde(rename = "scope", skip_serializing_if = "Option::is_none")]
+    pub scope: Option<models::AlertScope>,
+    /// Codes denoting special handling of the alert message (e.g., "IPAWSv1.0").
+    #[serde(rename = "code", skip_serializing_if = "Option::is_none")]
+    pub code: Option<Vec<String>>,
+    #[serde(rename = "note", skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Earlier messages this one replaces, as space-separated `sender,identifier,sent` triplets.
+    #[serde(rename = "references", skip_serializing_if = "Option::is_none")]
+    pub references: Option<String>,
+    #[serde(rename = "info", skip_serializing_if = "Option::is_none")]
+    pub info: Option<Vec<models::CapInfo>>,
+}
+
+impl CapAlert {
+    /// An alert message in OASIS Common Alerting Protocol (CAP) v1.2 XML format, as returned by the `/alerts/{id}` endpoint for `application/cap+xml` requests. <http://docs.oasis-open.org/emergency/cap/v1.2/CAP-v1.2-os.html>
+    pub fn new() -> CapAlert {
+        CapAlert {
+            identifier: None,
+            sender: None,
+            sent: None,
+            status: None,
+            msg_type: None,
+            scope: None,
+            code: None,
+            note: None,
+            references: None,
+            info: None,
+        }
+    }
+
+    /// Returns the areas of every info block in the message.
+    pub fn areas(&self) -> impl Iterator<Item = &models::CapArea> {
+        self.info
+            .iter()
+            .flatten()
+            .flat_map(|info| info.area.iter().flatten())
+    }
+
+    /// Returns the alert polygons as a GeoJSON geometry.
+    ///
+    /// A single polygon is returned as a `Polygon` and several as a
+    /// `MultiPolygon`. Returns `None` when the alert has no polygon, in which
+    /// case its extent is given only by its geocodes.
+    pub fn geometry(&self) -> Option<models::GeoJsonGeometry> {
+        let mut polygons: Vec<Vec<Vec<Vec<f64>>>> = self
+            .areas()
+            .flat_map(models::CapArea::polygon_rings)
+            .map(|ring| vec![ring])
+            .collect();
+        match polygons.len() {
+            0 => None,
+            1 => Some(models::GeoJsonGeometry::GeoJsonPolygon(Box::new(
+                models::GeoJsonPolygon {
+                    coordinates: polygons.remove(0),
+                    ..Default::default()
+                },
+            ))),
+            _ => Some(models::GeoJsonGeometry::GeoJsonMultiPolygon(Box::new(
+                models::GeoJsonMultiPolygon {
+                    coordinates: polygons,
+                    ..Default::default()
+                },
+            ))),
+        }
+    }
+}
+
+impl From<CapAlert> for models::Alert {
+    /// Converts a CAP message into the JSON alert model.
+    ///
+    /// Fields are taken from the first info block, which NWS messages always
+    /// use for their single English-language info. `ends` is taken from the
+    /// `eventEndingTime` parameter, matching the JSON API. `affectedZones` is
+    /// left unset because CAP messages do not carry zone URLs.
+    fn from(cap: CapAlert) -> Self {
+        let references = cap
+            .references
+            .as_deref()
+            .and_then(|references| alert_parameters::parse_expired_references(references).ok())
+            .unwrap_or_default();
+        let areas: Vec<&models::CapArea> = cap.areas().collect();
+        let area_desc: Vec<&str> = areas
+            .iter()
+            .filter_map(|area| area.area_desc.as_deref())
+            .collect();
+        let area_desc = (!area_desc.is_empty()).then(|| area_desc.join("; "));
+        let geocode_values = |name: &str| -> Vec<String> {
+            areas
+                .iter()
+                .flat_map(|area| area.geocode_values(name))
+                .map(str::to_owned)
+                .collect()
+        };
+        let geocode = models::AlertGeocode {
+            ugc: Some(geocode_values("UGC")),
+            same: Some(geocode_values("SAME")),
+        };
+        let info = cap
+            .info
+            .and_then(|info| info.into_iter().next())
+            .unwrap_or_default();
+        let parameters = info.parameter.as_deref().map(xml_parameter_map);
+        let ends = parameters
+            .as_ref()
+            .and_then(|parameters| parameters.get("eventEndingTime"))
+            .and_then(|values| values.first())
+            .and_then(|value| value.as_str())
+            .map(str::to_owned);
+
+        models::Alert {
+            id: cap.identifier,
+            area_desc,
+            geocode: Some(Box::new(geocode)),
+            affected_zones: None,
+            references: Some(references),
+            sent: cap.sent,
+            effective: info.effective,
+            onset: Some(info.onset),
+            expires: info.expires,
+            ends: Some(ends),
+            status: cap.status,
+            message_type: cap.msg_type,
+            category: info.category,
+            severity: info.severity,
+            certainty: info.certainty,
+            urgency: info.urgency,
+            event: info.event,
+            sender: cap.sender,
+            sender_name: info.sender_name,
+            headline: Some(info.headline),
+            description: info.description,
+            instruction: Some(info.instruction),
+            response: info.response_type,
+            parameters,
+            scope: cap.scope,
+            code: cap.code.and_then(|codes| codes.into_iter().next()),
+            language: info.language,
+            web: info.web,
+            event_code: info.event_code.as_deref().map(xml_parameter_map),
+        }
+    }
+}
+
+/// Groups CAP `valueName`/`value` pairs into the JSON API's map of value lists.
+fn xml_parameter_map(
+    parameters: &[models::AlertXmlParameter],
+) -> HashMap<String, Vec<serde_json::Value>> {
+    let mut map: HashMap<String, Vec<serde_json::Value>> = HashMap::new();
+    for parameter in parameters {
+        if let (Some(name), Some(value)) = (&parameter.value_name, &parameter.value) {
+            map.entry(name.clone())
+                .or_default()
+                .push(serde_json::Value::String(value.clone()));
+        }
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::alert::{Category, Response};
+    use serde::Deserialize;
+
+    const CAP: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<alert xmlns="urn:oasis:names:tc:emergency:cap:1.2">
+    <identifier>urn:oid:2.49.0.1.840.0.2</identifier>
+    <sender>w-nws.webmaster@noaa.gov</sender>
+    <sent>2025-06-01T16:30:00-07:00</sent>
+    <status>Actual</status>
+    <msgType>Update</msgType>
+    <scope>Public</scope>
+    <code>IPAWSv1.0</code>
+    <references>w-nws.webmaster@noaa.gov,urn:oid:2.49.0.1.840.0.1,2025-06-01T16:00:00-07:00</references>
+    <info>
+        <language>en-US</language>
+        <category>Met</category>
+        <event>Severe Thunderstorm Warning</event>
+        <responseType>Shelter</responseType>
+        <urgency>Immediate</urgency>
+        <severity>Severe</severity>
+        <certainty>Observed</certainty>
+        <eventCode>
+            <valueName>SAME</valueName>
+            <value>SVR</value>
+        </eventCode>
+        <effective>2025-06-01T16:30:00-07:00</effective>
+        <onset>2025-06-01T16:30:00-07:00</onset>
+        <expires>2025-06-01T17:00:00-07:00</expires>
+        <senderName>NWS Phoenix AZ</senderName>
+        <headline>Severe Thunderstorm Warning issued June 1 at 4:30PM MST</headline>
+        <description>At 430 PM MST, a severe thunderstorm was located near Phoenix.</description>
+        <instruction>For your protection move to an interior room.</instruction>
+        <web>http://www.weather.gov</web>
+        <parameter>
+            <valueName>maxWindGust</valueName>
+            <value>60 MPH</value>
+        </parameter>
+        <parameter>
+            <valueName>eventEndingTime</valueName>
+            <value>2025-06-01T17:00:00-07:00</value>
+        </parameter>
+        <parameter>
+            <valueName>VTEC</valueName>
+            <value>/O.CON.KPSR.SV.W.0042.000000T0000Z-250602T0000Z/</value>
+        </parameter>
+        <area>
+            <areaDesc>Maricopa, AZ</areaDesc>
+            <polygon>33.2,-112.5 33.2,-111.5 33.7,-111.5 33.7,-112.5 33.2,-112.5</polygon>
+            <geocode>
+                <valueName>SAME</valueName>
+                <value>004013</value>
+            </geocode>
+            <geocode>
+                <valueName>UGC</valueName>
+                <value>AZC013</value>
+            </geocode>
+        </area>
+    </info>
+</alert>"#;
+
+    fn parse() -> CapAlert {
+        let mut deserializer = quick_xml::de::Deserializer::from_str(CAP);
+        CapAlert::deserialize(&mut deserializer).unwrap()
+    }
+
+    #[test]
+    fn test_deserialize_cap() {
+        let cap = parse();
+        assert_eq!(cap.msg_type, Some(models::AlertMessageType::Update));
+        assert_eq!(cap.code, Some(vec!["IPAWSv1.0".to_owned()]));
+        let info = &cap.info.as_ref().unwrap()[0];
+        assert_eq!(info.category, Some(Category::Met));
+        assert_eq!(info.severity, Some(models::AlertSeverity::Severe));
+        assert_eq!(info.parameter.as_ref().unwrap().len(), 3);
+
+        let Some(models::GeoJsonGeometry::GeoJsonPolygon(polygon)) = cap.geometry() else {
+            panic!("expected a polygon");
+        };
+        assert_eq!(polygon.coordinates[0][0], [-112.5, 33.2]);
+    }
+
+    #[test]
+    fn test_cap_into_alert() {
+        let alert = models::Alert::from(parse());
+        assert_eq!(alert.id.as_deref(), Some("urn:oid:2.49.0.1.840.0.2"));
+        assert_eq!(alert.area_desc.as_deref(), Some("Maricopa, AZ"));
+        assert_eq!(alert.response, Some(Response::Shelter));
+        assert_eq!(
+            alert.ends,
+            Some(Some("2025-06-01T17:00:00-07:00".to_owned()))
+        );
+        assert_eq!(alert.parameter("maxWindGust"), Some("60 MPH"));
+        assert_eq!(alert.vtec().len(), 1);
+        let geocode = alert.geocode.unwrap();
+        assert_eq!(geocode.same, Some(vec!["004013".to_owned()]));
+        assert_eq!(geocode.ugc, Some(vec!["AZC013".to_owned()]));
+        let references = alert.references.unwrap();
+        assert_eq!(
+            references[0].identifier.as_deref(),
+            Some("urn:oid:2.49.0.1.840.0.1")
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// CapArea : The geographic area targeted by a CAP 1.2 alert info block.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CapArea {
+    /// A text description of the affected area.
+    #[serde(rename = "areaDesc", skip_serializing_if = "Option::is_none")]
+    pub area_desc: Option<String>,
+    /// Closed polygons as space-separated `latitude,longitude` pairs.
+    #[serde(rename = "polygon", skip_serializing_if = "Option::is_none")]
+    pub polygon: Option<Vec<String>>,
+    /// Geographic codes for the area, such as `SAME` and `UGC` codes.
+    #[serde(rename = "geocode", skip_serializing_if = "Option::is_none")]
+    pub geocode: Option<Vec<models::AlertXmlParameter>>,
+}
+
+impl CapArea {
+    /// The geographic area targeted by a CAP 1.2 alert info block.
+    pub fn new() -> CapArea {
+        CapArea {
+            area_desc: None,
+            polygon: None,
+            geocode: None,
+        }
+    }
+
+    /// Returns the values of the geocodes with the given name (e.g., "UGC").
+    pub fn geocode_values(&self, name: &str) -> impl Iterator<Item = &str> {
+        self.geocode
+            .iter()
+            .flatten()
+            .filter(move |geocode| geocode.value_name.as_deref() == Some(name))
+            .filter_map(|geocode| geocode.value.as_deref())
+    }
+
+    /// Parses the area polygons into GeoJSON rings of `[longitude, latitude]` positions.
+    ///
+    /// Empty polygons and polygons with malformed points are skipped.
+    pub fn polygon_rings(&self) -> Vec<Vec<Vec<f64>>> {
+        self.polygon
+            .iter()
+            .flatten()
+            .filter_map(|polygon| {
+                let ring: Vec<Vec<f64>> = polygon
+                    .split_whitespace()
+                    .map(|point| {
+                        let (latitude, longitude) = point.split_once(',')?;
+                        Some(vec![longitude.parse().ok()?, latitude.parse().ok()?])
+                    })
+                    .collect::<Option<_>>()?;
+                (!ring.is_empty()).then_some(ring)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,75 @@
+use crate::models;
+use crate::models::alert::{Category, Response};
+use serde::{Deserialize, Serialize};
+
+/// CapInfo : The event and audience details of a CAP 1.2 alert message.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CapInfo {
+    /// The language of the info block (e.g., "en-US").
+    #[serde(rename = "language", skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(rename = "category", skip_serializing_if = "Option::is_none")]
+    pub category: Option<Category>,
+    /// The text denoting the type of the subject event (e.g., "Heat Advisory").
+    #[serde(rename = "event", skip_serializing_if = "Option::is_none")]
+    pub event: Option<String>,
+    #[serde(rename = "responseType", skip_serializing_if = "Option::is_none")]
+    pub response_type: Option<Response>,
+    #[serde(rename = "urgency", skip_serializing_if = "Option::is_none")]
+    pub urgency: Option<models::AlertUrgency>,
+    #[serde(rename = "severity", skip_serializing_if = "Option::is_none")]
+    pub severity: Option<models::AlertSeverity>,
+    #[serde(rename = "certainty", skip_serializing_if = "Option::is_none")]
+    pub certainty: Option<models::AlertCertainty>,
+    /// System-specific codes identifying the event type (e.g., `SAME` and `NationalWeatherService`).
+    #[serde(rename = "eventCode", skip_serializing_if = "Option::is_none")]
+    pub event_code: Option<Vec<models::AlertXmlParameter>>,
+    #[serde(rename = "effective", skip_serializing_if = "Option::is_none")]
+    pub effective: Option<String>,
+    #[serde(rename = "onset", skip_serializing_if = "Option::is_none")]
+    pub onset: Option<String>,
+    #[serde(rename = "expires", skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+    /// The human-readable name of the issuing office (e.g., "NWS Phoenix AZ").
+    #[serde(rename = "senderName", skip_serializing_if = "Option::is_none")]
+    pub sender_name: Option<String>,
+    #[serde(rename = "headline", skip_serializing_if = "Option::is_none")]
+    pub headline: Option<String>,
+    #[serde(rename = "description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "instruction", skip_serializing_if = "Option::is_none")]
+    pub instruction: Option<String>,
+    #[serde(rename = "web", skip_serializing_if = "Option::is_none")]
+    pub web: Option<String>,
+    /// System-specific parameters (e.g., `VTEC`, `NWSheadline`, `eventEndingTime`).
+    #[serde(rename = "parameter", skip_serializing_if = "Option::is_none")]
+    pub parameter: Option<Vec<models::AlertXmlParameter>>,
+    #[serde(rename = "area", skip_serializing_if = "Option::is_none")]
+    pub area: Option<Vec<models::CapArea>>,
+}
+
+impl CapInfo {
+    /// The event and audience details of a CAP 1.2 alert message.
+    pub fn new() -> CapInfo {
+        CapInfo {
+            language: None,
+            category: None,
+            event: None,
+            response_type: None,
+            urgency: None,
+            severity: None,
+            certainty: None,
+            event_code: None,
+            effective: None,
+            onset: None,
+            expires: None,
+            sender_name: None,
+            headline: None,
+            description: None,
+            instruction: None,
+            web: None,
+            parameter: None,
+            area: None,
+        }
+    }
+}
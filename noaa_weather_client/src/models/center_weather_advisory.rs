@@ -1,4 +1,5 @@
 use crate::models;
+use crate::sigmet::{DecodedAdvisory, ParseAdvisoryError};
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
@@ -34,4 +35,19 @@ impl CenterWeatherAdvisory {
             text: None,
         }
     }
+
+    /// Decodes the advisory `text` into its hazards, altitudes, movement, and area.
+    ///
+    /// Returns `Ok(None)` when the advisory has no text.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseAdvisoryError`] if the text is not a CWA.
+    pub fn decode(&self) -> Result<Option<DecodedAdvisory>, ParseAdvisoryError> {
+        self.text
+            .as_deref()
+            .filter(|text| !text.trim().is_empty())
+            .map(str::parse)
+            .transpose()
+    }
 }
@@ -1,3 +1,4 @@
+use crate::geometry;
 use crate::models;
 use serde::{Deserialize, Serialize};
 
@@ -14,6 +15,25 @@ pub enum GeoJsonGeometry {
     GeoJsonMultiPolygon(Box<models::GeoJsonMultiPolygon>),
 }
 
+impl GeoJsonGeometry {
+    /// Returns true if the point lies inside this geometry.
+    ///
+    /// Only polygons and multi-polygons have an interior; other geometry types
+    /// never contain a point.
+    pub fn contains(&self, point: geometry::LatLon) -> bool {
+        match self {
+            Self::GeoJsonPolygon(polygon) => {
+                geometry::polygon_contains(&polygon.coordinates, point)
+            }
+            Self::GeoJsonMultiPolygon(multi_polygon) => multi_polygon
+                .coordinates
+                .iter()
+                .any(|polygon| geometry::polygon_contains(polygon, point)),
+            _ => false,
+        }
+    }
+}
+
 impl Default for GeoJsonGeometry {
     fn default() -> Self {
         Self::GeoJsonPoint(Default::default())
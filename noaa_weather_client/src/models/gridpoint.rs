@@ -1,4 +1,5 @@
 use crate::models;
+use crate::wkt;
 use serde::{Deserialize, Serialize};
 
 /// Gridpoint : Raw forecast data for a 2.5km grid square. This is a list of all potential data layers that may appear. Some layers may not be present in all areas. * temperature * dewpoint * maxTemperature * minTemperature * relativeHumidity * apparentTemperature * heatIndex * windChill * wetBulbGlobeTemperature * skyCover * windDirection * windSpeed * windGust * weather * hazards: Watch and advisory products in effect * probabilityOfPrecipitation * quantitativePrecipitation * iceAccumulation * snowfallAmount * snowLevel * ceilingHeight * visibility * transportWindSpeed * transportWindDirection * mixingHeight * hainesIndex * lightningActivityLevel * twentyFootWindSpeed * twentyFootWindDirection * waveHeight * wavePeriod * waveDirection * primarySwellHeight * primarySwellDirection * secondarySwellHeight * secondarySwellDirection * wavePeriod2 * windWaveHeight * dispersionIndex * pressure: Barometric pressure * probabilityOfTropicalStormWinds * probabilityOfHurricaneWinds * potentialOf15mphWinds * potentialOf25mphWinds * potentialOf35mphWinds * potentialOf45mphWinds * potentialOf20mphWindGusts * potentialOf30mphWindGusts * potentialOf40mphWindGusts * potentialOf50mphWindGusts * potentialOf60mphWindGusts * grasslandFireDangerIndex * probabilityOfThunder * davisStabilityIndex * atmosphericDispersionIndex * lowVisibilityOccurrenceRiskIndex * stability * redFlagThreatIndex
@@ -57,6 +58,17 @@ impl Gridpoint {
             hazards: None,
         }
     }
+
+    /// Parses the WKT `geometry` field into a GeoJSON geometry.
+    ///
+    /// Returns `Ok(None)` when the gridpoint has no geometry.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseWktError`](wkt::ParseWktError) if the geometry is not valid WKT.
+    pub fn geometry_parsed(&self) -> Result<Option<models::GeoJsonGeometry>, wkt::ParseWktError> {
+        wkt::parse_optional_wkt(self.geometry.as_ref())
+    }
 }
 #[derive(
     Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
@@ -1,6 +1,7 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::forecast_icon::{ForecastIcon, ParseForecastIconError};
 use crate::models;
 use serde::{Deserialize, Serialize};
 use serde_with::{NoneAsEmptyString, serde_as};
@@ -82,6 +83,17 @@ impl Gridpoint12hForecastPeriod {
             detailed_forecast: None,
         }
     }
+
+    /// Parses the icon URL into its time of day, conditions, and chances of precipitation.
+    ///
+    /// Returns `Ok(None)` when the period has no icon.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseForecastIconError`] if the icon is not a forecast icon URL.
+    pub fn forecast_icon(&self) -> Result<Option<ForecastIcon>, ParseForecastIconError> {
+        self.icon.as_deref().map(str::parse).transpose()
+    }
 }
 /// The unit of the temperature value (Fahrenheit or Celsius). This property is deprecated. Future versions will indicate the unit within the quantitative value object for the temperature property. To make use of the future standard format now, set the "forecast_temperature_qv" feature flag on the request.
 #[derive(
@@ -172,6 +184,30 @@ pub enum WindDirection {
     Nnw,
 }
 
+impl WindDirection {
+    /// Returns the bearing of the direction, in degrees clockwise from north.
+    pub fn degrees(self) -> f64 {
+        match self {
+            Self::N => 0.0,
+            Self::Nne => 22.5,
+            Self::Ne => 45.0,
+            Self::Ene => 67.5,
+            Self::E => 90.0,
+            Self::Ese => 112.5,
+            Self::Se => 135.0,
+            Self::Sse => 157.5,
+            Self::S => 180.0,
+            Self::Ssw => 202.5,
+            Self::Sw => 225.0,
+            Self::Wsw => 247.5,
+            Self::W => 270.0,
+            Self::Wnw => 292.5,
+            Self::Nw => 315.0,
+            Self::Nnw => 337.5,
+        }
+    }
+}
+
 impl Display for WindDirection {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", format!("{self:?}").to_uppercase())
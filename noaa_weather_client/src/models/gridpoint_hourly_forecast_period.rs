@@ -1,6 +1,7 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
+use crate::forecast_icon::{ForecastIcon, ParseForecastIconError};
 use crate::models;
 use serde::{Deserialize, Serialize};
 use serde_with::{NoneAsEmptyString, serde_as};
@@ -88,6 +89,17 @@ impl GridpointHourlyForecastPeriod {
             detailed_forecast: None,
         }
     }
+
+    /// Parses the icon URL into its time of day, conditions, and chances of precipitation.
+    ///
+    /// Returns `Ok(None)` when the period has no icon.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseForecastIconError`] if the icon is not a forecast icon URL.
+    pub fn forecast_icon(&self) -> Result<Option<ForecastIcon>, ParseForecastIconError> {
+        self.icon.as_deref().map(str::parse).transpose()
+    }
 }
 /// The unit of the temperature value (Fahrenheit or Celsius). This property is deprecated. Future versions will indicate the unit within the quantitative value object for the temperature property. To make use of the future standard format now, set the "forecast_temperature_qv" feature flag on the request.
 #[derive(
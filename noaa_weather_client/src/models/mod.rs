@@ -55,6 +55,12 @@ pub mod alert_types_response;
 pub use self::alert_types_response::AlertTypesResponse;
 pub mod area_code;
 pub use self::area_code::AreaCode;
+pub mod cap_alert;
+pub use self::cap_alert::CapAlert;
+pub mod cap_area;
+pub use self::cap_area::CapArea;
+pub mod cap_info;
+pub use self::cap_info::CapInfo;
 pub mod center_weather_advisory;
 pub use self::center_weather_advisory::CenterWeatherAdvisory;
 pub mod center_weather_advisory_collection_geo_json;
@@ -221,6 +227,10 @@ pub mod radar_station;
 pub use self::radar_station::{RadarStation, RadarStationFeature};
 pub mod radar_stations_response;
 pub use self::radar_stations_response::RadarStationsResponse;
+pub mod radar_wind_profiler;
+pub use self::radar_wind_profiler::RadarWindProfiler;
+pub mod radar_wind_profiler_level;
+pub use self::radar_wind_profiler_level::RadarWindProfilerLevel;
 pub mod region_code;
 pub use self::region_code::RegionCode;
 pub mod region_type;
@@ -1,3 +1,4 @@
+use crate::same::{self, SameArea};
 use serde::{Deserialize, Serialize};
 
 /// NOAA Weather Radio metadata for a point.
@@ -31,3 +32,10 @@ pub struct NoaaWeatherRadio {
     )]
     pub point_broadcast: Option<Option<String>>,
 }
+
+impl NoaaWeatherRadio {
+    /// Decodes the SAME code into the county it covers, using the bundled county table.
+    pub fn same_area(&self) -> Option<SameArea> {
+        same::lookup(self.same_code.as_deref()?)
+    }
+}
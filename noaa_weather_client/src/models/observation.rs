@@ -1,4 +1,9 @@
+use crate::derived;
+use crate::flight_category;
+use crate::metar;
 use crate::models;
+use crate::utils::units;
+use crate::wkt;
 use serde::{Deserialize, Serialize};
 
 use super::ValueUnit;
@@ -133,6 +138,89 @@ impl Observation {
             cloud_layers: None,
         }
     }
+
+    /// Parses the WKT `geometry` field into a GeoJSON geometry.
+    ///
+    /// Returns `Ok(None)` when the observation has no geometry.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseWktError`](wkt::ParseWktError) if the geometry is not valid WKT.
+    pub fn geometry_parsed(&self) -> Result<Option<models::GeoJsonGeometry>, wkt::ParseWktError> {
+        wkt::parse_optional_wkt(self.geometry.as_ref())
+    }
+
+    /// Returns the flight category for the observed cloud layers and visibility.
+    ///
+    /// Returns `None` when the observation reports neither cloud layers nor a visibility.
+    pub fn flight_category(&self) -> Option<flight_category::FlightCategory> {
+        let visibility = self
+            .visibility
+            .as_ref()
+            .and_then(units::length_in_meters)
+            .map(|meters| meters / units::METERS_PER_STATUTE_MILE);
+        let layers = self.cloud_layers.as_ref().and_then(Option::as_ref);
+        if visibility.is_none() && layers.is_none_or(Vec::is_empty) {
+            return None;
+        }
+        let ceiling = layers
+            .into_iter()
+            .flatten()
+            .filter(|layer| {
+                matches!(
+                    layer.amount,
+                    models::MetarSkyCoverage::Bkn
+                        | models::MetarSkyCoverage::Ovc
+                        | models::MetarSkyCoverage::Vv
+                )
+            })
+            .filter_map(|layer| units::length_in_meters(&layer.base))
+            .map(|meters| meters * units::FEET_PER_METER)
+            .min_by(f64::total_cmp);
+        Some(flight_category::FlightCategory::from_ceiling_and_visibility(ceiling, visibility))
+    }
+
+    /// Parses the `raw_message` METAR report.
+    ///
+    /// Returns `Ok(None)` when the observation has no raw message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseMetarError`](metar::ParseMetarError) if the raw message is not a valid METAR.
+    pub fn raw_message_parsed(&self) -> Result<Option<metar::Metar>, metar::ParseMetarError> {
+        self.raw_message
+            .as_deref()
+            .filter(|raw_message| !raw_message.trim().is_empty())
+            .map(str::parse)
+            .transpose()
+    }
+
+    /// Fills the fields NWS returned as `null` with values decoded from the `raw_message` METAR.
+    ///
+    /// See [`Metar::fill_observation`](metar::Metar::fill_observation) for the fields filled.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseMetarError`](metar::ParseMetarError) if the raw message is not a valid METAR.
+    pub fn fill_from_raw_message(&mut self) -> Result<(), metar::ParseMetarError> {
+        if let Some(metar) = self.raw_message_parsed()? {
+            metar.fill_observation(self);
+        }
+        Ok(())
+    }
+
+    /// Computes the quantities derived from the temperature, dewpoint, wind, and pressure.
+    pub fn derived(&self) -> derived::DerivedQuantities {
+        derived::DerivedQuantities::from_observation(self)
+    }
+
+    /// Fills the `relative_humidity`, `heat_index`, and `wind_chill` fields NWS
+    /// returned as `null` with values computed from the other fields.
+    ///
+    /// See [`DerivedQuantities::fill_observation`](derived::DerivedQuantities::fill_observation).
+    pub fn fill_derived(&mut self) {
+        self.derived().fill_observation(self);
+    }
 }
 #[derive(
     Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
@@ -1,4 +1,5 @@
 use crate::models;
+use crate::wkt;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
@@ -87,6 +88,17 @@ impl Point {
             nwr: None,
         }
     }
+
+    /// Parses the WKT `geometry` field into a GeoJSON geometry.
+    ///
+    /// Returns `Ok(None)` when the point has no geometry.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseWktError`](wkt::ParseWktError) if the geometry is not valid WKT.
+    pub fn geometry_parsed(&self) -> Result<Option<models::GeoJsonGeometry>, wkt::ParseWktError> {
+        wkt::parse_optional_wkt(self.geometry.as_ref())
+    }
 }
 #[derive(
     Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize, Default,
@@ -0,0 +1,105 @@
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RadarWindProfiler : A vertical wind profile measured by a radar wind profiler station. The NWS API does not publish a schema for this response, so every field is optional.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RadarWindProfiler {
+    #[serde(rename = "id", alias = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The profiler station identifier (e.g., "HWPA2").
+    #[serde(rename = "stationId", skip_serializing_if = "Option::is_none")]
+    pub station_id: Option<String>,
+    #[serde(rename = "name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "latitude", skip_serializing_if = "Option::is_none")]
+    pub latitude: Option<f64>,
+    #[serde(rename = "longitude", skip_serializing_if = "Option::is_none")]
+    pub longitude: Option<f64>,
+    #[serde(rename = "elevation", skip_serializing_if = "Option::is_none")]
+    pub elevation: Option<models::QuantitativeValue>,
+    /// The time the profile was measured.
+    #[serde(rename = "time", skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    /// The averaging interval of the profile (ISO 8601 duration).
+    #[serde(rename = "interval", skip_serializing_if = "Option::is_none")]
+    pub interval: Option<String>,
+    /// The measured levels, ordered from the lowest height.
+    #[serde(
+        rename = "levels",
+        alias = "profile",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub levels: Option<Vec<models::RadarWindProfilerLevel>>,
+    /// The fields of the response that are not modeled above.
+    #[serde(flatten)]
+    pub additional: serde_json::Map<String, serde_json::Value>,
+}
+
+impl RadarWindProfiler {
+    /// A vertical wind profile measured by a radar wind profiler station. The NWS API does not publish a schema for this response, so every field is optional.
+    pub fn new() -> RadarWindProfiler {
+        RadarWindProfiler {
+            id: None,
+            station_id: None,
+            name: None,
+            latitude: None,
+            longitude: None,
+            elevation: None,
+            time: None,
+            interval: None,
+            levels: None,
+            additional: serde_json::Map::new(),
+        }
+    }
+
+    /// Returns the levels sorted by height, lowest first. Levels without a height are dropped.
+    pub fn levels_by_height(&self) -> Vec<&models::RadarWindProfilerLevel> {
+        let mut levels: Vec<&models::RadarWindProfilerLevel> = self
+            .levels
+            .iter()
+            .flatten()
+            .filter(|level| level.height_value().is_some())
+            .collect();
+        levels.sort_by(|a, b| {
+            let height =
+                |level: &models::RadarWindProfilerLevel| level.height_value().unwrap_or(0.0);
+            height(a).total_cmp(&height(b))
+        });
+        levels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_deserialize_keeps_unmodeled_fields() {
+        let value = serde_json::json!({
+            "stationId": "HWPA2",
+            "time": "2025-10-18T12:00:00+00:00",
+            "quality": "good",
+            "levels": [
+                {
+                    "height": {"unitCode": "wmoUnit:m", "value": 1500.0},
+                    "windSpeed": {"unitCode": "wmoUnit:km_h-1", "value": 30.0},
+                    "windDirection": {"unitCode": "wmoUnit:degree_(angle)", "value": 250.0},
+                    "signalToNoise": 12.5
+                },
+                {
+                    "height": {"unitCode": "wmoUnit:m", "value": 500.0},
+                    "windSpeed": {"unitCode": "wmoUnit:km_h-1", "value": 15.0}
+                }
+            ]
+        });
+        let profiler: RadarWindProfiler = serde_json::from_value(value.clone()).unwrap();
+        assert_eq!(profiler.station_id.as_deref(), Some("HWPA2"));
+        assert_eq!(profiler.additional["quality"], "good");
+
+        let levels = profiler.levels_by_height();
+        assert_eq!(levels[0].height_value(), Some(500.0));
+        assert_eq!(levels[1].additional["signalToNoise"], 12.5);
+
+        assert_eq!(serde_json::to_value(&profiler).unwrap(), value);
+    }
+}
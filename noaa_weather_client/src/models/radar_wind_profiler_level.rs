@@ -0,0 +1,35 @@
+use crate::models;
+use serde::{Deserialize, Serialize};
+
+/// RadarWindProfilerLevel : The wind measured at one height of a radar wind profile.
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RadarWindProfilerLevel {
+    /// The height of the level above the station.
+    #[serde(rename = "height", skip_serializing_if = "Option::is_none")]
+    pub height: Option<models::QuantitativeValue>,
+    #[serde(rename = "windSpeed", skip_serializing_if = "Option::is_none")]
+    pub wind_speed: Option<models::QuantitativeValue>,
+    /// The direction the wind is blowing from, in degrees.
+    #[serde(rename = "windDirection", skip_serializing_if = "Option::is_none")]
+    pub wind_direction: Option<models::QuantitativeValue>,
+    /// The fields of the level that are not modeled above.
+    #[serde(flatten)]
+    pub additional: serde_json::Map<String, serde_json::Value>,
+}
+
+impl RadarWindProfilerLevel {
+    /// The wind measured at one height of a radar wind profile.
+    pub fn new() -> RadarWindProfilerLevel {
+        RadarWindProfilerLevel {
+            height: None,
+            wind_speed: None,
+            wind_direction: None,
+            additional: serde_json::Map::new(),
+        }
+    }
+
+    /// Returns the numeric height of the level, if reported.
+    pub fn height_value(&self) -> Option<f64> {
+        self.height.as_ref()?.value.flatten()
+    }
+}
@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::option::Option;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct TerminalAerodromeForecast {
     #[serde(rename = "@xmlns:ns0")]
     pub xmlns_ns0: String,
@@ -21,7 +21,7 @@ pub struct TerminalAerodromeForecast {
     pub ns0_bulletin_identifier: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Ns0MeteorologicalInformation {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -29,7 +29,7 @@ pub struct Ns0MeteorologicalInformation {
     pub taf: Taf,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Taf {
     #[serde(rename = "@xmlns:aixm")]
     pub xmlns_aixm: String,
@@ -54,11 +54,11 @@ pub struct Taf {
     pub valid_period: ValidPeriod,
     #[serde(rename = "baseForecast")]
     pub base_forecast: BaseForecast,
-    #[serde(rename = "changeForecast")]
+    #[serde(rename = "changeForecast", default)]
     pub change_forecast: Vec<ChangeForecast>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct IssueTime {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -66,7 +66,7 @@ pub struct IssueTime {
     pub ns1_time_instant: Ns1TimeInstant,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Ns1TimeInstant {
     #[serde(rename = "@id")]
     pub ns1_id: String,
@@ -76,7 +76,7 @@ pub struct Ns1TimeInstant {
     pub ns1_time_position: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Aerodrome {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -84,7 +84,7 @@ pub struct Aerodrome {
     pub aixm_airport_heliport: AixmAirportHeliport,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AixmAirportHeliport {
     #[serde(rename = "@id")]
     pub ns1_id: String,
@@ -94,7 +94,7 @@ pub struct AixmAirportHeliport {
     pub aixm_time_slice: AixmTimeSlice,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AixmTimeSlice {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -102,7 +102,7 @@ pub struct AixmTimeSlice {
     pub aixm_airport_heliport_time_slice: AixmAirportHeliportTimeSlice,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AixmAirportHeliportTimeSlice {
     #[serde(rename = "@id")]
     pub ns1_id: String,
@@ -120,10 +120,10 @@ pub struct AixmAirportHeliportTimeSlice {
     pub aixm_arp: AixmArp,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Ns1ValidTime {}
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AixmArp {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -131,7 +131,7 @@ pub struct AixmArp {
     pub aixm_elevated_point: AixmElevatedPoint,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AixmElevatedPoint {
     #[serde(rename = "@srsDimension")]
     pub srs_dimension: String,
@@ -147,7 +147,7 @@ pub struct AixmElevatedPoint {
     pub ns1_pos: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ValidPeriod {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -155,7 +155,7 @@ pub struct ValidPeriod {
     pub ns1_time_period: Ns0MeteorologicalInformationTafValidPeriodNs1TimePeriod,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Ns0MeteorologicalInformationTafValidPeriodNs1TimePeriod {
     #[serde(rename = "@id")]
     pub ns1_id: String,
@@ -167,7 +167,7 @@ pub struct Ns0MeteorologicalInformationTafValidPeriodNs1TimePeriod {
     pub ns1_end_position: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecast {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -175,7 +175,7 @@ pub struct BaseForecast {
     pub meteorological_aerodrome_forecast: BaseForecastMeteorologicalAerodromeForecast,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecast {
     #[serde(rename = "@cloudAndVisibilityOK")]
     pub cloud_and_visibility_ok: String,
@@ -183,6 +183,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecast {
     pub ns1_id: String,
     #[serde(rename = "$text")]
     pub text: Option<String>,
+    pub weather: Option<Vec<Weather>>,
     #[serde(rename = "phenomenonTime")]
     pub phenomenon_time: Option<BaseForecastMeteorologicalAerodromeForecastPhenomenonTime>,
     #[serde(rename = "prevailingVisibility")]
@@ -195,7 +196,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecast {
     pub cloud: Option<BaseForecastMeteorologicalAerodromeForecastCloud>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastPhenomenonTime {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -203,7 +204,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastPhenomenonTime {
     pub ns1_time_period: BaseForecastMeteorologicalAerodromeForecastPhenomenonTimeNs1TimePeriod,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastPhenomenonTimeNs1TimePeriod {
     #[serde(rename = "@id")]
     pub ns1_id: String,
@@ -215,7 +216,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastPhenomenonTimeNs1TimePerio
     pub ns1_end_position: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastPrevailingVisibility {
     #[serde(rename = "@uom")]
     pub uom: String,
@@ -223,7 +224,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastPrevailingVisibility {
     pub text: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastSurfaceWind {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -232,7 +233,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastSurfaceWind {
         BaseForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecast,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecast {
     #[serde(rename = "@variableWindDirection")]
     pub variable_wind_direction: String,
@@ -246,7 +247,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfac
     pub wind_gust_speed: Option<BaseForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecastWindGustSpeed>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecastMeanWindDirection
 {
     #[serde(rename = "@uom")]
@@ -255,7 +256,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfac
     pub text: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecastMeanWindSpeed
 {
     #[serde(rename = "@uom")]
@@ -264,7 +265,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfac
     pub text: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecastWindGustSpeed
 {
     #[serde(rename = "@uom")]
@@ -273,7 +274,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfac
     pub text: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastCloud {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -282,7 +283,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastCloud {
         BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecast,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecast {
     #[serde(rename = "@id")]
     pub ns1_id: String,
@@ -291,7 +292,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecas
     pub layer: Vec<BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayer>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayer {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -300,7 +301,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecas
         BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayerCloudLayer,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayerCloudLayer {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -310,14 +311,14 @@ pub struct BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecas
         BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayerCloudLayerBase,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayerCloudLayerAmount
 {
     #[serde(rename = "@href")]
     pub xlink_href: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayerCloudLayerBase
 {
     #[serde(rename = "@uom")]
@@ -326,7 +327,7 @@ pub struct BaseForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecas
     pub text: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecast {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -334,7 +335,7 @@ pub struct ChangeForecast {
     pub meteorological_aerodrome_forecast: ChangeForecastMeteorologicalAerodromeForecast,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecast {
     #[serde(rename = "@cloudAndVisibilityOK")]
     pub cloud_and_visibility_ok: String,
@@ -350,20 +351,20 @@ pub struct ChangeForecastMeteorologicalAerodromeForecast {
     #[serde(rename = "prevailingVisibility")]
     pub prevailing_visibility:
         Option<ChangeForecastMeteorologicalAerodromeForecastPrevailingVisibility>,
-    pub cloud: ChangeForecastMeteorologicalAerodromeForecastCloud,
+    pub cloud: Option<ChangeForecastMeteorologicalAerodromeForecastCloud>,
     #[serde(rename = "surfaceWind")]
     pub surface_wind: Option<ChangeForecastMeteorologicalAerodromeForecastSurfaceWind>,
     #[serde(rename = "prevailingVisibilityOperator")]
     pub prevailing_visibility_operator: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Weather {
     #[serde(rename = "@href")]
     pub xlink_href: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastPhenomenonTime {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -371,7 +372,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastPhenomenonTime {
     pub ns1_time_period: ChangeForecastMeteorologicalAerodromeForecastPhenomenonTimeNs1TimePeriod,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastPhenomenonTimeNs1TimePeriod {
     #[serde(rename = "@id")]
     pub ns1_id: String,
@@ -383,7 +384,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastPhenomenonTimeNs1TimePer
     pub ns1_end_position: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastPrevailingVisibility {
     #[serde(rename = "@uom")]
     pub uom: String,
@@ -391,7 +392,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastPrevailingVisibility {
     pub text: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastCloud {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -400,7 +401,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastCloud {
         ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecast,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecast {
     #[serde(rename = "@id")]
     pub ns1_id: String,
@@ -409,7 +410,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForec
     pub layer: Vec<ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayer>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayer {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -418,7 +419,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForec
         ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayerCloudLayer,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayerCloudLayer {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -426,14 +427,14 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForec
     pub base: ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayerCloudLayerBase,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayerCloudLayerAmount
 {
     #[serde(rename = "@href")]
     pub xlink_href: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForecastLayerCloudLayerBase
 {
     #[serde(rename = "@uom")]
@@ -442,7 +443,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastCloudAerodromeCloudForec
     pub text: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastSurfaceWind {
     #[serde(rename = "$text")]
     pub text: Option<String>,
@@ -451,7 +452,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastSurfaceWind {
         ChangeForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecast,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecast {
     #[serde(rename = "@variableWindDirection")]
     pub variable_wind_direction: String,
@@ -465,7 +466,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurf
     pub mean_wind_speed: Option<ChangeForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecastMeanWindSpeed>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecastWindGustSpeed
 {
     #[serde(rename = "@uom")]
@@ -474,7 +475,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurf
     pub text: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecastMeanWindDirection
 {
     #[serde(rename = "@uom")]
@@ -483,7 +484,7 @@ pub struct ChangeForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurf
     pub text: Option<String>,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ChangeForecastMeteorologicalAerodromeForecastSurfaceWindAerodromeSurfaceWindForecastMeanWindSpeed
 {
     #[serde(rename = "@uom")]
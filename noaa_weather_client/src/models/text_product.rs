@@ -1,4 +1,11 @@
+use crate::afd;
+use crate::climate;
+use crate::lsr;
 use crate::models;
+use crate::product_header;
+use crate::segment;
+use crate::ugc;
+use crate::vtec;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
@@ -37,4 +44,92 @@ impl TextProduct {
             product_text: None,
         }
     }
+
+    /// Parses the WMO heading, AWIPS ID, and mass news disseminator block at the
+    /// start of the product text, along with its UGC segments.
+    ///
+    /// Returns `Ok(None)` when the product has no text.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseProductHeaderError`](product_header::ParseProductHeaderError)
+    /// if the text does not start with a WMO heading.
+    pub fn header(
+        &self,
+    ) -> Result<Option<product_header::ProductHeader>, product_header::ParseProductHeaderError>
+    {
+        self.product_text
+            .as_deref()
+            .filter(|product_text| !product_text.trim().is_empty())
+            .map(str::parse)
+            .transpose()
+    }
+
+    /// Parses the product text as a daily climate report (`CLI`).
+    ///
+    /// Returns `Ok(None)` when the product has no text.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseClimateReportError`](climate::ParseClimateReportError)
+    /// if the text has no climate summary.
+    pub fn climate_report(
+        &self,
+    ) -> Result<Option<climate::ClimateReport>, climate::ParseClimateReportError> {
+        self.product_text
+            .as_deref()
+            .filter(|product_text| !product_text.trim().is_empty())
+            .map(str::parse)
+            .transpose()
+    }
+
+    /// Parses the storm reports in a Local Storm Report (`LSR`) product.
+    pub fn storm_reports(&self) -> Vec<lsr::StormReport> {
+        self.product_text
+            .as_deref()
+            .map(lsr::parse_storm_reports)
+            .unwrap_or_default()
+    }
+
+    /// Splits a segmented product, such as a `ZFP`, `WSW`, or `FFW`, into its
+    /// `$$`-delimited segments with their UGC zones, VTEC strings, and headlines.
+    pub fn segments(&self) -> Vec<segment::ProductSegment> {
+        self.product_text
+            .as_deref()
+            .map(segment::split_segments)
+            .unwrap_or_default()
+    }
+
+    /// Parses the UGC strings in the product text, one per product segment.
+    pub fn ugc(&self) -> Vec<ugc::Ugc> {
+        self.product_text
+            .as_deref()
+            .map(ugc::find_ugc)
+            .unwrap_or_default()
+    }
+
+    /// Parses the VTEC strings, and any accompanying H-VTEC strings, in the product text.
+    pub fn vtec(&self) -> Vec<vtec::Vtec> {
+        self.product_text
+            .as_deref()
+            .map(vtec::parse_vtec)
+            .unwrap_or_default()
+    }
+
+    /// Parses the product text as an Area Forecast Discussion (`AFD`).
+    ///
+    /// Returns `Ok(None)` when the product has no text.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseAfdError`](afd::ParseAfdError) if the text has no discussion sections.
+    pub fn area_forecast_discussion(
+        &self,
+    ) -> Result<Option<afd::AreaForecastDiscussion>, afd::ParseAfdError> {
+        self.product_text
+            .as_deref()
+            .filter(|product_text| !product_text.trim().is_empty())
+            .map(str::parse)
+            .transpose()
+    }
 }
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::zone_forecast::ZonePeriodSummary;
+
 #[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ZoneForecastPeriodsInner {
     /// A sequential identifier number.
@@ -21,4 +23,10 @@ impl ZoneForecastPeriodsInner {
             detailed_forecast,
         }
     }
+
+    /// Extracts the temperatures, wind, chance of precipitation, and sky
+    /// condition from the detailed forecast.
+    pub fn summary(&self) -> ZonePeriodSummary {
+        ZonePeriodSummary::from_text(&self.detailed_forecast)
+    }
 }
@@ -0,0 +1,139 @@
+//! Offline lookups for the VOR navaids used to describe aviation advisory areas.
+//!
+//! SIGMETs and Center Weather Advisories outline their areas with points
+//! given relative to VORs, such as `30NW ABQ` for 30 nautical miles
+//! northwest of the Albuquerque VOR. This module bundles the positions of
+//! the VORs most often used in those outlines, and resolves a bearing and
+//! distance from a navaid to a latitude and longitude.
+//!
+//! The bundled table covers the contiguous United States VORs most common in
+//! advisory outlines, with approximate positions suitable for plotting
+//! advisory areas. It is not a complete or authoritative navaid database;
+//! [`DecodedAdvisory::parse_with_navaids`](crate::sigmet::DecodedAdvisory::parse_with_navaids)
+//! accepts positions from another source, such as FAA NASR data.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::navaid;
+//!
+//! let abq = navaid::lookup("abq").unwrap();
+//! assert_eq!(abq.name, "Albuquerque");
+//!
+//! let point = navaid::destination(abq.position, 0.0, 60.0);
+//! assert!((point.latitude - (abq.position.latitude + 1.0)).abs() < 0.01);
+//! ```
+
+use std::sync::LazyLock;
+
+use crate::geometry::LatLon;
+
+/// The bundled navaid table, as `id,name,latitude,longitude` rows sorted by ID.
+const NAVAID_TABLE: &str = include_str!("../data/navaids.csv");
+
+/// The mean radius of the Earth, in nautical miles.
+const EARTH_RADIUS_NAUTICAL_MILES: f64 = 3440.065;
+
+static NAVAIDS: LazyLock<Vec<Navaid>> = LazyLock::new(|| {
+    NAVAID_TABLE
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.splitn(4, ',');
+            Some(Navaid {
+                id: fields.next()?,
+                name: fields.next()?,
+                position: LatLon {
+                    latitude: fields.next()?.parse().ok()?,
+                    longitude: fields.next()?.parse().ok()?,
+                },
+            })
+        })
+        .collect()
+});
+
+/// A VOR navaid.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Navaid {
+    /// The three-letter identifier (e.g., "ABQ").
+    pub id: &'static str,
+    /// The navaid name (e.g., "Albuquerque").
+    pub name: &'static str,
+    pub position: LatLon,
+}
+
+/// Returns every navaid in the bundled table, sorted by identifier.
+pub fn navaids() -> &'static [Navaid] {
+    &NAVAIDS
+}
+
+/// Returns the navaid with the given identifier, ignoring case.
+pub fn lookup(id: &str) -> Option<&'static Navaid> {
+    let id = id.trim().to_ascii_uppercase();
+    let navaids = navaids();
+    navaids
+        .binary_search_by(|navaid| navaid.id.cmp(&id))
+        .ok()
+        .map(|index| &navaids[index])
+}
+
+/// Returns the point `distance_nautical_miles` from `origin` along the
+/// great circle with the initial true bearing `bearing_degrees`.
+pub fn destination(origin: LatLon, bearing_degrees: f64, distance_nautical_miles: f64) -> LatLon {
+    let angular_distance = distance_nautical_miles / EARTH_RADIUS_NAUTICAL_MILES;
+    let bearing = bearing_degrees.to_radians();
+    let latitude = origin.latitude.to_radians();
+    let longitude = origin.longitude.to_radians();
+
+    let destination_latitude = (latitude.sin() * angular_distance.cos()
+        + latitude.cos() * angular_distance.sin() * bearing.cos())
+    .asin();
+    let destination_longitude = longitude
+        + (bearing.sin() * angular_distance.sin() * latitude.cos())
+            .atan2(angular_distance.cos() - latitude.sin() * destination_latitude.sin());
+
+    LatLon {
+        latitude: destination_latitude.to_degrees(),
+        longitude: (destination_longitude.to_degrees() + 540.0) % 360.0 - 180.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_sorted_and_complete() {
+        let navaids = navaids();
+        assert!(navaids.len() > 200);
+        assert!(navaids.windows(2).all(|pair| pair[0].id < pair[1].id));
+        assert_eq!(NAVAID_TABLE.lines().skip(1).count(), navaids.len());
+        assert!(navaids.iter().all(|navaid| {
+            (24.0..50.0).contains(&navaid.position.latitude)
+                && (-125.0..-66.0).contains(&navaid.position.longitude)
+        }));
+    }
+
+    #[test]
+    fn test_lookup() {
+        let navaid = lookup(" tcc ").unwrap();
+        assert_eq!(navaid.id, "TCC");
+        assert_eq!(navaid.name, "Tucumcari");
+        assert!(lookup("XYZ").is_none());
+    }
+
+    #[test]
+    fn test_destination() {
+        let origin = LatLon {
+            latitude: 35.0,
+            longitude: -106.0,
+        };
+        let east = destination(origin, 90.0, 60.0);
+        assert!((east.latitude - 34.99).abs() < 0.01);
+        assert!((east.longitude - (-104.78)).abs() < 0.01);
+
+        let unmoved = destination(origin, 225.0, 0.0);
+        assert!((unmoved.latitude - origin.latitude).abs() < 1e-9);
+        assert!((unmoved.longitude - origin.longitude).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,614 @@
+//! Line and section diffs between two issuances of a text product.
+//!
+//! Forecasters reissue Area Forecast Discussions, Hazardous Weather Outlooks,
+//! and zone forecasts several times a day. A [`ProductDiff`] compares two
+//! issuances line by line and, for products with sections, reports which
+//! sections were added, removed, or changed. Segmented products are compared
+//! by segment, keyed by the areas each segment covers; other products are
+//! compared by their `.HEADING...` sections.
+//!
+//! [`diff_latest_issuances`] fetches and compares the two most recent
+//! issuances of a product type for a location.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::product_diff::{LineChange, diff_lines};
+//!
+//! let changes = diff_lines("Sunny.\nHighs 98.\n", "Sunny.\nHighs 101.\n");
+//! assert_eq!(
+//!     changes,
+//!     [
+//!         LineChange::Unchanged("Sunny.".to_string()),
+//!         LineChange::Removed("Highs 98.".to_string()),
+//!         LineChange::Added("Highs 101.".to_string()),
+//!     ]
+//! );
+//! ```
+
+use std::collections::HashMap;
+use std::error;
+use std::fmt;
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+
+use crate::afd::AreaForecastDiscussion;
+use crate::apis::Error;
+use crate::apis::configuration::Configuration;
+use crate::apis::products::{self, ProductError, ProductsTypeLocationError};
+use crate::models::{NwsForecastOfficeId, TextProduct};
+use crate::segment;
+
+/// An error returned when the latest issuances of a product could not be fetched.
+#[derive(Debug)]
+pub enum ProductDiffError {
+    /// The list of products could not be fetched.
+    Products(Error<ProductsTypeLocationError>),
+    /// One of the products could not be fetched.
+    Product(Error<ProductError>),
+    /// Fewer than two issuances were found, holding the number found.
+    NotEnoughIssuances(usize),
+}
+
+impl fmt::Display for ProductDiffError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Products(error) => write!(f, "Error fetching products: {error}"),
+            Self::Product(error) => write!(f, "Error fetching product: {error}"),
+            Self::NotEnoughIssuances(count) => write!(
+                f,
+                "Expected at least two issuances to compare, found {count}"
+            ),
+        }
+    }
+}
+
+impl error::Error for ProductDiffError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Products(error) => Some(error),
+            Self::Product(error) => Some(error),
+            Self::NotEnoughIssuances(_) => None,
+        }
+    }
+}
+
+/// A line of a line diff.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "change", content = "line", rename_all = "snake_case")]
+pub enum LineChange {
+    /// A line present in both issuances.
+    Unchanged(String),
+    /// A line only present in the older issuance.
+    Removed(String),
+    /// A line only present in the newer issuance.
+    Added(String),
+}
+
+impl LineChange {
+    /// Returns the text of the line.
+    pub fn line(&self) -> &str {
+        match self {
+            Self::Unchanged(line) | Self::Removed(line) | Self::Added(line) => line,
+        }
+    }
+
+    /// Returns true for added and removed lines.
+    pub fn is_change(&self) -> bool {
+        !matches!(self, Self::Unchanged(_))
+    }
+}
+
+/// How a section differs between two issuances.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionChangeKind {
+    Unchanged,
+    Changed,
+    Added,
+    Removed,
+}
+
+impl fmt::Display for SectionChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unchanged => write!(f, "Unchanged"),
+            Self::Changed => write!(f, "Changed"),
+            Self::Added => write!(f, "Added"),
+            Self::Removed => write!(f, "Removed"),
+        }
+    }
+}
+
+/// A section of a product and how it differs between two issuances.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionChange {
+    /// The section name, e.g., `AVIATION`, or the areas of a segment.
+    pub name: String,
+    /// How the section differs.
+    pub change: SectionChangeKind,
+    /// The number of lines only present in the newer issuance.
+    pub added_lines: usize,
+    /// The number of lines only present in the older issuance.
+    pub removed_lines: usize,
+}
+
+/// The differences between two issuances of a text product.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductDiff {
+    /// The ID of the older issuance.
+    pub older_id: Option<String>,
+    /// The issuance time of the older issuance.
+    pub older_issuance_time: Option<String>,
+    /// The ID of the newer issuance.
+    pub newer_id: Option<String>,
+    /// The issuance time of the newer issuance.
+    pub newer_issuance_time: Option<String>,
+    /// The line diff of the full product text.
+    pub lines: Vec<LineChange>,
+    /// The sections in the newer issuance's order, followed by removed sections.
+    pub sections: Vec<SectionChange>,
+}
+
+impl ProductDiff {
+    /// Compares the text of two issuances of a product.
+    pub fn new(older: &TextProduct, newer: &TextProduct) -> Self {
+        let older_text = older.product_text.as_deref().unwrap_or_default();
+        let newer_text = newer.product_text.as_deref().unwrap_or_default();
+        Self {
+            older_id: older.id.clone(),
+            older_issuance_time: older.issuance_time.clone(),
+            newer_id: newer.id.clone(),
+            newer_issuance_time: newer.issuance_time.clone(),
+            lines: diff_lines(older_text, newer_text),
+            sections: diff_sections(older_text, newer_text),
+        }
+    }
+
+    /// Returns true if any line was added or removed.
+    pub fn has_changes(&self) -> bool {
+        self.lines.iter().any(LineChange::is_change)
+    }
+
+    /// Formats the line diff as unified diff hunks, with `context` unchanged
+    /// lines around each change.
+    pub fn unified(&self, context: usize) -> String {
+        unified(&self.lines, context)
+    }
+}
+
+/// Fetches the two most recent issuances of a product type for a location
+/// and compares them.
+///
+/// # Errors
+///
+/// Returns a [`ProductDiffError`] if a request fails or fewer than two
+/// issuances are available.
+pub async fn diff_latest_issuances(
+    configuration: &Configuration,
+    type_id: &str,
+    location_id: &NwsForecastOfficeId,
+) -> Result<ProductDiff, ProductDiffError> {
+    let collection =
+        products::get_products_by_type_and_location(configuration, type_id, location_id)
+            .await
+            .map_err(ProductDiffError::Products)?;
+    let mut issuances: Vec<(Option<Timestamp>, &str)> = collection
+        .at_graph
+        .iter()
+        .flatten()
+        .filter_map(|product| {
+            let time = product
+                .issuance_time
+                .as_deref()
+                .and_then(|time| time.parse().ok());
+            product.id.as_deref().map(|id| (time, id))
+        })
+        .collect();
+    issuances.sort_by_key(|(time, _)| std::cmp::Reverse(*time));
+    let [(_, newer_id), (_, older_id), ..] = issuances[..] else {
+        return Err(ProductDiffError::NotEnoughIssuances(issuances.len()));
+    };
+    let newer = products::get_product(configuration, newer_id)
+        .await
+        .map_err(ProductDiffError::Product)?;
+    let older = products::get_product(configuration, older_id)
+        .await
+        .map_err(ProductDiffError::Product)?;
+    Ok(ProductDiff::new(&older, &newer))
+}
+
+/// Computes a line diff between two texts, ignoring trailing whitespace and
+/// line ending differences.
+pub fn diff_lines(older: &str, newer: &str) -> Vec<LineChange> {
+    let older: Vec<&str> = older.lines().map(str::trim_end).collect();
+    let newer: Vec<&str> = newer.lines().map(str::trim_end).collect();
+
+    let prefix = older
+        .iter()
+        .zip(&newer)
+        .take_while(|(first, second)| first == second)
+        .count();
+    let suffix = older[prefix..]
+        .iter()
+        .rev()
+        .zip(newer[prefix..].iter().rev())
+        .take_while(|(first, second)| first == second)
+        .count();
+    let older_middle = &older[prefix..older.len() - suffix];
+    let newer_middle = &newer[prefix..newer.len() - suffix];
+
+    // lengths[i][j] is the length of the longest common subsequence of
+    // older_middle[i..] and newer_middle[j..].
+    let width = newer_middle.len() + 1;
+    let mut lengths = vec![0_u32; (older_middle.len() + 1) * width];
+    for i in (0..older_middle.len()).rev() {
+        for j in (0..newer_middle.len()).rev() {
+            lengths[i * width + j] = if older_middle[i] == newer_middle[j] {
+                lengths[(i + 1) * width + j + 1] + 1
+            } else {
+                lengths[(i + 1) * width + j].max(lengths[i * width + j + 1])
+            };
+        }
+    }
+
+    let mut changes: Vec<LineChange> = older[..prefix]
+        .iter()
+        .map(|line| LineChange::Unchanged((*line).to_string()))
+        .collect();
+    let (mut i, mut j) = (0, 0);
+    while i < older_middle.len() || j < newer_middle.len() {
+        if i < older_middle.len() && j < newer_middle.len() && older_middle[i] == newer_middle[j] {
+            changes.push(LineChange::Unchanged(older_middle[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if j == newer_middle.len()
+            || (i < older_middle.len()
+                && lengths[(i + 1) * width + j] >= lengths[i * width + j + 1])
+        {
+            changes.push(LineChange::Removed(older_middle[i].to_string()));
+            i += 1;
+        } else {
+            changes.push(LineChange::Added(newer_middle[j].to_string()));
+            j += 1;
+        }
+    }
+    changes.extend(
+        older[older.len() - suffix..]
+            .iter()
+            .map(|line| LineChange::Unchanged((*line).to_string())),
+    );
+    changes
+}
+
+/// Compares the sections of two issuances of a product.
+///
+/// Segmented products are compared by segment and other products by their
+/// `.HEADING...` sections. Products with neither have no sections.
+pub fn diff_sections(older: &str, newer: &str) -> Vec<SectionChange> {
+    let older_sections = sections(older);
+    let newer_sections = sections(newer);
+    let older_by_name: HashMap<&str, &str> = older_sections
+        .iter()
+        .map(|(name, text)| (name.as_str(), text.as_str()))
+        .collect();
+
+    let mut changes: Vec<SectionChange> = newer_sections
+        .iter()
+        .map(|(name, text)| {
+            let (change, lines) = match older_by_name.get(name.as_str()) {
+                Some(older_text) => {
+                    let lines = diff_lines(older_text, text);
+                    let change = if lines.iter().any(LineChange::is_change) {
+                        SectionChangeKind::Changed
+                    } else {
+                        SectionChangeKind::Unchanged
+                    };
+                    (change, lines)
+                }
+                None => (SectionChangeKind::Added, diff_lines("", text)),
+            };
+            section_change(name, change, &lines)
+        })
+        .collect();
+    changes.extend(
+        older_sections
+            .iter()
+            .filter(|(name, _)| !newer_sections.iter().any(|(newer, _)| newer == name))
+            .map(|(name, text)| {
+                section_change(name, SectionChangeKind::Removed, &diff_lines(text, ""))
+            }),
+    );
+    changes
+}
+
+fn section_change(name: &str, change: SectionChangeKind, lines: &[LineChange]) -> SectionChange {
+    SectionChange {
+        name: name.to_string(),
+        change,
+        added_lines: lines
+            .iter()
+            .filter(|line| matches!(line, LineChange::Added(_)))
+            .count(),
+        removed_lines: lines
+            .iter()
+            .filter(|line| matches!(line, LineChange::Removed(_)))
+            .count(),
+    }
+}
+
+/// Splits a product into named sections, numbering repeated names so each is unique.
+fn sections(text: &str) -> Vec<(String, String)> {
+    let segments = segment::split_segments(text);
+    let sections: Vec<(String, String)> = if segments.is_empty() {
+        text.parse::<AreaForecastDiscussion>()
+            .map(|discussion| {
+                discussion
+                    .sections
+                    .into_iter()
+                    .map(|section| (section.kind.to_string(), section.text))
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        segments
+            .into_iter()
+            .map(|segment| {
+                let name = if segment.areas.is_empty() {
+                    segment
+                        .ugc
+                        .codes
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                } else {
+                    segment.areas.join(", ")
+                };
+                let mut text = segment.headlines.join("\n");
+                if !text.is_empty() {
+                    text.push('\n');
+                }
+                text.push_str(&segment.text);
+                (name, text)
+            })
+            .collect()
+    };
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    sections
+        .into_iter()
+        .map(|(name, text)| {
+            let count = counts.entry(name.clone()).or_default();
+            *count += 1;
+            if *count == 1 {
+                (name, text)
+            } else {
+                (format!("{name} ({count})"), text)
+            }
+        })
+        .collect()
+}
+
+/// Formats line changes as unified diff hunks.
+fn unified(lines: &[LineChange], context: usize) -> String {
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| line.is_change())
+        .map(|(index, _)| index)
+        .collect();
+
+    // Merge the changed lines and their context into hunk ranges.
+    let mut hunks: Vec<(usize, usize)> = Vec::new();
+    for index in changed {
+        let start = index.saturating_sub(context);
+        let end = (index + context + 1).min(lines.len());
+        match hunks.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => hunks.push((start, end)),
+        }
+    }
+
+    let mut output = String::new();
+    for (start, end) in hunks {
+        let older_before = lines[..start]
+            .iter()
+            .filter(|line| !matches!(line, LineChange::Added(_)))
+            .count();
+        let newer_before = lines[..start]
+            .iter()
+            .filter(|line| !matches!(line, LineChange::Removed(_)))
+            .count();
+        let hunk = &lines[start..end];
+        let older_count = hunk
+            .iter()
+            .filter(|line| !matches!(line, LineChange::Added(_)))
+            .count();
+        let newer_count = hunk
+            .iter()
+            .filter(|line| !matches!(line, LineChange::Removed(_)))
+            .count();
+        output.push_str(&format!(
+            "@@ -{},{older_count} +{},{newer_count} @@\n",
+            older_before + usize::from(older_count > 0),
+            newer_before + usize::from(newer_count > 0),
+        ));
+        for line in hunk {
+            let marker = match line {
+                LineChange::Unchanged(_) => ' ',
+                LineChange::Removed(_) => '-',
+                LineChange::Added(_) => '+',
+            };
+            output.push(marker);
+            output.push_str(line.line());
+            output.push('\n');
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const OLDER_AFD: &str = "
+Area Forecast Discussion
+National Weather Service Phoenix AZ
+312 AM MST Sat Oct 18 2025
+
+.SHORT TERM /TODAY THROUGH SUNDAY/...
+Dry and warm. Highs near 100.
+
+.AVIATION...
+VFR.
+
+.FIRE WEATHER...
+Low humidities.
+
+$$
+";
+
+    const NEWER_AFD: &str = "
+Area Forecast Discussion
+National Weather Service Phoenix AZ
+915 AM MST Sat Oct 18 2025
+
+.UPDATE...
+Morning soundings show more moisture.
+
+.SHORT TERM /TODAY THROUGH SUNDAY/...
+Dry and warm. Highs near 102.
+
+.AVIATION...
+VFR.
+
+$$
+";
+
+    #[test]
+    fn test_diff_lines() {
+        let changes = diff_lines("a\nb\nc\nd\n", "a\nc\nx\nd\r\n");
+        assert_eq!(
+            changes,
+            [
+                LineChange::Unchanged("a".into()),
+                LineChange::Removed("b".into()),
+                LineChange::Unchanged("c".into()),
+                LineChange::Added("x".into()),
+                LineChange::Unchanged("d".into()),
+            ]
+        );
+        assert!(
+            diff_lines("same\n", "same")
+                .iter()
+                .all(|line| !line.is_change())
+        );
+    }
+
+    #[test]
+    fn test_diff_discussion_sections() {
+        let sections = diff_sections(OLDER_AFD, NEWER_AFD);
+        let summary: Vec<(&str, SectionChangeKind, usize, usize)> = sections
+            .iter()
+            .map(|section| {
+                (
+                    section.name.as_str(),
+                    section.change,
+                    section.added_lines,
+                    section.removed_lines,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("UPDATE", SectionChangeKind::Added, 1, 0),
+                ("SHORT TERM", SectionChangeKind::Changed, 1, 1),
+                ("AVIATION", SectionChangeKind::Unchanged, 0, 0),
+                ("FIRE WEATHER", SectionChangeKind::Removed, 0, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_diff_segment_sections() {
+        let older = "AZZ537-190915-\nGreater Phoenix Area-\n312 AM MST Sat Oct 18 2025\n\n.TODAY...Sunny. Highs 98.\n\n$$\n";
+        let newer = "AZZ537-191500-\nGreater Phoenix Area-\n915 AM MST Sat Oct 18 2025\n\n.TODAY...Sunny. Highs 101.\n\n$$\n";
+        let sections = diff_sections(older, newer);
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].name, "Greater Phoenix Area");
+        assert_eq!(sections[0].change, SectionChangeKind::Changed);
+    }
+
+    #[test]
+    fn test_unified() {
+        let lines = diff_lines(
+            "1\n2\n3\n4\n5\n6\n7\n8\n",
+            "1\n2\n3\nfour\n5\n6\n7\n8\nnine\n",
+        );
+        assert_eq!(
+            unified(&lines, 1),
+            "@@ -3,3 +3,3 @@\n 3\n-4\n+four\n 5\n@@ -8,1 +8,2 @@\n 8\n+nine\n"
+        );
+        assert_eq!(unified(&diff_lines("a", "a"), 3), "");
+    }
+
+    #[test]
+    fn test_product_diff() {
+        let older = TextProduct {
+            id: Some("older".into()),
+            product_text: Some(OLDER_AFD.into()),
+            ..TextProduct::new()
+        };
+        let newer = TextProduct {
+            id: Some("newer".into()),
+            product_text: Some(NEWER_AFD.into()),
+            ..TextProduct::new()
+        };
+        let diff = ProductDiff::new(&older, &newer);
+        assert!(diff.has_changes());
+        assert_eq!(diff.older_id.as_deref(), Some("older"));
+        assert!(diff.unified(0).contains("-Dry and warm. Highs near 100.\n"));
+        assert!(!ProductDiff::new(&older, &older).has_changes());
+    }
+
+    #[tokio::test]
+    async fn test_diff_latest_issuances() {
+        let server = MockServer::start().await;
+        let collection = serde_json::json!({
+            "@graph": [
+                {"id": "older", "issuanceTime": "2025-10-18T10:12:00+00:00"},
+                {"id": "newer", "issuanceTime": "2025-10-18T16:15:00+00:00"},
+                {"id": "oldest", "issuanceTime": "2025-10-17T21:40:00+00:00"}
+            ]
+        });
+        Mock::given(method("GET"))
+            .and(path("/products/types/AFD/locations/PSR"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(collection))
+            .mount(&server)
+            .await;
+        for (id, text) in [("older", OLDER_AFD), ("newer", NEWER_AFD)] {
+            Mock::given(method("GET"))
+                .and(path(format!("/products/{id}")))
+                .respond_with(
+                    ResponseTemplate::new(200)
+                        .set_body_json(serde_json::json!({"id": id, "productText": text})),
+                )
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let configuration = Configuration::new(None, Some(server.uri()), None, None);
+        let diff = diff_latest_issuances(&configuration, "AFD", &NwsForecastOfficeId::Psr)
+            .await
+            .unwrap();
+        assert_eq!(diff.older_id.as_deref(), Some("older"));
+        assert_eq!(diff.newer_id.as_deref(), Some("newer"));
+        assert_eq!(diff.sections.len(), 4);
+    }
+}
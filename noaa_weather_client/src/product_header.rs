@@ -0,0 +1,450 @@
+//! Parser for the header of NWS text products.
+//!
+//! Every text product starts with a WMO abbreviated heading, an AWIPS
+//! product identifier (PIL), and a mass news disseminator (MND) block with
+//! the product title, the issuing office, and the issuance time in local time:
+//!
+//! ```text
+//! FXUS65 KPSR 181012 CCA
+//! AFDPSR
+//!
+//! Area Forecast Discussion...CORRECTED
+//! National Weather Service Phoenix AZ
+//! 312 AM MST Sat Oct 18 2025
+//! ```
+//!
+//! Segmented products follow with one or more segments, each starting with a
+//! UGC string and its VTEC strings and ending with `$$`.
+//!
+//! See NWS Directive 10-1701 for the product format.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::product_header::{ProductHeader, WmoIndicator};
+//!
+//! let text = "000\nFXUS65 KPSR 181012 CCA\nAFDPSR\n\nArea Forecast Discussion\nNational Weather Service Phoenix AZ\n312 AM MST Sat Oct 18 2025\n";
+//! let header: ProductHeader = text.parse().unwrap();
+//! assert_eq!(header.wmo_heading.data_type, "FXUS65");
+//! assert_eq!(header.wmo_heading.indicator, Some(WmoIndicator::Correction('A')));
+//! assert_eq!(header.awips_id.as_deref(), Some("AFDPSR"));
+//! assert_eq!(header.issuance_time.unwrap().to_string(), "2025-10-18T10:12:00Z");
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use jiff::Timestamp;
+use jiff::civil::{Date, DateTime};
+use jiff::tz::Offset;
+use serde::{Deserialize, Serialize};
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::segment::{self, ProductSegment};
+
+/// The number of lines searched for the WMO heading, which may follow a `000` line.
+const HEADING_SEARCH_LINES: usize = 3;
+
+/// An error returned when a text product header cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseProductHeaderError {
+    invalid_value: String,
+}
+
+impl ParseProductHeaderError {
+    fn new(value: &str) -> Self {
+        Self {
+            invalid_value: value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for ParseProductHeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid product header value: {}", self.invalid_value)
+    }
+}
+
+impl std::error::Error for ParseProductHeaderError {}
+
+/// The parsed header of a text product.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ProductHeader {
+    /// The WMO abbreviated heading.
+    pub wmo_heading: WmoHeading,
+    /// The AWIPS product identifier, e.g., `AFDPSR`.
+    pub awips_id: Option<String>,
+    /// The product title, e.g., `Area Forecast Discussion`.
+    pub title: Option<String>,
+    /// The issuing office line, e.g., `National Weather Service Phoenix AZ`.
+    pub office: Option<String>,
+    /// The issuance time line as written, in local time.
+    pub local_issuance_time: Option<String>,
+    /// The issuance time, if the local issuance time line could be resolved.
+    pub issuance_time: Option<Timestamp>,
+    /// The UGC segments of a segmented product.
+    pub segments: Vec<ProductSegment>,
+}
+
+impl FromStr for ProductHeader {
+    type Err = ParseProductHeaderError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let lines: Vec<&str> = string.lines().map(str::trim).collect();
+        let (heading_index, wmo_heading) = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .take(HEADING_SEARCH_LINES)
+            .find_map(|(index, line)| Some((index, line.parse::<WmoHeading>().ok()?)))
+            .ok_or_else(|| {
+                ParseProductHeaderError::new(
+                    lines.iter().find(|line| !line.is_empty()).unwrap_or(&""),
+                )
+            })?;
+
+        let mut rest = lines[heading_index + 1..].iter().copied().peekable();
+        let awips_id = rest.next_if(|line| is_awips_id(line)).map(str::to_string);
+
+        let mut title = Vec::new();
+        let mut office = None;
+        let mut local_issuance_time = None;
+        for line in rest {
+            if line.is_empty() {
+                if office.is_some() {
+                    break;
+                }
+                continue;
+            }
+            if office.is_some() {
+                local_issuance_time = Some(line.to_string());
+                break;
+            }
+            if is_office_line(line) {
+                office = Some(line.to_string());
+            } else if is_segment_start(line) {
+                break;
+            } else {
+                title.push(line);
+            }
+        }
+        let issuance_time = local_issuance_time.as_deref().and_then(parse_local_time);
+
+        Ok(Self {
+            wmo_heading,
+            awips_id,
+            title: (office.is_some() && !title.is_empty()).then(|| title.join(" ")),
+            office,
+            local_issuance_time,
+            issuance_time,
+            segments: segment::split_segments(string),
+        })
+    }
+}
+
+/// A WMO abbreviated heading, `TTAAii CCCC YYGGgg [BBB]`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct WmoHeading {
+    /// The data type and geographic designators with the bulletin number (`TTAAii`), e.g., `FXUS65`.
+    pub data_type: String,
+    /// The originating station (`CCCC`), e.g., `KPSR`.
+    pub station: String,
+    /// The day of month of the heading time in UTC.
+    pub day: i8,
+    /// The hour of the heading time in UTC.
+    pub hour: i8,
+    /// The minute of the heading time in UTC.
+    pub minute: i8,
+    /// The delayed, correction, amendment, or segment indicator (`BBB`).
+    pub indicator: Option<WmoIndicator>,
+}
+
+impl fmt::Display for WmoHeading {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {:02}{:02}{:02}",
+            self.data_type, self.station, self.day, self.hour, self.minute
+        )?;
+        if let Some(indicator) = &self.indicator {
+            write!(f, " {indicator}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for WmoHeading {
+    type Err = ParseProductHeaderError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseProductHeaderError::new(string);
+        let mut tokens = string.split_whitespace();
+        let data_type = tokens
+            .next()
+            .filter(|token| {
+                token.len() == 6
+                    && token.is_ascii()
+                    && token[..4].bytes().all(|byte| byte.is_ascii_uppercase())
+                    && token[4..].bytes().all(|byte| byte.is_ascii_digit())
+            })
+            .ok_or_else(invalid)?;
+        let station = tokens
+            .next()
+            .filter(|token| token.len() == 4 && token.bytes().all(|byte| byte.is_ascii_uppercase()))
+            .ok_or_else(invalid)?;
+        let time = tokens
+            .next()
+            .filter(|token| token.len() == 6 && token.bytes().all(|byte| byte.is_ascii_digit()))
+            .ok_or_else(invalid)?;
+        let indicator = tokens.next().map(str::parse).transpose()?;
+        if tokens.next().is_some() {
+            return Err(invalid());
+        }
+        let field =
+            |range: std::ops::Range<usize>| time[range].parse::<i8>().map_err(|_| invalid());
+        let (day, hour, minute) = (field(0..2)?, field(2..4)?, field(4..6)?);
+        if !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+            return Err(invalid());
+        }
+        Ok(Self {
+            data_type: data_type.to_string(),
+            station: station.to_string(),
+            day,
+            hour,
+            minute,
+            indicator,
+        })
+    }
+}
+
+/// The `BBB` indicator of a WMO heading.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum WmoIndicator {
+    /// A delayed or retransmitted product (`RRx`).
+    Delayed(char),
+    /// A corrected product (`CCx`).
+    Correction(char),
+    /// An amended product (`AAx`).
+    Amendment(char),
+    /// A segment of a product split across messages (`Pxx`).
+    Segment(String),
+}
+
+impl fmt::Display for WmoIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Delayed(sequence) => write!(f, "RR{sequence}"),
+            Self::Correction(sequence) => write!(f, "CC{sequence}"),
+            Self::Amendment(sequence) => write!(f, "AA{sequence}"),
+            Self::Segment(sequence) => write!(f, "P{sequence}"),
+        }
+    }
+}
+
+impl FromStr for WmoIndicator {
+    type Err = ParseProductHeaderError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseProductHeaderError::new(string);
+        if string.len() != 3 || !string.bytes().all(|byte| byte.is_ascii_uppercase()) {
+            return Err(invalid());
+        }
+        let sequence = string.chars().nth(2).ok_or_else(invalid)?;
+        match &string[..2] {
+            "RR" => Ok(Self::Delayed(sequence)),
+            "CC" => Ok(Self::Correction(sequence)),
+            "AA" => Ok(Self::Amendment(sequence)),
+            _ if string.starts_with('P') => Ok(Self::Segment(string[1..].to_string())),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Parses a local issuance time line such as `312 AM MST Sat Oct 18 2025`.
+///
+/// Returns `None` for unknown time zones or malformed lines.
+pub(crate) fn parse_local_time(line: &str) -> Option<Timestamp> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+    let [time, meridiem, zone, _weekday, month, day, year] = tokens[..] else {
+        return None;
+    };
+    let (hour, minute) = parse_clock_time(time, meridiem)?;
+    let offset = Offset::from_hours(time_zone_offset(zone)?).ok()?;
+    let date = Date::new(year.parse().ok()?, parse_month(month)?, day.parse().ok()?).ok()?;
+    let datetime = DateTime::from_parts(date, jiff::civil::Time::new(hour, minute, 0, 0).ok()?);
+    offset.to_timestamp(datetime).ok()
+}
+
+/// Parses a 12-hour `HMM` or `HHMM` time with its `AM` or `PM` marker into an hour and minute.
+pub(crate) fn parse_clock_time(time: &str, meridiem: &str) -> Option<(i8, i8)> {
+    if !time.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+    let (hour, minute) = match time.len() {
+        1 | 2 => (time, "0"),
+        3 | 4 => time.split_at(time.len() - 2),
+        _ => return None,
+    };
+    let hour: i8 = hour.parse().ok()?;
+    let minute: i8 = minute.parse().ok()?;
+    if !(1..=12).contains(&hour) || minute > 59 {
+        return None;
+    }
+    match meridiem.to_ascii_uppercase().as_str() {
+        "AM" => Some((hour % 12, minute)),
+        "PM" => Some((hour % 12 + 12, minute)),
+        _ => None,
+    }
+}
+
+/// Returns the UTC offset in hours of a US time zone abbreviation.
+pub(crate) fn time_zone_offset(zone: &str) -> Option<i8> {
+    match zone.to_ascii_uppercase().as_str() {
+        "UTC" | "GMT" | "Z" => Some(0),
+        "AST" | "EDT" => Some(-4),
+        "EST" | "CDT" => Some(-5),
+        "CST" | "MDT" => Some(-6),
+        "MST" | "PDT" => Some(-7),
+        "PST" | "AKDT" => Some(-8),
+        "AKST" | "HDT" => Some(-9),
+        "HST" => Some(-10),
+        "SST" => Some(-11),
+        "CHST" => Some(10),
+        _ => None,
+    }
+}
+
+/// Parses a three-letter English month abbreviation.
+pub(crate) fn parse_month(month: &str) -> Option<i8> {
+    let index = [
+        "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+    ]
+    .iter()
+    .position(|name| {
+        month
+            .get(..3)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+    })?;
+    i8::try_from(index + 1).ok()
+}
+
+/// Returns true for an AWIPS product identifier: four to six uppercase letters or digits.
+fn is_awips_id(line: &str) -> bool {
+    (4..=6).contains(&line.len())
+        && line
+            .bytes()
+            .all(|byte| byte.is_ascii_uppercase() || byte.is_ascii_digit())
+}
+
+/// Returns true for the issuing office line of the MND block.
+fn is_office_line(line: &str) -> bool {
+    let upper = line.to_ascii_uppercase();
+    upper.starts_with("NATIONAL WEATHER SERVICE") || upper.starts_with("NWS ")
+}
+
+/// Returns true for the first line of a UGC segment.
+fn is_segment_start(line: &str) -> bool {
+    line.ends_with('-') && !line.contains(' ')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WSW: &str = "
+000
+WWUS45 KFGZ 181002
+WSWFGZ
+
+URGENT - WINTER WEATHER MESSAGE
+National Weather Service Flagstaff AZ
+302 AM MST Sat Oct 18 2025
+
+AZZ004-006-181800-
+/O.NEW.KFGZ.WW.Y.0003.251018T1200Z-251019T0000Z/
+Kaibab Plateau-Western Mogollon Rim-
+302 AM MST Sat Oct 18 2025
+
+...WINTER WEATHER ADVISORY IN EFFECT FROM 5 AM TO 5 PM MST TODAY...
+
+* WHAT...Snow expected.
+
+$$
+
+AZZ015-181800-
+/O.CON.KFGZ.WS.W.0002.000000T0000Z-251019T0000Z/
+Chuska Mountains-
+302 AM MST Sat Oct 18 2025
+
+...WINTER STORM WARNING REMAINS IN EFFECT UNTIL 5 PM MST THIS AFTERNOON...
+
+$$
+";
+
+    #[test]
+    fn test_parse_segmented_product_header() {
+        let header: ProductHeader = WSW.parse().unwrap();
+        assert_eq!(header.wmo_heading.to_string(), "WWUS45 KFGZ 181002");
+        assert_eq!(header.wmo_heading.station, "KFGZ");
+        assert_eq!(header.wmo_heading.indicator, None);
+        assert_eq!(header.awips_id.as_deref(), Some("WSWFGZ"));
+        assert_eq!(
+            header.title.as_deref(),
+            Some("URGENT - WINTER WEATHER MESSAGE")
+        );
+        assert_eq!(
+            header.office.as_deref(),
+            Some("National Weather Service Flagstaff AZ")
+        );
+        assert_eq!(
+            header.local_issuance_time.as_deref(),
+            Some("302 AM MST Sat Oct 18 2025")
+        );
+        assert_eq!(
+            header.issuance_time,
+            Some("2025-10-18T10:02:00Z".parse().unwrap())
+        );
+
+        assert_eq!(header.segments.len(), 2);
+        assert_eq!(header.segments[0].ugc.to_string(), "AZZ004-006-181800-");
+        assert_eq!(header.segments[0].vtec.len(), 1);
+        assert_eq!(header.segments[1].vtec[0].primary.office, "KFGZ");
+    }
+
+    #[test]
+    fn test_parse_wmo_heading() {
+        let heading: WmoHeading = "FXUS65 KPSR 011130 AAB".parse().unwrap();
+        assert_eq!(heading.data_type, "FXUS65");
+        assert_eq!((heading.day, heading.hour, heading.minute), (1, 11, 30));
+        assert_eq!(heading.indicator, Some(WmoIndicator::Amendment('B')));
+        assert_eq!(heading.to_string(), "FXUS65 KPSR 011130 AAB");
+
+        assert!("FXUS65 KPSR 011190".parse::<WmoHeading>().is_err());
+        assert!("AFDPSR".parse::<WmoHeading>().is_err());
+        assert!("FXUS65 KPSR 011130 XYZ".parse::<WmoHeading>().is_err());
+    }
+
+    #[test]
+    fn test_parse_local_time() {
+        assert_eq!(
+            parse_local_time("1200 PM EDT Mon Jun 2 2025"),
+            Some("2025-06-02T16:00:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            parse_local_time("12 AM HST Mon Jun 2 2025"),
+            Some("2025-06-02T10:00:00Z".parse().unwrap())
+        );
+        assert_eq!(parse_local_time("1200 PM XYZ Mon Jun 2 2025"), None);
+    }
+
+    #[test]
+    fn test_missing_heading() {
+        let error = "Area Forecast Discussion"
+            .parse::<ProductHeader>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid product header value: Area Forecast Discussion"
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Full-text search across recent issuances of text products.
+//!
+//! The `/products` endpoint filters by location, office, WMO ID, type, and
+//! time, but not by the product text. [`fetch_products`] lists the products
+//! matching a [`ProductsQueryParams`] and downloads their text with bounded
+//! concurrency, and [`search_products`] returns those with the lines that match
+//! a regular expression. [`keyword_pattern`] builds a case-insensitive pattern for a
+//! phrase whose words may be wrapped across lines.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::product_search::{find_snippets, keyword_pattern};
+//!
+//! let pattern = keyword_pattern("blowing dust").unwrap();
+//! let text = ".SHORT TERM...\nGusty winds may lead to areas of blowing\ndust this afternoon.\n";
+//! let snippets = find_snippets(text, &pattern);
+//! assert_eq!(snippets[0].line_number, 2);
+//! assert_eq!(snippets[0].text, "Gusty winds may lead to areas of blowing dust this afternoon.");
+//! ```
+
+use std::error;
+use std::fmt;
+
+use futures_util::{StreamExt, TryStreamExt, stream};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+
+use crate::apis::Error;
+use crate::apis::configuration::Configuration;
+use crate::apis::products::{self, ProductError, ProductsQueryError, ProductsQueryParams};
+use crate::models::TextProduct;
+
+/// An error returned when products could not be searched.
+#[derive(Debug)]
+pub enum ProductSearchError {
+    /// The list of products could not be fetched.
+    Products(Error<ProductsQueryError>),
+    /// One of the products could not be fetched.
+    Product(Error<ProductError>),
+}
+
+impl fmt::Display for ProductSearchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Products(error) => write!(f, "Error fetching products: {error}"),
+            Self::Product(error) => write!(f, "Error fetching product: {error}"),
+        }
+    }
+}
+
+impl error::Error for ProductSearchError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Products(error) => Some(error),
+            Self::Product(error) => Some(error),
+        }
+    }
+}
+
+/// A product whose text matches a search.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductMatch {
+    /// The product ID.
+    pub id: Option<String>,
+    /// The issuing office, e.g., `KPSR`.
+    pub issuing_office: Option<String>,
+    /// The issuance time.
+    pub issuance_time: Option<String>,
+    /// The product type code, e.g., `AFD`.
+    pub product_code: Option<String>,
+    /// The product name, e.g., `Area Forecast Discussion`.
+    pub product_name: Option<String>,
+    /// The matching lines, in product order.
+    pub snippets: Vec<Snippet>,
+}
+
+impl ProductMatch {
+    /// Searches the text of a product, returning `None` when nothing matches.
+    pub fn find(product: &TextProduct, pattern: &Regex) -> Option<Self> {
+        let snippets = find_snippets(product.product_text.as_deref()?, pattern);
+        (!snippets.is_empty()).then(|| Self {
+            id: product.id.clone(),
+            issuing_office: product.issuing_office.clone(),
+            issuance_time: product.issuance_time.clone(),
+            product_code: product.product_code.clone(),
+            product_name: product.product_name.clone(),
+            snippets,
+        })
+    }
+}
+
+/// The lines of a product matched by a search.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snippet {
+    /// The 1-based number of the first matching line.
+    pub line_number: usize,
+    /// The matching lines joined into one line.
+    pub text: String,
+}
+
+/// Builds a case-insensitive pattern matching the words of a phrase in order,
+/// separated by any whitespace, including line breaks.
+///
+/// # Errors
+///
+/// Returns a [`regex::Error`] if the phrase is too long to compile.
+pub fn keyword_pattern(phrase: &str) -> Result<Regex, regex::Error> {
+    let words: Vec<String> = phrase.split_whitespace().map(regex::escape).collect();
+    RegexBuilder::new(&words.join(r"\s+"))
+        .case_insensitive(true)
+        .build()
+}
+
+/// Finds the lines of a text matched by a pattern.
+///
+/// A match that spans several lines produces one snippet holding all of them.
+/// Lines are only reported once, even when they hold several matches.
+pub fn find_snippets(text: &str, pattern: &Regex) -> Vec<Snippet> {
+    let text = text.replace("\r\n", "\n");
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+        .collect();
+    let line_of = |offset: usize| line_starts.partition_point(|start| *start <= offset) - 1;
+
+    let mut snippets: Vec<Snippet> = Vec::new();
+    let mut next_line = 0;
+    for found in pattern.find_iter(&text).filter(|found| !found.is_empty()) {
+        let first = line_of(found.start()).max(next_line);
+        let last = line_of(found.end() - 1);
+        if first > last {
+            continue;
+        }
+        let lines: Vec<&str> = (first..=last)
+            .map(|line| {
+                let end = line_starts.get(line + 1).map_or(text.len(), |end| end - 1);
+                text[line_starts[line]..end].trim()
+            })
+            .collect();
+        snippets.push(Snippet {
+            line_number: first + 1,
+            text: lines.join(" "),
+        });
+        next_line = last + 1;
+    }
+    snippets
+}
+
+/// Lists the products matching `params` and downloads up to `concurrency` of
+/// them at a time, returning them in the order they were listed.
+///
+/// # Errors
+///
+/// Returns a [`ProductSearchError`] if the list or any product cannot be fetched.
+pub async fn fetch_products(
+    configuration: &Configuration,
+    params: ProductsQueryParams,
+    concurrency: usize,
+) -> Result<Vec<TextProduct>, ProductSearchError> {
+    let collection = products::get_products_query(configuration, params)
+        .await
+        .map_err(ProductSearchError::Products)?;
+    let ids: Vec<String> = collection
+        .at_graph
+        .into_iter()
+        .flatten()
+        .filter_map(|product| product.id)
+        .collect();
+    stream::iter(ids)
+        .map(|id| async move { products::get_product(configuration, &id).await })
+        .buffered(concurrency.max(1))
+        .map_err(ProductSearchError::Product)
+        .try_collect()
+        .await
+}
+
+/// Fetches the products matching `params` with [`fetch_products`] and returns
+/// those whose text matches `pattern`, in the order they were listed.
+///
+/// # Errors
+///
+/// Returns a [`ProductSearchError`] if the list or any product cannot be fetched.
+pub async fn search_products(
+    configuration: &Configuration,
+    params: ProductsQueryParams,
+    pattern: &Regex,
+    concurrency: usize,
+) -> Result<Vec<ProductMatch>, ProductSearchError> {
+    let products = fetch_products(configuration, params, concurrency).await?;
+    Ok(products
+        .iter()
+        .filter_map(|product| ProductMatch::find(product, pattern))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::NwsForecastOfficeId;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    const AFD: &str = "
+.SHORT TERM /TODAY THROUGH SUNDAY/...
+Strong outflow winds may produce BLOWING DUST
+across Pinal County. Blowing dust is also possible
+near Casa Grande.
+
+.AVIATION...
+Blowing
+dust may reduce visibility.
+";
+
+    #[test]
+    fn test_find_keyword_snippets() {
+        let snippets = find_snippets(AFD, &keyword_pattern("blowing dust").unwrap());
+        assert_eq!(
+            snippets,
+            [
+                Snippet {
+                    line_number: 3,
+                    text: "Strong outflow winds may produce BLOWING DUST".into(),
+                },
+                Snippet {
+                    line_number: 4,
+                    text: "across Pinal County. Blowing dust is also possible".into(),
+                },
+                Snippet {
+                    line_number: 8,
+                    text: "Blowing dust may reduce visibility.".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_regex_snippets() {
+        let pattern = Regex::new(r"(?i)\b(pinal|casa grande)\b").unwrap();
+        let snippets = find_snippets(AFD, &pattern);
+        assert_eq!(
+            snippets
+                .iter()
+                .map(|snippet| snippet.line_number)
+                .collect::<Vec<_>>(),
+            [4, 5]
+        );
+        assert!(find_snippets(AFD, &Regex::new("haboob").unwrap()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_products() {
+        let server = MockServer::start().await;
+        let collection = serde_json::json!({
+            "@graph": [{"id": "dusty"}, {"id": "calm"}]
+        });
+        Mock::given(method("GET"))
+            .and(path("/products"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(collection))
+            .mount(&server)
+            .await;
+        for (id, text) in [("dusty", AFD), ("calm", ".AVIATION...\nVFR.\n")] {
+            Mock::given(method("GET"))
+                .and(path(format!("/products/{id}")))
+                .respond_with(ResponseTemplate::new(200).set_body_json(
+                    serde_json::json!({"id": id, "productCode": "AFD", "productText": text}),
+                ))
+                .expect(1)
+                .mount(&server)
+                .await;
+        }
+
+        let configuration = Configuration::new(None, Some(server.uri()), None, None);
+        let params = ProductsQueryParams {
+            location_ids: Some(vec![NwsForecastOfficeId::Psr]),
+            product_type_codes: Some(vec!["AFD".to_string()]),
+            ..Default::default()
+        };
+        let matches = search_products(
+            &configuration,
+            params,
+            &keyword_pattern("blowing dust").unwrap(),
+            2,
+        )
+        .await
+        .unwrap();
+        assert_eq!(matches.len(), 1);
+        assert_eq!(matches[0].id.as_deref(), Some("dusty"));
+        assert_eq!(matches[0].snippets.len(), 3);
+    }
+}
@@ -0,0 +1,222 @@
+//! Offline lookups for SAME (Specific Area Message Encoding) and county FIPS codes.
+//!
+//! Alerts list the counties they cover as six-digit SAME codes in
+//! [`AlertGeocode::same`](crate::models::AlertGeocode::same), and points report
+//! their county's code in
+//! [`NoaaWeatherRadio::same_code`](crate::models::NoaaWeatherRadio::same_code).
+//! A SAME code is a one-digit county subdivision followed by the five-digit
+//! county FIPS code (`PSSCCC`). This module bundles the NWS county table so
+//! those codes can be turned into names without a network request, and county
+//! names can be turned back into codes.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::models::StateTerritoryCode;
+//! use noaa_weather_client::same::{self, CountySubdivision};
+//!
+//! let area = same::lookup("704013").unwrap();
+//! assert_eq!(area.county.name, "Maricopa");
+//! assert_eq!(area.subdivision, CountySubdivision::Southwest);
+//! assert_eq!(area.to_string(), "Southwest Maricopa, AZ");
+//!
+//! let county = same::find_county(StateTerritoryCode::Az, "pima").unwrap();
+//! assert_eq!(county.same_code(), "004019");
+//! ```
+
+use std::fmt;
+use std::sync::LazyLock;
+
+use crate::models::StateTerritoryCode;
+
+/// The bundled county table, as `fips,state,county` rows sorted by FIPS code.
+const COUNTY_TABLE: &str = include_str!("../data/same_counties.csv");
+
+static COUNTIES: LazyLock<Vec<County>> = LazyLock::new(|| {
+    COUNTY_TABLE
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ',');
+            Some(County {
+                fips: fields.next()?,
+                state: fields.next()?.parse().ok()?,
+                name: fields.next()?,
+            })
+        })
+        .collect()
+});
+
+/// A county, parish, borough, independent city, or equivalent.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct County {
+    /// The five-digit FIPS code (e.g., "04013").
+    pub fips: &'static str,
+    pub state: StateTerritoryCode,
+    /// The county name without a "County" or "Parish" suffix (e.g., "Maricopa").
+    pub name: &'static str,
+}
+
+impl County {
+    /// Returns the SAME code covering the entire county (e.g., "004013").
+    pub fn same_code(&self) -> String {
+        format!("0{}", self.fips)
+    }
+}
+
+impl fmt::Display for County {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}, {}", self.name, self.state)
+    }
+}
+
+/// The part of a county a SAME code covers, from its leading digit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CountySubdivision {
+    Entire,
+    Northwest,
+    North,
+    Northeast,
+    West,
+    Central,
+    East,
+    Southwest,
+    South,
+    Southeast,
+}
+
+impl CountySubdivision {
+    /// Returns the subdivision for a SAME code's leading digit.
+    pub fn from_digit(digit: char) -> Option<Self> {
+        match digit {
+            '0' => Some(Self::Entire),
+            '1' => Some(Self::Northwest),
+            '2' => Some(Self::North),
+            '3' => Some(Self::Northeast),
+            '4' => Some(Self::West),
+            '5' => Some(Self::Central),
+            '6' => Some(Self::East),
+            '7' => Some(Self::Southwest),
+            '8' => Some(Self::South),
+            '9' => Some(Self::Southeast),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CountySubdivision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Entire => write!(f, "Entire"),
+            Self::Northwest => write!(f, "Northwest"),
+            Self::North => write!(f, "North"),
+            Self::Northeast => write!(f, "Northeast"),
+            Self::West => write!(f, "West"),
+            Self::Central => write!(f, "Central"),
+            Self::East => write!(f, "East"),
+            Self::Southwest => write!(f, "Southwest"),
+            Self::South => write!(f, "South"),
+            Self::Southeast => write!(f, "Southeast"),
+        }
+    }
+}
+
+/// A decoded SAME code: a county and the part of it covered.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub struct SameArea {
+    pub subdivision: CountySubdivision,
+    pub county: &'static County,
+}
+
+impl fmt::Display for SameArea {
+    /// Formats the area as, e.g., "Maricopa, AZ" or "Southwest Maricopa, AZ".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.subdivision {
+            CountySubdivision::Entire => write!(f, "{}", self.county),
+            subdivision => write!(f, "{subdivision} {}", self.county),
+        }
+    }
+}
+
+/// Returns every county in the bundled table, sorted by FIPS code.
+pub fn counties() -> &'static [County] {
+    &COUNTIES
+}
+
+/// Returns the county with the given five-digit FIPS code.
+pub fn county_by_fips(fips: &str) -> Option<&'static County> {
+    let counties = counties();
+    counties
+        .binary_search_by(|county| county.fips.cmp(fips))
+        .ok()
+        .map(|index| &counties[index])
+}
+
+/// Finds a county by state and name, ignoring case.
+///
+/// A trailing "County" or "Parish" is ignored, so "Caddo Parish" matches "Caddo".
+pub fn find_county(state: StateTerritoryCode, name: &str) -> Option<&'static County> {
+    let name = name.trim();
+    let name = name
+        .strip_suffix(" County")
+        .or_else(|| name.strip_suffix(" Parish"))
+        .unwrap_or(name);
+    counties()
+        .iter()
+        .find(|county| county.state == state && county.name.eq_ignore_ascii_case(name))
+}
+
+/// Decodes a six-digit SAME code (e.g., "004013") into a county and subdivision.
+///
+/// Returns `None` for malformed codes and for codes not in the bundled table,
+/// such as marine areas.
+pub fn lookup(code: &str) -> Option<SameArea> {
+    if code.len() != 6 || !code.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let subdivision = CountySubdivision::from_digit(code.chars().next()?)?;
+    Some(SameArea {
+        subdivision,
+        county: county_by_fips(&code[1..])?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_sorted_and_complete() {
+        let counties = counties();
+        assert!(counties.len() > 3_200);
+        assert!(counties.windows(2).all(|pair| pair[0].fips < pair[1].fips));
+        assert_eq!(COUNTY_TABLE.lines().skip(1).count(), counties.len());
+    }
+
+    #[test]
+    fn test_lookup() {
+        let area = lookup("048201").unwrap();
+        assert_eq!(area.county.name, "Harris");
+        assert_eq!(area.county.state, StateTerritoryCode::Tx);
+        assert_eq!(area.subdivision, CountySubdivision::Entire);
+        assert_eq!(area.to_string(), "Harris, TX");
+
+        let area = lookup("951760").unwrap();
+        assert_eq!(area.to_string(), "Southeast City of Richmond, VA");
+    }
+
+    #[test]
+    fn test_lookup_invalid() {
+        assert!(lookup("04013").is_none());
+        assert!(lookup("0040AB").is_none());
+        assert!(lookup("057530").is_none());
+    }
+
+    #[test]
+    fn test_find_county() {
+        let county = find_county(StateTerritoryCode::La, "Caddo Parish").unwrap();
+        assert_eq!(county.fips, "22017");
+        assert_eq!(county.same_code(), "022017");
+        assert!(find_county(StateTerritoryCode::Az, "Harris").is_none());
+    }
+}
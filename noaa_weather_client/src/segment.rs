@@ -0,0 +1,254 @@
+//! Splitting of segmented text products.
+//!
+//! Watches, warnings, and zone forecast products (`ZFP`, `NPW`, `WSW`,
+//! `FFW`, ...) consist of segments delimited by `$$`. Each segment starts
+//! with a UGC string, its VTEC strings, the names of the areas covered, and
+//! the issuance time, followed by `...HEADLINE...` paragraphs and the body:
+//!
+//! ```text
+//! AZZ004-006-181800-
+//! /O.NEW.KFGZ.WW.Y.0003.251018T1200Z-251019T0000Z/
+//! Kaibab Plateau-Western Mogollon Rim-
+//! Including the cities of Jacob Lake and Flagstaff
+//! 302 AM MST Sat Oct 18 2025
+//!
+//! ...WINTER WEATHER ADVISORY IN EFFECT FROM 5 AM TO 5 PM MST TODAY...
+//!
+//! * WHAT...Snow expected.
+//!
+//! $$
+//! ```
+//!
+//! [`TextProduct::segments`](crate::models::TextProduct::segments) splits the
+//! `product_text` of a product.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::segment::split_segments;
+//!
+//! let text = "AZZ537-540-190915-\nGreater Phoenix Area-Tonto Basin-\n1231 AM MST Sun Oct 19 2025\n\n.TODAY...Sunny.\n\n$$\n";
+//! let segments = split_segments(text);
+//! assert_eq!(segments[0].areas, ["Greater Phoenix Area", "Tonto Basin"]);
+//! assert!(segments[0].covers("AZZ540"));
+//! assert_eq!(segments[0].text, ".TODAY...Sunny.");
+//! ```
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+
+use crate::product_header;
+use crate::ugc::{self, Ugc};
+use crate::vtec::{self, Vtec};
+
+/// A segment of a segmented product, delimited by `$$`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProductSegment {
+    /// The counties and zones the segment covers.
+    pub ugc: Ugc,
+    /// The VTEC strings in the segment.
+    pub vtec: Vec<Vtec>,
+    /// The names of the areas covered, e.g., `Kaibab Plateau`.
+    pub areas: Vec<String>,
+    /// The segment issuance time, if its local issuance time line could be resolved.
+    pub issuance_time: Option<Timestamp>,
+    /// The `...HEADLINE...` paragraphs at the start of the body, without the dots.
+    pub headlines: Vec<String>,
+    /// The body following the headlines.
+    pub text: String,
+}
+
+impl ProductSegment {
+    /// Returns true if the segment covers a county or zone, given as a code such as `AZZ540`.
+    pub fn covers(&self, code: &str) -> bool {
+        self.ugc
+            .codes
+            .iter()
+            .any(|covered| covered.to_string().eq_ignore_ascii_case(code.trim()))
+    }
+
+    /// Parses a segment, returning `None` when it does not start with a UGC string.
+    fn parse(segment: &str) -> Option<Self> {
+        let lines: Vec<&str> = segment.lines().map(str::trim_end).collect();
+        let start = lines.iter().position(|line| {
+            let line = line.trim();
+            ugc::starts_ugc(line) && !line.contains(' ')
+        })?;
+        let header_end = lines[start..]
+            .iter()
+            .position(|line| line.trim().is_empty())
+            .map_or(lines.len(), |offset| start + offset);
+        let header = &lines[start..header_end];
+        let ugc = ugc::find_ugc(&header.join("\n")).into_iter().next()?;
+
+        let mut areas = Vec::new();
+        let mut issuance_time = None;
+        let mut in_ugc = true;
+        for line in header.iter().map(|line| line.trim()) {
+            if in_ugc {
+                in_ugc = !ugc::ends_with_expiration(line);
+                continue;
+            }
+            if line.starts_with('/') && line.ends_with('/') {
+                continue;
+            }
+            if let Some(time) = product_header::parse_local_time(line) {
+                issuance_time = Some(time);
+            } else if let Some(names) = line.strip_suffix('-') {
+                areas.extend(
+                    names
+                        .split('-')
+                        .map(str::trim)
+                        .filter(|name| !name.is_empty())
+                        .map(str::to_string),
+                );
+            }
+        }
+
+        let body = lines[header_end..].join("\n");
+        let mut paragraphs = body
+            .split("\n\n")
+            .map(str::trim)
+            .filter(|paragraph| !paragraph.is_empty())
+            .peekable();
+        let mut headlines = Vec::new();
+        while let Some(paragraph) = paragraphs.next_if(|paragraph| is_headline(paragraph)) {
+            headlines.extend(split_headlines(paragraph));
+        }
+
+        Some(Self {
+            ugc,
+            vtec: vtec::parse_vtec(segment),
+            areas,
+            issuance_time,
+            headlines,
+            text: paragraphs.collect::<Vec<_>>().join("\n\n"),
+        })
+    }
+}
+
+/// Splits a product into its `$$`-delimited segments, skipping any part
+/// that does not start with a UGC string, such as the product header.
+pub fn split_segments(text: &str) -> Vec<ProductSegment> {
+    text.replace("\r\n", "\n")
+        .split("$$")
+        .filter_map(ProductSegment::parse)
+        .collect()
+}
+
+/// Returns true for a `...HEADLINE...` paragraph.
+fn is_headline(paragraph: &str) -> bool {
+    paragraph.starts_with("...") && paragraph.ends_with("...") && paragraph.len() > 6
+}
+
+/// Splits a paragraph of one or more headlines, each of which may wrap across lines.
+fn split_headlines(paragraph: &str) -> Vec<String> {
+    let mut headlines = Vec::new();
+    let mut current = String::new();
+    for line in paragraph.lines().map(str::trim) {
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(line);
+        if current.len() > 6 && current.ends_with("...") {
+            headlines.push(current.trim_matches('.').trim().to_string());
+            current.clear();
+        }
+    }
+    if !current.is_empty() {
+        headlines.push(current.trim_matches('.').trim().to_string());
+    }
+    headlines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WSW: &str = "
+000
+WWUS45 KFGZ 181002
+WSWFGZ
+
+URGENT - WINTER WEATHER MESSAGE
+National Weather Service Flagstaff AZ
+302 AM MST Sat Oct 18 2025
+
+AZZ004-006-
+015-181800-
+/O.NEW.KFGZ.WW.Y.0003.251018T1200Z-251019T0000Z/
+Kaibab Plateau-Western Mogollon Rim-
+Chuska Mountains-
+Including the cities of Jacob Lake, Flagstaff,
+and Window Rock
+302 AM MST Sat Oct 18 2025
+
+...WINTER WEATHER ADVISORY IN EFFECT FROM 5 AM TO
+5 PM MST TODAY...
+
+* WHAT...Snow expected. Total snow accumulations of 2 to 4 inches.
+
+* WHERE...Kaibab Plateau.
+
+$$
+
+AZZ015-181800-
+/O.CON.KFGZ.WS.W.0002.000000T0000Z-251019T0000Z/
+/O.EXP.KFGZ.WW.Y.0002.000000T0000Z-251018T1000Z/
+Chuska Mountains-
+302 AM MST Sat Oct 18 2025
+
+...WINTER STORM WARNING REMAINS IN EFFECT UNTIL 5 PM MST THIS
+AFTERNOON...
+...WINTER WEATHER ADVISORY HAS EXPIRED...
+
+$$
+";
+
+    #[test]
+    fn test_split_segments() {
+        let segments = split_segments(WSW);
+        assert_eq!(segments.len(), 2);
+
+        let advisory = &segments[0];
+        assert_eq!(advisory.ugc.to_string(), "AZZ004-006-015-181800-");
+        assert!(advisory.covers("azz015"));
+        assert!(!advisory.covers("AZZ005"));
+        assert_eq!(advisory.vtec.len(), 1);
+        assert_eq!(
+            advisory.areas,
+            ["Kaibab Plateau", "Western Mogollon Rim", "Chuska Mountains"]
+        );
+        assert_eq!(
+            advisory.issuance_time,
+            Some("2025-10-18T10:02:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            advisory.headlines,
+            ["WINTER WEATHER ADVISORY IN EFFECT FROM 5 AM TO 5 PM MST TODAY"]
+        );
+        assert_eq!(
+            advisory.text,
+            "* WHAT...Snow expected. Total snow accumulations of 2 to 4 inches.\n\n* WHERE...Kaibab Plateau."
+        );
+
+        let warning = &segments[1];
+        assert_eq!(warning.vtec.len(), 2);
+        assert_eq!(warning.areas, ["Chuska Mountains"]);
+        assert_eq!(
+            warning.headlines,
+            [
+                "WINTER STORM WARNING REMAINS IN EFFECT UNTIL 5 PM MST THIS AFTERNOON",
+                "WINTER WEATHER ADVISORY HAS EXPIRED"
+            ]
+        );
+        assert_eq!(warning.text, "");
+    }
+
+    #[test]
+    fn test_split_unsegmented_product() {
+        assert!(
+            split_segments("Area Forecast Discussion\n\n.AVIATION...\nVFR.\n\n$$\n").is_empty()
+        );
+    }
+}
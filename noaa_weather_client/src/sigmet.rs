@@ -0,0 +1,911 @@
+//! Decoding of SIGMET and Center Weather Advisory (CWA) text.
+//!
+//! [`Sigmet`](crate::models::Sigmet) and
+//! [`CenterWeatherAdvisory`](crate::models::CenterWeatherAdvisory) only carry
+//! identifiers and times as fields; the affected area, flight levels,
+//! movement, and trend are in the message text:
+//!
+//! ```text
+//! CONVECTIVE SIGMET 45W
+//! VALID UNTIL 2055Z
+//! NM AZ
+//! FROM 30NW ABQ-40E TCC-50S ELP-60W TUS-30NW ABQ
+//! AREA SEV TS MOV FROM 24025KT. TOPS ABV FL450.
+//! ```
+//!
+//! [`DecodedAdvisory`] parses convective SIGMETs, domestic and international
+//! (ICAO) SIGMETs, and CWAs. Area vertices given relative to a VOR, such as
+//! `30NW ABQ`, are resolved with the bundled [`navaid`] table, or with a
+//! caller-supplied lookup through [`DecodedAdvisory::parse_with_navaids`].
+//! Directions are true, distances are in nautical miles, and altitudes are
+//! in feet.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::sigmet::{AdvisoryKind, AviationHazard, DecodedAdvisory, Movement};
+//!
+//! let text = "ZAB CWA 101 VALID UNTIL 182045\nFROM ABQ-TCC-30SW ABQ-ABQ\nAREA OF SEV TURB FL250-FL380. MOV FROM 24025KT.";
+//! let advisory: DecodedAdvisory = text.parse().unwrap();
+//! assert_eq!(advisory.kind, AdvisoryKind::CenterWeatherAdvisory);
+//! assert_eq!(advisory.hazards, [AviationHazard::Turbulence]);
+//! assert_eq!(advisory.altitude.unwrap().to_string(), "FL250-FL380");
+//! assert_eq!(
+//!     advisory.movement,
+//!     Some(Movement::Moving { from_degrees: 240, speed_knots: 25 })
+//! );
+//! assert_eq!(advisory.positions().unwrap().len(), 4);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::geometry::LatLon;
+use crate::models::gridpoint_12h_forecast_period::WindDirection;
+use crate::navaid;
+
+/// An error returned when text is not a SIGMET or CWA.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAdvisoryError {
+    invalid_value: String,
+}
+
+impl ParseAdvisoryError {
+    fn new(invalid_value: &str) -> Self {
+        Self {
+            invalid_value: invalid_value.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseAdvisoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid aviation advisory value: {}", self.invalid_value)
+    }
+}
+
+impl std::error::Error for ParseAdvisoryError {}
+
+/// The kind of aviation advisory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AdvisoryKind {
+    /// A convective SIGMET (`WST`).
+    ConvectiveSigmet,
+    /// A non-convective SIGMET, domestic (`WS`) or international.
+    Sigmet,
+    /// A Center Weather Advisory issued by a Center Weather Service Unit.
+    CenterWeatherAdvisory,
+}
+
+impl fmt::Display for AdvisoryKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConvectiveSigmet => write!(f, "Convective SIGMET"),
+            Self::Sigmet => write!(f, "SIGMET"),
+            Self::CenterWeatherAdvisory => write!(f, "CWA"),
+        }
+    }
+}
+
+/// A hazard named in an advisory.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AviationHazard {
+    Thunderstorm,
+    Turbulence,
+    Icing,
+    Ifr,
+    MountainObscuration,
+    LowLevelWindShear,
+    VolcanicAsh,
+    DustStorm,
+    SandStorm,
+    TropicalCyclone,
+}
+
+impl fmt::Display for AviationHazard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Thunderstorm => write!(f, "Thunderstorms"),
+            Self::Turbulence => write!(f, "Turbulence"),
+            Self::Icing => write!(f, "Icing"),
+            Self::Ifr => write!(f, "IFR"),
+            Self::MountainObscuration => write!(f, "Mountain obscuration"),
+            Self::LowLevelWindShear => write!(f, "Low-level wind shear"),
+            Self::VolcanicAsh => write!(f, "Volcanic ash"),
+            Self::DustStorm => write!(f, "Dust storm"),
+            Self::SandStorm => write!(f, "Sand storm"),
+            Self::TropicalCyclone => write!(f, "Tropical cyclone"),
+        }
+    }
+}
+
+/// The altitudes an advisory applies between.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AltitudeRange {
+    /// The base in feet, `None` when unbounded or at the freezing level.
+    pub base_feet: Option<u32>,
+    /// Whether the base is the freezing level, as in `BTN FRZLVL AND FL220`.
+    pub base_freezing_level: bool,
+    /// The top in feet, `None` when unbounded.
+    pub top_feet: Option<u32>,
+    /// Whether the tops extend above `top_feet`, as in `TOPS ABV FL450`.
+    pub tops_above: bool,
+}
+
+impl fmt::Display for AltitudeRange {
+    /// Formats the range as, e.g., "SFC-FL100", "FRZLVL-FL220", or "TOPS ABV FL450".
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = |feet: u32| match feet {
+            0 => "SFC".to_string(),
+            feet if feet < 18_000 => format!("{feet} FT"),
+            feet => format!("FL{:03}", feet / 100),
+        };
+        let base = if self.base_freezing_level {
+            Some("FRZLVL".to_string())
+        } else {
+            self.base_feet.map(level)
+        };
+        match (base, self.top_feet) {
+            (Some(base), Some(top)) => write!(f, "{base}-{}", level(top)),
+            (Some(base), None) => write!(f, "ABV {base}"),
+            (None, Some(top)) if self.tops_above => write!(f, "TOPS ABV {}", level(top)),
+            (None, Some(top)) => write!(f, "BLW {}", level(top)),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// The movement of an advisory area.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Movement {
+    /// Moving from a true direction, in degrees, at a speed in knots.
+    Moving { from_degrees: u16, speed_knots: u16 },
+    /// Moving little (`MOV LTL`).
+    Little,
+    /// Stationary (`STNR` or `QS`).
+    Stationary,
+}
+
+impl fmt::Display for Movement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Moving {
+                from_degrees,
+                speed_knots,
+            } => write!(f, "From {from_degrees:03}° at {speed_knots} kt"),
+            Self::Little => write!(f, "Little movement"),
+            Self::Stationary => write!(f, "Stationary"),
+        }
+    }
+}
+
+/// The expected change in intensity of the hazard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IntensityTrend {
+    Intensifying,
+    Weakening,
+    NoChange,
+}
+
+impl fmt::Display for IntensityTrend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Intensifying => write!(f, "Intensifying"),
+            Self::Weakening => write!(f, "Weakening"),
+            Self::NoChange => write!(f, "No change"),
+        }
+    }
+}
+
+/// A point outlining an advisory area.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Vertex {
+    /// The point as written, e.g., `30NW ABQ` or `N3200 W11000`.
+    pub description: String,
+    /// The position, if the point could be resolved.
+    pub position: Option<LatLon>,
+}
+
+/// A decoded SIGMET or CWA.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecodedAdvisory {
+    pub kind: AdvisoryKind,
+    /// The advisory identifier, e.g., `45W`, `ROMEO 2`, or `101`.
+    pub id: String,
+    /// The end of the validity period as written, e.g., `2055Z` or `182045`.
+    pub valid_until: Option<String>,
+    /// The two-letter codes of the states and coastal waters affected.
+    pub states: Vec<String>,
+    /// The hazards, in the order they are first named.
+    pub hazards: Vec<AviationHazard>,
+    pub altitude: Option<AltitudeRange>,
+    pub movement: Option<Movement>,
+    pub intensity: Option<IntensityTrend>,
+    /// The points outlining the area: a polygon, a line, or a single point.
+    pub vertices: Vec<Vertex>,
+    /// The width of a line of thunderstorms, in nautical miles.
+    pub width_nautical_miles: Option<u16>,
+    /// The diameter of an isolated thunderstorm area, in nautical miles.
+    pub diameter_nautical_miles: Option<u16>,
+}
+
+impl DecodedAdvisory {
+    /// Decodes a SIGMET or CWA, resolving navaids with `lookup` instead of the
+    /// bundled [`navaid`] table.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseAdvisoryError`] if the text has no SIGMET or CWA heading.
+    pub fn parse_with_navaids(
+        text: &str,
+        lookup: impl Fn(&str) -> Option<LatLon>,
+    ) -> Result<Self, ParseAdvisoryError> {
+        let lines: Vec<String> = text
+            .lines()
+            .map(|line| line.trim().to_ascii_uppercase())
+            .filter(|line| !line.is_empty())
+            .take_while(|line| !line.starts_with("OUTLOOK"))
+            .collect();
+        let (heading_index, heading) = lines
+            .iter()
+            .enumerate()
+            .find_map(|(index, line)| Heading::parse(line).map(|heading| (index, heading)))
+            .ok_or_else(|| ParseAdvisoryError::new(text.trim()))?;
+
+        let mut lines = lines[heading_index + 1..].iter().peekable();
+        let mut valid_until = heading.valid_until;
+        if valid_until.is_none()
+            && let Some(line) = lines.next_if(|line| line.starts_with("VALID "))
+        {
+            valid_until = line.split_whitespace().last().map(valid_time);
+        }
+
+        let mut states = Vec::new();
+        let mut area = String::new();
+        let mut body = heading.rest;
+        while let Some(line) = lines.next() {
+            if area.is_empty()
+                && let Some(points) = line.strip_prefix("FROM ")
+            {
+                area.push_str(points);
+                while area.ends_with('-') || area.ends_with(" TO") {
+                    let Some(line) = lines.next() else { break };
+                    area.push(' ');
+                    area.push_str(line);
+                }
+            } else if area.is_empty() && body.trim().is_empty() && is_states_line(line) {
+                states.extend(
+                    line.split_whitespace()
+                        .filter(|token| token.len() == 2)
+                        .map(str::to_string),
+                );
+            } else {
+                body.push(' ');
+                body.push_str(line);
+            }
+        }
+
+        let mut tokens = tokenize(&body);
+        let vertices = if area.is_empty() {
+            take_coordinates(&mut tokens)
+                .into_iter()
+                .map(|description| Vertex {
+                    position: parse_coordinates(&description),
+                    description,
+                })
+                .collect()
+        } else {
+            area.trim_end_matches('.')
+                .split('-')
+                .flat_map(|part| part.split(" TO "))
+                .map(|point| point.split_whitespace().collect::<Vec<_>>().join(" "))
+                .filter(|point| !point.is_empty())
+                .map(|description| Vertex {
+                    position: resolve_point(&description, &lookup),
+                    description,
+                })
+                .collect()
+        };
+
+        Ok(Self {
+            kind: heading.kind,
+            id: heading.id,
+            valid_until,
+            states,
+            hazards: parse_hazards(&tokens),
+            altitude: parse_altitude(&tokens),
+            movement: parse_movement(&tokens),
+            intensity: parse_intensity(&tokens),
+            vertices,
+            width_nautical_miles: parse_width(&tokens),
+            diameter_nautical_miles: parse_diameter(&tokens),
+        })
+    }
+
+    /// Returns the positions of the vertices, or `None` if any could not be resolved.
+    pub fn positions(&self) -> Option<Vec<LatLon>> {
+        self.vertices.iter().map(|vertex| vertex.position).collect()
+    }
+}
+
+impl FromStr for DecodedAdvisory {
+    type Err = ParseAdvisoryError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse_with_navaids(text, |id| navaid::lookup(id).map(|navaid| navaid.position))
+    }
+}
+
+/// The line naming an advisory, e.g., `SIGMET ROMEO 2 VALID UNTIL 181930`.
+struct Heading {
+    kind: AdvisoryKind,
+    id: String,
+    valid_until: Option<String>,
+    /// The text following the heading on the same line.
+    rest: String,
+}
+
+impl Heading {
+    fn parse(line: &str) -> Option<Self> {
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let (kind, id, id_end) = if let Some(start) = line.find("CONVECTIVE SIGMET") {
+            let rest = line[start + "CONVECTIVE SIGMET".len()..].trim_start_matches([' ', '.']);
+            let id = rest.split_whitespace().next()?;
+            let end = tokens.iter().position(|token| token.ends_with(id))? + 1;
+            (AdvisoryKind::ConvectiveSigmet, id.to_string(), end)
+        } else if let Some(index) = tokens.iter().position(|token| *token == "SIGMET") {
+            let name = tokens.get(index + 1)?;
+            match tokens.get(index + 2) {
+                Some(number) if number.chars().all(|c| c.is_ascii_digit()) => {
+                    (AdvisoryKind::Sigmet, format!("{name} {number}"), index + 3)
+                }
+                _ => (AdvisoryKind::Sigmet, name.to_string(), index + 2),
+            }
+        } else if let Some(index) = tokens.iter().position(|token| *token == "CWA")
+            && tokens.contains(&"VALID")
+        {
+            let id = tokens.get(index + 1)?;
+            (
+                AdvisoryKind::CenterWeatherAdvisory,
+                id.to_string(),
+                index + 2,
+            )
+        } else {
+            return None;
+        };
+
+        let (valid_until, rest_start) = match tokens.iter().position(|token| *token == "VALID") {
+            Some(index) if tokens.get(index + 1) == Some(&"UNTIL") => {
+                (tokens.get(index + 2).copied(), index + 3)
+            }
+            Some(index) => (tokens.get(index + 1).copied(), index + 2),
+            None => (None, id_end),
+        };
+        Some(Self {
+            kind,
+            id,
+            valid_until: valid_until.map(valid_time),
+            rest: tokens.get(rest_start..).unwrap_or_default().join(" "),
+        })
+    }
+}
+
+/// Returns the end of a validity period written as `181930`, `2055Z.`, or `181200/181600`.
+fn valid_time(period: &str) -> String {
+    let end = period.rsplit('/').next().unwrap_or(period);
+    end.trim_end_matches(['-', '.']).to_string()
+}
+
+/// Returns true for a line listing states, e.g., `NM AZ` or `TX LA AND CSTL WTRS`.
+fn is_states_line(line: &str) -> bool {
+    line.split_whitespace().all(|token| {
+        matches!(token, "AND" | "CSTL" | "WTRS")
+            || (token.len() == 2 && token.chars().all(|c| c.is_ascii_alphabetic()))
+    })
+}
+
+/// Splits text into tokens, keeping `-` as a token and dropping sentence punctuation.
+fn tokenize(text: &str) -> Vec<String> {
+    text.replace('-', " - ")
+        .replace(['.', ','], " ")
+        .split_whitespace()
+        .map(str::to_string)
+        .collect()
+}
+
+/// Removes the coordinates following `WI` (within) from the tokens of an
+/// international SIGMET and returns them as `N3200 W11000` pairs.
+fn take_coordinates(tokens: &mut Vec<String>) -> Vec<String> {
+    let Some(start) = tokens.iter().position(|token| token == "WI") else {
+        return Vec::new();
+    };
+    let end = tokens[start + 1..]
+        .iter()
+        .position(|token| {
+            token != "-"
+                && parse_angle(token, 'N', 'S', 2).is_none()
+                && parse_angle(token, 'E', 'W', 3).is_none()
+        })
+        .map_or(tokens.len(), |offset| start + 1 + offset);
+    let coordinates: Vec<String> = tokens
+        .drain(start..end)
+        .skip(1)
+        .filter(|token| token != "-")
+        .collect();
+    coordinates.chunks(2).map(|pair| pair.join(" ")).collect()
+}
+
+/// Resolves a point written as `30NW ABQ`, `ABQ`, or `N3200 W11000`.
+fn resolve_point(point: &str, lookup: &impl Fn(&str) -> Option<LatLon>) -> Option<LatLon> {
+    if let Some(position) = parse_coordinates(point) {
+        return Some(position);
+    }
+    let Some((offset, id)) = point.split_once(' ') else {
+        return lookup(point);
+    };
+    let origin = lookup(id)?;
+    let direction_start = offset.find(|c: char| !c.is_ascii_digit())?;
+    let distance: f64 = offset[..direction_start].parse().ok()?;
+    let direction: WindDirection = offset[direction_start..].parse().ok()?;
+    Some(navaid::destination(origin, direction.degrees(), distance))
+}
+
+/// Parses ICAO coordinates such as `N3200 W11000` or `S05 E120`.
+fn parse_coordinates(point: &str) -> Option<LatLon> {
+    let (latitude, longitude) = point.split_once(' ')?;
+    Some(LatLon {
+        latitude: parse_angle(latitude, 'N', 'S', 2)?,
+        longitude: parse_angle(longitude.trim(), 'E', 'W', 3)?,
+    })
+}
+
+/// Parses a hemisphere letter followed by whole degrees and optional minutes.
+fn parse_angle(token: &str, positive: char, negative: char, degree_digits: usize) -> Option<f64> {
+    let (sign, digits) = match token.strip_prefix(positive) {
+        Some(digits) => (1.0, digits),
+        None => (-1.0, token.strip_prefix(negative)?),
+    };
+    if !digits.chars().all(|c| c.is_ascii_digit())
+        || (digits.len() != degree_digits && digits.len() != degree_digits + 2)
+    {
+        return None;
+    }
+    let degrees: f64 = digits[..degree_digits].parse().ok()?;
+    let minutes: f64 = match &digits[degree_digits..] {
+        "" => 0.0,
+        minutes => minutes.parse().ok()?,
+    };
+    Some(sign * (degrees + minutes / 60.0))
+}
+
+fn parse_hazards(tokens: &[String]) -> Vec<AviationHazard> {
+    let mut hazards = Vec::new();
+    for (index, token) in tokens.iter().enumerate() {
+        let next = tokens.get(index + 1).map(String::as_str);
+        let hazard = match (token.as_str(), next) {
+            ("TS" | "TSRA" | "TSGR" | "TSTM" | "TSTMS", _) => AviationHazard::Thunderstorm,
+            ("TURB" | "TURBC", _) => AviationHazard::Turbulence,
+            ("ICE" | "ICG" | "ICING", _) => AviationHazard::Icing,
+            ("IFR" | "LIFR", _) => AviationHazard::Ifr,
+            ("MTN", Some("OBSC" | "OBSCN" | "OBSCD")) => AviationHazard::MountainObscuration,
+            ("LLWS", _) => AviationHazard::LowLevelWindShear,
+            ("VA", _) | ("VOLCANIC", Some("ASH")) => AviationHazard::VolcanicAsh,
+            ("DS" | "DUSTSTORM", _) | ("DUST", Some("STORM")) => AviationHazard::DustStorm,
+            ("SS" | "SANDSTORM", _) | ("SAND", Some("STORM")) => AviationHazard::SandStorm,
+            ("TC", _) | ("TROPICAL", Some("CYCLONE")) => AviationHazard::TropicalCyclone,
+            _ => continue,
+        };
+        if !hazards.contains(&hazard) {
+            hazards.push(hazard);
+        }
+    }
+    hazards
+}
+
+/// Parses an altitude such as `FL250`, `080` (8,000 feet), `SFC`, or
+/// `FRZLVL`, returning `Some(None)` for the freezing level.
+fn parse_level(token: &str) -> Option<Option<u32>> {
+    match token {
+        "SFC" => Some(Some(0)),
+        "FRZLVL" => Some(None),
+        token => {
+            let digits = token.strip_prefix("FL").unwrap_or(token);
+            (digits.len() == 3 && digits.chars().all(|c| c.is_ascii_digit()))
+                .then(|| digits.parse::<u32>().ok().map(|hundreds| hundreds * 100))
+        }
+    }
+}
+
+/// Parses a flight level written with its `FL` prefix, e.g., `FL180`.
+fn parse_flight_level(token: &str) -> Option<u32> {
+    token.strip_prefix("FL")?;
+    parse_level(token)?
+}
+
+fn parse_altitude(tokens: &[String]) -> Option<AltitudeRange> {
+    let token = |index: usize| tokens.get(index).map_or("", String::as_str);
+    let mut altitude: Option<AltitudeRange> = None;
+    for index in 0..tokens.len() {
+        let range = match token(index) {
+            "BTN" if token(index + 2) == "AND" => {
+                let (Some(base), Some(top)) =
+                    (parse_level(token(index + 1)), parse_level(token(index + 3)))
+                else {
+                    continue;
+                };
+                AltitudeRange {
+                    base_feet: base,
+                    base_freezing_level: token(index + 1) == "FRZLVL",
+                    top_feet: top,
+                    tops_above: false,
+                }
+            }
+            "TOPS" | "TOP" => {
+                let qualifier = token(index + 1);
+                let level = match qualifier {
+                    "TO" | "ABV" | "ABOVE" => token(index + 2),
+                    level => level,
+                };
+                let Some(top) = parse_flight_level(level) else {
+                    continue;
+                };
+                AltitudeRange {
+                    base_feet: altitude.and_then(|altitude| altitude.base_feet),
+                    base_freezing_level: altitude
+                        .is_some_and(|altitude| altitude.base_freezing_level),
+                    top_feet: Some(top),
+                    tops_above: matches!(qualifier, "ABV" | "ABOVE"),
+                }
+            }
+            "BLW" | "BLO" | "BELOW" => {
+                let Some(top) = parse_flight_level(token(index + 1)) else {
+                    continue;
+                };
+                AltitudeRange {
+                    top_feet: Some(top),
+                    ..Default::default()
+                }
+            }
+            "ABV" | "ABOVE" if !matches!(index.checked_sub(1).map(token), Some("TOPS" | "TOP")) => {
+                let Some(base) = parse_flight_level(token(index + 1)) else {
+                    continue;
+                };
+                AltitudeRange {
+                    base_feet: Some(base),
+                    ..Default::default()
+                }
+            }
+            range if range.contains('/') => {
+                let (base, top) = range.split_once('/')?;
+                let (Some(base_feet), Some(top_feet)) = (parse_level(base), parse_level(top))
+                else {
+                    continue;
+                };
+                AltitudeRange {
+                    base_feet,
+                    base_freezing_level: base == "FRZLVL",
+                    top_feet,
+                    tops_above: false,
+                }
+            }
+            base if token(index + 1) == "-" && base.starts_with("FL") => {
+                let (Some(base), Some(top)) =
+                    (parse_flight_level(base), parse_level(token(index + 2)))
+                else {
+                    continue;
+                };
+                AltitudeRange {
+                    base_feet: Some(base),
+                    top_feet: top,
+                    ..Default::default()
+                }
+            }
+            _ => continue,
+        };
+        altitude = Some(range);
+        if range.base_feet.is_some() && range.top_feet.is_some() {
+            break;
+        }
+    }
+    altitude
+}
+
+fn parse_movement(tokens: &[String]) -> Option<Movement> {
+    let token = |index: usize| tokens.get(index).map_or("", String::as_str);
+    for index in 0..tokens.len() {
+        match token(index) {
+            "STNR" | "QS" => return Some(Movement::Stationary),
+            "MOV" => {}
+            _ => continue,
+        }
+        match token(index + 1) {
+            "LTL" => return Some(Movement::Little),
+            "FROM" => {
+                let wind = token(index + 2).strip_suffix("KT")?;
+                if wind.len() < 5 || !wind.chars().all(|c| c.is_ascii_digit()) {
+                    return None;
+                }
+                return Some(Movement::Moving {
+                    from_degrees: wind[..3].parse().ok()?,
+                    speed_knots: wind[3..].parse().ok()?,
+                });
+            }
+            toward => {
+                let direction: WindDirection = toward.parse().ok()?;
+                let speed = token(index + 2);
+                let speed = speed.strip_suffix("KT").unwrap_or(speed);
+                let from_degrees = ((direction.degrees() + 180.0) % 360.0).round() as u16;
+                return Some(Movement::Moving {
+                    from_degrees,
+                    speed_knots: speed.parse().ok()?,
+                });
+            }
+        }
+    }
+    None
+}
+
+fn parse_intensity(tokens: &[String]) -> Option<IntensityTrend> {
+    tokens.iter().find_map(|token| match token.as_str() {
+        "INTSF" | "INTSFY" | "INTSFG" | "DVLPG" => Some(IntensityTrend::Intensifying),
+        "WKN" | "WKNG" | "DMSHG" => Some(IntensityTrend::Weakening),
+        "NC" => Some(IntensityTrend::NoChange),
+        _ => None,
+    })
+}
+
+/// Parses the width of a line, written `20 NM WIDE` or `20NM WIDE`.
+fn parse_width(tokens: &[String]) -> Option<u16> {
+    tokens.iter().enumerate().find_map(|(index, token)| {
+        if tokens.get(index + 1)? == "WIDE" {
+            token.strip_suffix("NM")?.parse().ok()
+        } else if tokens.get(index + 1)? == "NM" && tokens.get(index + 2)? == "WIDE" {
+            token.parse().ok()
+        } else {
+            None
+        }
+    })
+}
+
+/// Parses the diameter of an area, written `D30` or `DIAM 30NM`.
+fn parse_diameter(tokens: &[String]) -> Option<u16> {
+    tokens.iter().enumerate().find_map(|(index, token)| {
+        if token == "DIAM" {
+            let diameter = tokens.get(index + 1)?;
+            diameter.strip_suffix("NM").unwrap_or(diameter).parse().ok()
+        } else {
+            token.strip_prefix('D')?.parse().ok()
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONVECTIVE_SIGMET: &str = "
+WSTW
+CONVECTIVE SIGMET 45W
+VALID UNTIL 2055Z
+NM AZ
+FROM 30NW ABQ-40E TCC-50S ELP-
+60W TUS-30NW ABQ
+AREA SEV TS MOV FROM 24025KT. TOPS ABV FL450.
+HAIL TO 1.5 IN...WIND GUSTS TO 60KT POSS.
+
+OUTLOOK VALID 182055-190055
+FROM 40N DEN-GLD-TCC-40N DEN
+WST ISSUANCES POSS. REFER TO MOST RECENT ACUS01 KWNS.
+";
+
+    #[test]
+    fn test_parse_convective_sigmet() {
+        let advisory: DecodedAdvisory = CONVECTIVE_SIGMET.parse().unwrap();
+        assert_eq!(advisory.kind, AdvisoryKind::ConvectiveSigmet);
+        assert_eq!(advisory.id, "45W");
+        assert_eq!(advisory.valid_until.as_deref(), Some("2055Z"));
+        assert_eq!(advisory.states, ["NM", "AZ"]);
+        assert_eq!(advisory.hazards, [AviationHazard::Thunderstorm]);
+        assert_eq!(
+            advisory.altitude,
+            Some(AltitudeRange {
+                top_feet: Some(45_000),
+                tops_above: true,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            advisory.movement,
+            Some(Movement::Moving {
+                from_degrees: 240,
+                speed_knots: 25,
+            })
+        );
+        assert_eq!(
+            advisory
+                .vertices
+                .iter()
+                .map(|vertex| vertex.description.as_str())
+                .collect::<Vec<_>>(),
+            ["30NW ABQ", "40E TCC", "50S ELP", "60W TUS", "30NW ABQ"]
+        );
+
+        let positions = advisory.positions().unwrap();
+        let abq = navaid::lookup("ABQ").unwrap().position;
+        assert!(positions[0].latitude > abq.latitude + 0.3);
+        assert!(positions[0].longitude < abq.longitude - 0.4);
+        assert_eq!(positions[0], positions[4]);
+    }
+
+    #[test]
+    fn test_parse_domestic_sigmet() {
+        let text = "
+WSUS05 KKCI 181530
+SLCR WS 181530
+SIGMET ROMEO 2 VALID UNTIL 181930
+WY CO
+FROM 30NW CZI TO 40E DEN TO 30SW HBU TO
+40SE OCS TO 30NW CZI
+OCNL SEV TURB BTN FL250 AND FL380. DUE TO WNDSHR ASSOCD WITH JTST. CONDS CONTG BYD 1930Z.
+";
+        let advisory: DecodedAdvisory = text.parse().unwrap();
+        assert_eq!(advisory.kind, AdvisoryKind::Sigmet);
+        assert_eq!(advisory.id, "ROMEO 2");
+        assert_eq!(advisory.valid_until.as_deref(), Some("181930"));
+        assert_eq!(advisory.states, ["WY", "CO"]);
+        assert_eq!(advisory.hazards, [AviationHazard::Turbulence]);
+        assert_eq!(advisory.altitude.unwrap().to_string(), "FL250-FL380");
+        assert_eq!(advisory.vertices.len(), 5);
+        assert_eq!(advisory.vertices[3].description, "40SE OCS");
+        assert!(advisory.positions().is_some());
+    }
+
+    #[test]
+    fn test_parse_international_sigmet() {
+        let text = "KZAB SIGMET ROMEO 2 VALID 181200/181600 KKCI-\nKZAB ALBUQUERQUE FIR SEV TURB FCST WI N3200 W11000 - N3300 W10900 -\nN3130 W10800 - N3200 W11000. FL250/380. MOV NE 25KT. NC.";
+        let advisory: DecodedAdvisory = text.parse().unwrap();
+        assert_eq!(advisory.kind, AdvisoryKind::Sigmet);
+        assert_eq!(advisory.valid_until.as_deref(), Some("181600"));
+        assert!(advisory.states.is_empty());
+        assert_eq!(advisory.hazards, [AviationHazard::Turbulence]);
+        assert_eq!(
+            advisory.altitude,
+            Some(AltitudeRange {
+                base_feet: Some(25_000),
+                top_feet: Some(38_000),
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            advisory.movement,
+            Some(Movement::Moving {
+                from_degrees: 225,
+                speed_knots: 25,
+            })
+        );
+        assert_eq!(advisory.intensity, Some(IntensityTrend::NoChange));
+        assert_eq!(advisory.vertices.len(), 4);
+        assert_eq!(
+            advisory.vertices[2].position,
+            Some(LatLon {
+                latitude: 31.5,
+                longitude: -108.0,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_center_weather_advisory() {
+        let text = "
+ZAB1 CWA 181845
+ZAB CWA 101 VALID UNTIL 182045
+FROM 20S ABQ
+ISOL SEV TS D30 MOV LTL. TOPS TO FL410. INTSF.
+";
+        let advisory: DecodedAdvisory = text.parse().unwrap();
+        assert_eq!(advisory.kind, AdvisoryKind::CenterWeatherAdvisory);
+        assert_eq!(advisory.id, "101");
+        assert_eq!(advisory.valid_until.as_deref(), Some("182045"));
+        assert_eq!(advisory.movement, Some(Movement::Little));
+        assert_eq!(advisory.intensity, Some(IntensityTrend::Intensifying));
+        assert_eq!(advisory.diameter_nautical_miles, Some(30));
+        assert_eq!(advisory.altitude.unwrap().to_string(), "BLW FL410");
+        assert_eq!(advisory.vertices.len(), 1);
+
+        let text = "ZLA CWA 203 VALID UNTIL 190300\nFROM 20NW BLH-40E TRM\nLINE TS 20 NM WIDE MOV FROM 18015KT. WKN.";
+        let advisory: DecodedAdvisory = text.parse().unwrap();
+        assert_eq!(advisory.width_nautical_miles, Some(20));
+        assert_eq!(advisory.intensity, Some(IntensityTrend::Weakening));
+        assert_eq!(advisory.vertices.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_icing_and_ifr() {
+        let text = "ZDV CWA 102 VALID UNTIL 190100\nFROM DEN-GLD-PUB-DEN\nMOD TO SEV ICE BTN FRZLVL AND FL220. IFR CONDS IN -SN. MTN OBSCN.";
+        let advisory: DecodedAdvisory = text.parse().unwrap();
+        assert_eq!(
+            advisory.hazards,
+            [
+                AviationHazard::Icing,
+                AviationHazard::Ifr,
+                AviationHazard::MountainObscuration
+            ]
+        );
+        assert_eq!(
+            advisory.altitude,
+            Some(AltitudeRange {
+                base_feet: None,
+                base_freezing_level: true,
+                top_feet: Some(22_000),
+                tops_above: false,
+            })
+        );
+        assert_eq!(advisory.altitude.unwrap().to_string(), "FRZLVL-FL220");
+    }
+
+    #[test]
+    fn test_resolve_intercardinal_points() {
+        let origin = LatLon {
+            latitude: 35.0,
+            longitude: -110.0,
+        };
+        let lookup = |id: &str| (id == "XYZ").then_some(origin);
+        let north_northeast = resolve_point("30NNE XYZ", &lookup).unwrap();
+        assert_eq!(north_northeast, navaid::destination(origin, 22.5, 30.0));
+        assert!(north_northeast.latitude > origin.latitude + 0.45);
+        assert!(north_northeast.longitude > origin.longitude + 0.2);
+
+        let west_southwest = resolve_point("30WSW XYZ", &lookup).unwrap();
+        assert_eq!(west_southwest, navaid::destination(origin, 247.5, 30.0));
+
+        let advisory: DecodedAdvisory = "ZAB CWA 101 VALID UNTIL 182045\nFROM 30NNE ABQ-ABQ-TCC\nSEV TURB BLW FL180. MOV SSW 10KT."
+            .parse()
+            .unwrap();
+        let abq = navaid::lookup("ABQ").unwrap().position;
+        assert_eq!(
+            advisory.vertices[0].position,
+            Some(navaid::destination(abq, 22.5, 30.0))
+        );
+        assert_eq!(
+            advisory.movement,
+            Some(Movement::Moving {
+                from_degrees: 23,
+                speed_knots: 10,
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_with_navaids() {
+        let text = "ZAB CWA 101 VALID UNTIL 182045\nFROM XYZ-60N XYZ-ABQ\nSEV TURB BLW FL180.";
+        let origin = LatLon {
+            latitude: 35.0,
+            longitude: -110.0,
+        };
+        let advisory =
+            DecodedAdvisory::parse_with_navaids(text, |id| (id == "XYZ").then_some(origin))
+                .unwrap();
+        assert_eq!(advisory.vertices[0].position, Some(origin));
+        let north = advisory.vertices[1].position.unwrap();
+        assert!((north.latitude - 36.0).abs() < 0.01);
+        assert!((north.longitude - origin.longitude).abs() < 0.01);
+        assert_eq!(advisory.vertices[2].position, None);
+        assert_eq!(advisory.positions(), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(
+            "METAR KPHX 181551Z 27005KT 10SM CLR"
+                .parse::<DecodedAdvisory>()
+                .is_err()
+        );
+        assert!("ZAB1 CWA 181845".parse::<DecodedAdvisory>().is_err());
+    }
+}
@@ -0,0 +1,782 @@
+//! A simplified, pilot-friendly view of Terminal Aerodrome Forecasts (TAFs).
+//!
+//! [`TerminalAerodromeForecast`] mirrors the IWXXM XML returned by the
+//! `/stations/{stationId}/tafs/{date}/{time}` endpoint element for element.
+//! [`DecodedTaf`] flattens it into the parts a pilot reads off a TAF: the
+//! station, issue time, validity, the base conditions, and the ordered
+//! `FM`/`BECMG`/`TEMPO`/`PROB` change groups. Winds are given in knots,
+//! visibility in statute miles, and cloud bases in feet.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::taf::{CloudCover, CloudLayer, Visibility, Wind};
+//!
+//! let wind = Wind {
+//!     direction: Some(270),
+//!     speed_knots: 15.0,
+//!     gust_knots: Some(25.0),
+//! };
+//! assert_eq!(wind.to_string(), "27015G25KT");
+//!
+//! let visibility = Visibility {
+//!     statute_miles: 6.0,
+//!     greater_than: true,
+//!     less_than: false,
+//! };
+//! assert_eq!(visibility.to_string(), "P6SM");
+//!
+//! let layer = CloudLayer {
+//!     cover: CloudCover::Broken,
+//!     base_feet: Some(5000.0),
+//! };
+//! assert_eq!(layer.to_string(), "BKN050");
+//! assert!(layer.cover.is_ceiling());
+//! ```
+
+use std::fmt;
+
+use jiff::Timestamp;
+use serde::{Deserialize, Serialize};
+
+use crate::flight_category::FlightCategory;
+use crate::models::TerminalAerodromeForecast;
+use crate::models::terminal_aerodrome_forecast::{
+    BaseForecastMeteorologicalAerodromeForecast, ChangeForecastMeteorologicalAerodromeForecast,
+    Weather,
+};
+use crate::utils::units::{KNOTS_PER_METER_PER_SECOND, METERS_PER_FOOT, METERS_PER_STATUTE_MILE};
+
+const KNOTS_PER_KILOMETER_PER_HOUR: f64 = 0.539_957;
+
+/// The visibility IWXXM reports for "10 km or more", which US TAFs encode as `P6SM`.
+const UNLIMITED_VISIBILITY_METERS: f64 = 9999.0;
+
+/// A decoded Terminal Aerodrome Forecast.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecodedTaf {
+    /// The ICAO identifier of the aerodrome (e.g., "KPHX").
+    pub station: String,
+    pub issue_time: Option<Timestamp>,
+    pub valid_from: Option<Timestamp>,
+    pub valid_to: Option<Timestamp>,
+    /// The conditions forecast from the start of the validity period.
+    pub base: TafConditions,
+    /// The change groups in the order they appear in the forecast.
+    pub changes: Vec<TafChangeGroup>,
+}
+
+impl DecodedTaf {
+    /// Returns the groups that apply at `time`: the base conditions or the
+    /// latest `FM` group in force, followed by any other change groups whose
+    /// period contains `time`.
+    pub fn groups_at(&self, time: Timestamp) -> Vec<&TafConditions> {
+        let mut prevailing = &self.base;
+        let mut groups = Vec::new();
+        for change in &self.changes {
+            let started = change.from.is_some_and(|from| from <= time);
+            let ended = change.to.is_some_and(|to| to <= time);
+            if change.kind == TafChangeKind::From {
+                if started {
+                    prevailing = &change.conditions;
+                    groups.clear();
+                }
+            } else if started && !ended {
+                groups.push(&change.conditions);
+            }
+        }
+        groups.insert(0, prevailing);
+        groups
+    }
+
+    /// Returns the flight category of the base conditions followed by that of each change group.
+    ///
+    /// `BECMG`, `TEMPO`, and `PROB` groups take the visibility and clouds they
+    /// do not forecast from the prevailing base or `FM` conditions.
+    pub fn flight_categories(&self) -> Vec<Option<FlightCategory>> {
+        let mut prevailing = &self.base;
+        let mut categories = vec![self.base.flight_category()];
+        for change in &self.changes {
+            if change.kind == TafChangeKind::From {
+                prevailing = &change.conditions;
+                categories.push(change.conditions.flight_category());
+            } else {
+                categories.push(
+                    change
+                        .conditions
+                        .with_prevailing(prevailing)
+                        .flight_category(),
+                );
+            }
+        }
+        categories
+    }
+}
+
+impl From<&TerminalAerodromeForecast> for DecodedTaf {
+    fn from(bulletin: &TerminalAerodromeForecast) -> Self {
+        let taf = &bulletin.ns0_meteorological_information.taf;
+        let valid_period = &taf.valid_period.ns1_time_period;
+        let changes = taf
+            .change_forecast
+            .iter()
+            .filter_map(|change| {
+                TafChangeGroup::try_from_iwxxm(&change.meteorological_aerodrome_forecast)
+            })
+            .collect();
+        DecodedTaf {
+            station: taf
+                .aerodrome
+                .aixm_airport_heliport
+                .aixm_time_slice
+                .aixm_airport_heliport_time_slice
+                .aixm_location_indicator_icao
+                .clone(),
+            issue_time: parse_time(&taf.issue_time.ns1_time_instant.ns1_time_position),
+            valid_from: parse_time(&valid_period.ns1_begin_position),
+            valid_to: parse_time(&valid_period.ns1_end_position),
+            base: TafConditions::from(&taf.base_forecast.meteorological_aerodrome_forecast),
+            changes,
+        }
+    }
+}
+
+/// Returns the flight category of the base conditions followed by one entry for
+/// each `changeForecast` in `bulletin`, in document order.
+///
+/// The categories are those of [`DecodedTaf::flight_categories`], realigned with
+/// the IWXXM change forecasts: a change group with an unknown change indicator,
+/// which [`DecodedTaf`] leaves out, gets `None`.
+pub fn change_forecast_flight_categories(
+    bulletin: &TerminalAerodromeForecast,
+) -> Vec<Option<FlightCategory>> {
+    let taf = &bulletin.ns0_meteorological_information.taf;
+    let mut decoded = DecodedTaf::from(bulletin).flight_categories().into_iter();
+    let mut categories = vec![decoded.next().flatten()];
+    categories.extend(taf.change_forecast.iter().map(|change| {
+        TafChangeKind::from_indicator(&change.meteorological_aerodrome_forecast.change_indicator)
+            .and_then(|_| decoded.next().flatten())
+    }));
+    categories
+}
+
+impl From<TerminalAerodromeForecast> for DecodedTaf {
+    fn from(bulletin: TerminalAerodromeForecast) -> Self {
+        DecodedTaf::from(&bulletin)
+    }
+}
+
+/// A forecast change group (e.g., `FM021800` or `TEMPO 0220/0224`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TafChangeGroup {
+    pub kind: TafChangeKind,
+    pub from: Option<Timestamp>,
+    pub to: Option<Timestamp>,
+    pub conditions: TafConditions,
+}
+
+impl TafChangeGroup {
+    /// Converts an IWXXM change forecast, returning `None` for an unknown change indicator.
+    fn try_from_iwxxm(forecast: &ChangeForecastMeteorologicalAerodromeForecast) -> Option<Self> {
+        let period = &forecast.phenomenon_time.ns1_time_period;
+        Some(TafChangeGroup {
+            kind: TafChangeKind::from_indicator(&forecast.change_indicator)?,
+            from: parse_time(&period.ns1_begin_position),
+            to: parse_time(&period.ns1_end_position),
+            conditions: TafConditions::from(forecast),
+        })
+    }
+}
+
+/// The type of a TAF change group.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum TafChangeKind {
+    /// `FM`: conditions change rapidly and replace all earlier groups.
+    From,
+    /// `BECMG`: conditions change gradually over the period.
+    Becoming,
+    /// `TEMPO`: temporary fluctuations lasting less than an hour at a time.
+    Temporary,
+    /// `PROBnn`: conditions with a 30 or 40 percent probability, optionally temporary.
+    Probability { percent: u8, temporary: bool },
+}
+
+impl TafChangeKind {
+    /// Parses an IWXXM `changeIndicator` value (e.g., "TEMPORARY_FLUCTUATIONS").
+    pub fn from_indicator(indicator: &str) -> Option<Self> {
+        match indicator {
+            "FROM" => Some(Self::From),
+            "BECOMING" => Some(Self::Becoming),
+            "TEMPORARY_FLUCTUATIONS" => Some(Self::Temporary),
+            _ => {
+                let rest = indicator.strip_prefix("PROBABILITY_")?;
+                let (percent, temporary) = match rest.split_once('_') {
+                    Some((percent, "TEMPORARY_FLUCTUATIONS")) => (percent, true),
+                    Some(_) => return None,
+                    None => (rest, false),
+                };
+                Some(Self::Probability {
+                    percent: percent.parse().ok()?,
+                    temporary,
+                })
+            }
+        }
+    }
+}
+
+impl fmt::Display for TafChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::From => f.write_str("FM"),
+            Self::Becoming => f.write_str("BECMG"),
+            Self::Temporary => f.write_str("TEMPO"),
+            Self::Probability {
+                percent,
+                temporary: false,
+            } => write!(f, "PROB{percent}"),
+            Self::Probability {
+                percent,
+                temporary: true,
+            } => write!(f, "PROB{percent} TEMPO"),
+        }
+    }
+}
+
+/// The conditions forecast for the base period or a change group.
+///
+/// Elements a change group does not mention are left empty, since they carry
+/// over from the earlier groups.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TafConditions {
+    pub wind: Option<Wind>,
+    pub visibility: Option<Visibility>,
+    /// Weather phenomena as TAF codes (e.g., "-SHRA", "VCTS").
+    pub weather: Vec<String>,
+    pub clouds: Vec<CloudLayer>,
+    /// Whether `CAVOK` (ceiling and visibility OK) was forecast.
+    pub cavok: bool,
+}
+
+impl TafConditions {
+    /// Returns the lowest broken or overcast layer, or vertical visibility.
+    pub fn ceiling(&self) -> Option<&CloudLayer> {
+        self.clouds.iter().find(|layer| layer.cover.is_ceiling())
+    }
+
+    /// Returns the flight category for the forecast ceiling and visibility.
+    ///
+    /// `CAVOK` is always `VFR`. Returns `None` when the group forecasts
+    /// neither clouds nor a visibility.
+    pub fn flight_category(&self) -> Option<FlightCategory> {
+        if self.cavok {
+            return Some(FlightCategory::Vfr);
+        }
+        if self.clouds.is_empty() && self.visibility.is_none() {
+            return None;
+        }
+        Some(FlightCategory::from_ceiling_and_visibility(
+            self.ceiling().and_then(|layer| layer.base_feet),
+            self.visibility
+                .filter(|visibility| !visibility.greater_than)
+                .map(|visibility| visibility.statute_miles),
+        ))
+    }
+
+    /// Returns these conditions with the elements they do not forecast taken from `prevailing`.
+    pub fn with_prevailing(&self, prevailing: &TafConditions) -> TafConditions {
+        let forecasts_sky = self.cavok || !self.clouds.is_empty();
+        TafConditions {
+            wind: self.wind.or(prevailing.wind),
+            visibility: if self.cavok {
+                None
+            } else {
+                self.visibility.or(prevailing.visibility)
+            },
+            weather: if self.weather.is_empty() {
+                prevailing.weather.clone()
+            } else {
+                self.weather.clone()
+            },
+            clouds: if forecasts_sky {
+                self.clouds.clone()
+            } else {
+                prevailing.clouds.clone()
+            },
+            cavok: self.cavok || (prevailing.cavok && !forecasts_sky && self.visibility.is_none()),
+        }
+    }
+}
+
+/// Builds `TafConditions` from the base and change forecasts, whose IWXXM
+/// mirrors have identical fields but distinct types.
+macro_rules! impl_conditions_from {
+    ($forecast:ty) => {
+        impl From<&$forecast> for TafConditions {
+            fn from(forecast: &$forecast) -> Self {
+                TafConditions {
+                    wind: forecast.surface_wind.as_ref().and_then(|surface_wind| {
+                        let wind = &surface_wind.aerodrome_surface_wind_forecast;
+                        let speed = wind.mean_wind_speed.as_ref()?;
+                        Some(Wind {
+                            direction: (wind.variable_wind_direction != "true")
+                                .then(|| {
+                                    wind.mean_wind_direction
+                                        .as_ref()
+                                        .and_then(|direction| direction.text.as_deref())
+                                        .and_then(|direction| direction.trim().parse::<f64>().ok())
+                                        .map(|direction| direction.round() as u16)
+                                })
+                                .flatten(),
+                            speed_knots: to_knots(speed.text.as_deref()?, &speed.uom)?,
+                            gust_knots: wind
+                                .wind_gust_speed
+                                .as_ref()
+                                .and_then(|gust| to_knots(gust.text.as_deref()?, &gust.uom)),
+                        })
+                    }),
+                    visibility: forecast
+                        .prevailing_visibility
+                        .as_ref()
+                        .and_then(|visibility| {
+                            Visibility::from_measurement(
+                                visibility.text.as_deref()?,
+                                &visibility.uom,
+                                forecast.prevailing_visibility_operator.as_deref(),
+                            )
+                        }),
+                    weather: weather_codes(forecast.weather.as_deref()),
+                    clouds: forecast
+                        .cloud
+                        .iter()
+                        .flat_map(|cloud| &cloud.aerodrome_cloud_forecast.layer)
+                        .filter_map(|layer| {
+                            let layer = &layer.cloud_layer;
+                            Some(CloudLayer {
+                                cover: CloudCover::from_code(last_segment(
+                                    &layer.amount.xlink_href,
+                                ))?,
+                                base_feet: layer
+                                    .base
+                                    .text
+                                    .as_deref()
+                                    .and_then(|base| to_feet(base, &layer.base.uom)),
+                            })
+                        })
+                        .collect(),
+                    cavok: forecast.cloud_and_visibility_ok == "true",
+                }
+            }
+        }
+    };
+}
+
+impl_conditions_from!(BaseForecastMeteorologicalAerodromeForecast);
+impl_conditions_from!(ChangeForecastMeteorologicalAerodromeForecast);
+
+/// A forecast surface wind.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Wind {
+    /// The direction the wind is blowing from in degrees true, or `None` when variable.
+    pub direction: Option<u16>,
+    pub speed_knots: f64,
+    pub gust_knots: Option<f64>,
+}
+
+impl Wind {
+    /// Returns `true` for a calm wind (`00000KT`).
+    pub fn is_calm(&self) -> bool {
+        self.speed_knots.round() == 0.0
+    }
+}
+
+impl fmt::Display for Wind {
+    /// Formats the wind as a TAF wind group (e.g., "27015G25KT" or "VRB03KT").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_calm() {
+            return f.write_str("00000KT");
+        }
+        match self.direction {
+            Some(direction) => write!(f, "{direction:03}")?,
+            None => f.write_str("VRB")?,
+        }
+        write!(f, "{:02}", self.speed_knots.round() as u32)?;
+        if let Some(gust) = self.gust_knots {
+            write!(f, "G{:02}", gust.round() as u32)?;
+        }
+        f.write_str("KT")
+    }
+}
+
+/// A forecast prevailing visibility.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Visibility {
+    pub statute_miles: f64,
+    /// Whether the visibility is greater than `statute_miles` (e.g., `P6SM`).
+    pub greater_than: bool,
+    /// Whether the visibility is less than `statute_miles` (e.g., `M1/4SM`).
+    pub less_than: bool,
+}
+
+impl Visibility {
+    /// Converts a visibility measurement with an IWXXM unit and optional `ABOVE`/`BELOW` operator.
+    pub(crate) fn from_measurement(value: &str, uom: &str, operator: Option<&str>) -> Option<Self> {
+        let value: f64 = value.trim().parse().ok()?;
+        let meters = match uom {
+            "m" => value,
+            "km" => value * 1000.0,
+            "[mi_i]" | "[smi_us]" | "mi" => value * METERS_PER_STATUTE_MILE,
+            _ => return None,
+        };
+        if meters >= UNLIMITED_VISIBILITY_METERS || operator == Some("ABOVE") {
+            let statute_miles = (meters / METERS_PER_STATUTE_MILE).min(6.0);
+            return Some(Visibility {
+                statute_miles: statute_miles.floor(),
+                greater_than: true,
+                less_than: false,
+            });
+        }
+        // IWXXM gives metric equivalents of the reported miles (e.g., 4800 m for
+        // 3SM), so round back to the nearest sixteenth, the finest reportable step.
+        Some(Visibility {
+            statute_miles: (meters / METERS_PER_STATUTE_MILE * 16.0).round() / 16.0,
+            greater_than: false,
+            less_than: operator == Some("BELOW"),
+        })
+    }
+}
+
+impl fmt::Display for Visibility {
+    /// Formats the visibility as a TAF visibility group (e.g., "P6SM", "1 1/2SM" or "M1/4SM").
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.greater_than {
+            f.write_str("P")?;
+        } else if self.less_than {
+            f.write_str("M")?;
+        }
+        // Sixteenths, the step `from_measurement` rounds to, reduced to lowest terms.
+        let sixteenths = (self.statute_miles * 16.0).round() as u32;
+        let (whole, mut numerator, mut denominator) = (sixteenths / 16, sixteenths % 16, 16);
+        while numerator > 0 && numerator % 2 == 0 {
+            numerator /= 2;
+            denominator /= 2;
+        }
+        match (whole, numerator) {
+            (whole, 0) => write!(f, "{whole}SM"),
+            (0, numerator) => write!(f, "{numerator}/{denominator}SM"),
+            (whole, numerator) => write!(f, "{whole} {numerator}/{denominator}SM"),
+        }
+    }
+}
+
+/// A forecast cloud layer.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CloudLayer {
+    pub cover: CloudCover,
+    /// The height of the layer base above ground level.
+    pub base_feet: Option<f64>,
+}
+
+impl fmt::Display for CloudLayer {
+    /// Formats the layer as a TAF cloud group (e.g., "BKN050"), with the base in hundreds of feet.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.cover.code())?;
+        match self.base_feet {
+            Some(base) if self.cover.has_base() => {
+                write!(f, "{:03}", (base / 100.0).round() as u32)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// The sky cover of a cloud layer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum CloudCover {
+    SkyClear,
+    Clear,
+    NoSignificantCloud,
+    Few,
+    Scattered,
+    Broken,
+    Overcast,
+    VerticalVisibility,
+}
+
+impl CloudCover {
+    /// Parses a cloud cover code (e.g., "BKN").
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "SKC" => Some(Self::SkyClear),
+            "CLR" => Some(Self::Clear),
+            "NSC" => Some(Self::NoSignificantCloud),
+            "FEW" => Some(Self::Few),
+            "SCT" => Some(Self::Scattered),
+            "BKN" => Some(Self::Broken),
+            "OVC" => Some(Self::Overcast),
+            "VV" => Some(Self::VerticalVisibility),
+            _ => None,
+        }
+    }
+
+    /// Returns the cloud cover code (e.g., "BKN").
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::SkyClear => "SKC",
+            Self::Clear => "CLR",
+            Self::NoSignificantCloud => "NSC",
+            Self::Few => "FEW",
+            Self::Scattered => "SCT",
+            Self::Broken => "BKN",
+            Self::Overcast => "OVC",
+            Self::VerticalVisibility => "VV",
+        }
+    }
+
+    /// Returns `true` for the covers that form a ceiling: broken, overcast, and vertical visibility.
+    pub fn is_ceiling(&self) -> bool {
+        matches!(
+            self,
+            Self::Broken | Self::Overcast | Self::VerticalVisibility
+        )
+    }
+
+    /// Returns `true` for the covers reported with a base height.
+    fn has_base(&self) -> bool {
+        matches!(
+            self,
+            Self::Few | Self::Scattered | Self::Broken | Self::Overcast | Self::VerticalVisibility
+        )
+    }
+}
+
+impl fmt::Display for CloudCover {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+fn parse_time(time: &str) -> Option<Timestamp> {
+    time.parse().ok()
+}
+
+/// Returns the final path segment of a WMO code registry link.
+fn last_segment(href: &str) -> &str {
+    href.rsplit('/').next().unwrap_or(href)
+}
+
+fn weather_codes(weather: Option<&[Weather]>) -> Vec<String> {
+    weather
+        .into_iter()
+        .flatten()
+        .map(|weather| last_segment(&weather.xlink_href).to_owned())
+        .filter(|code| !code.is_empty())
+        .collect()
+}
+
+fn to_knots(value: &str, uom: &str) -> Option<f64> {
+    let value: f64 = value.trim().parse().ok()?;
+    match uom {
+        "[kn_i]" | "kt" => Some(value),
+        "m/s" | "m_s-1" => Some(value * KNOTS_PER_METER_PER_SECOND),
+        "km/h" | "km_h-1" => Some(value * KNOTS_PER_KILOMETER_PER_HOUR),
+        _ => None,
+    }
+}
+
+fn to_feet(value: &str, uom: &str) -> Option<f64> {
+    let value: f64 = value.trim().parse().ok()?;
+    match uom {
+        "[ft_i]" | "ft" => Some(value),
+        "m" => Some(value / METERS_PER_FOOT),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TAF: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<ns0:MeteorologicalBulletin xmlns:ns0="http://def.wmo.int/collect/2014" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xmlns:ns1="http://www.opengis.net/gml/3.2" xsi:schemaLocation="http://def.wmo.int/collect/2014" ns1:id="bulletin">
+  <ns0:meteorologicalInformation>
+    <TAF xmlns:aixm="http://www.aixm.aero/schema/5.1.1" xmlns="http://icao.int/iwxxm/3.0" xmlns:xlink="http://www.w3.org/1999/xlink" xsi:schemaLocation="http://icao.int/iwxxm/3.0" reportStatus="NORMAL" permissibleUsage="OPERATIONAL" ns1:id="taf">
+      <issueTime><ns1:TimeInstant ns1:id="ti"><ns1:timePosition>2025-06-01T17:20:00Z</ns1:timePosition></ns1:TimeInstant></issueTime>
+      <aerodrome>
+        <aixm:AirportHeliport ns1:id="ah">
+          <aixm:timeSlice>
+            <aixm:AirportHeliportTimeSlice ns1:id="ts">
+              <ns1:validTime/>
+              <aixm:interpretation>SNAPSHOT</aixm:interpretation>
+              <aixm:designator>PHX</aixm:designator>
+              <aixm:locationIndicatorICAO>KPHX</aixm:locationIndicatorICAO>
+              <aixm:ARP><aixm:ElevatedPoint srsDimension="3" srsName="crs" axisLabels="Lat Long Altitude" ns1:id="ep"><ns1:pos>33.43 -112.02 337</ns1:pos></aixm:ElevatedPoint></aixm:ARP>
+            </aixm:AirportHeliportTimeSlice>
+          </aixm:timeSlice>
+        </aixm:AirportHeliport>
+      </aerodrome>
+      <validPeriod><ns1:TimePeriod ns1:id="vp"><ns1:beginPosition>2025-06-01T18:00:00Z</ns1:beginPosition><ns1:endPosition>2025-06-03T00:00:00Z</ns1:endPosition></ns1:TimePeriod></validPeriod>
+      <baseForecast>
+        <MeteorologicalAerodromeForecast cloudAndVisibilityOK="false" ns1:id="base">
+          <phenomenonTime><ns1:TimePeriod ns1:id="bp"><ns1:beginPosition>2025-06-01T18:00:00Z</ns1:beginPosition><ns1:endPosition>2025-06-01T22:00:00Z</ns1:endPosition></ns1:TimePeriod></phenomenonTime>
+          <prevailingVisibility uom="m">9999</prevailingVisibility>
+          <prevailingVisibilityOperator>ABOVE</prevailingVisibilityOperator>
+          <surfaceWind>
+            <AerodromeSurfaceWindForecast variableWindDirection="false">
+              <meanWindDirection uom="deg">270</meanWindDirection>
+              <meanWindSpeed uom="[kn_i]">15</meanWindSpeed>
+              <windGustSpeed uom="[kn_i]">25</windGustSpeed>
+            </AerodromeSurfaceWindForecast>
+          </surfaceWind>
+          <cloud>
+            <AerodromeCloudForecast ns1:id="bc">
+              <layer><CloudLayer><amount xlink:href="http://codes.wmo.int/49-2/CloudAmountReportedAtAerodrome/FEW"/><base uom="[ft_i]">8000</base></CloudLayer></layer>
+              <layer><CloudLayer><amount xlink:href="http://codes.wmo.int/49-2/CloudAmountReportedAtAerodrome/BKN"/><base uom="[ft_i]">15000</base></CloudLayer></layer>
+            </AerodromeCloudForecast>
+          </cloud>
+        </MeteorologicalAerodromeForecast>
+      </baseForecast>
+      <changeForecast>
+        <MeteorologicalAerodromeForecast cloudAndVisibilityOK="false" changeIndicator="PROBABILITY_30_TEMPORARY_FLUCTUATIONS" ns1:id="c1">
+          <phenomenonTime><ns1:TimePeriod ns1:id="c1p"><ns1:beginPosition>2025-06-01T20:00:00Z</ns1:beginPosition><ns1:endPosition>2025-06-01T22:00:00Z</ns1:endPosition></ns1:TimePeriod></phenomenonTime>
+          <prevailingVisibility uom="m">4800</prevailingVisibility>
+          <weather xlink:href="http://codes.wmo.int/306/4678/TSRA"/>
+          <cloud>
+            <AerodromeCloudForecast ns1:id="c1c">
+              <layer><CloudLayer><amount xlink:href="http://codes.wmo.int/49-2/CloudAmountReportedAtAerodrome/OVC"/><base uom="[ft_i]">4000</base></CloudLayer></layer>
+            </AerodromeCloudForecast>
+          </cloud>
+        </MeteorologicalAerodromeForecast>
+      </changeForecast>
+      <changeForecast>
+        <MeteorologicalAerodromeForecast cloudAndVisibilityOK="true" changeIndicator="FROM" ns1:id="c2">
+          <phenomenonTime><ns1:TimePeriod ns1:id="c2p"><ns1:beginPosition>2025-06-01T22:00:00Z</ns1:beginPosition><ns1:endPosition>2025-06-03T00:00:00Z</ns1:endPosition></ns1:TimePeriod></phenomenonTime>
+          <surfaceWind>
+            <AerodromeSurfaceWindForecast variableWindDirection="true">
+              <meanWindSpeed uom="m/s">2</meanWindSpeed>
+            </AerodromeSurfaceWindForecast>
+          </surfaceWind>
+        </MeteorologicalAerodromeForecast>
+      </changeForecast>
+    </TAF>
+  </ns0:meteorologicalInformation>
+  <ns0:bulletinIdentifier>A_LTUS41KPSR011720_C_KWIN</ns0:bulletinIdentifier>
+</ns0:MeteorologicalBulletin>"#;
+
+    fn parse(xml: &str) -> TerminalAerodromeForecast {
+        let mut deserializer = quick_xml::de::Deserializer::from_str(xml);
+        TerminalAerodromeForecast::deserialize(&mut deserializer).unwrap()
+    }
+
+    fn decode() -> DecodedTaf {
+        DecodedTaf::from(parse(TAF))
+    }
+
+    #[test]
+    fn test_decode_taf() {
+        let taf = decode();
+        assert_eq!(taf.station, "KPHX");
+        assert_eq!(
+            taf.issue_time,
+            Some("2025-06-01T17:20:00Z".parse().unwrap())
+        );
+
+        let base = &taf.base;
+        assert_eq!(base.wind.unwrap().to_string(), "27015G25KT");
+        assert_eq!(base.visibility.unwrap().to_string(), "P6SM");
+        assert_eq!(base.clouds.len(), 2);
+        assert_eq!(base.ceiling().unwrap().to_string(), "BKN150");
+
+        assert_eq!(taf.changes.len(), 2);
+        let prob = &taf.changes[0];
+        assert_eq!(prob.kind.to_string(), "PROB30 TEMPO");
+        assert_eq!(prob.conditions.weather, vec!["TSRA".to_owned()]);
+        assert_eq!(prob.conditions.visibility.unwrap().to_string(), "3SM");
+        assert!(prob.conditions.wind.is_none());
+
+        let from = &taf.changes[1];
+        assert_eq!(from.kind, TafChangeKind::From);
+        assert!(from.conditions.cavok);
+        assert!(from.conditions.clouds.is_empty());
+        assert_eq!(from.conditions.wind.unwrap().to_string(), "VRB04KT");
+    }
+
+    #[test]
+    fn test_groups_at() {
+        let taf = decode();
+        let groups = taf.groups_at("2025-06-01T21:00:00Z".parse().unwrap());
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0], &taf.base);
+
+        let groups = taf.groups_at("2025-06-01T23:00:00Z".parse().unwrap());
+        assert_eq!(groups, vec![&taf.changes[1].conditions]);
+    }
+
+    #[test]
+    fn test_flight_categories() {
+        let taf = decode();
+        assert_eq!(
+            taf.flight_categories(),
+            vec![
+                Some(FlightCategory::Vfr),
+                Some(FlightCategory::Mvfr),
+                Some(FlightCategory::Vfr)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_change_forecast_flight_categories() {
+        assert_eq!(
+            change_forecast_flight_categories(&parse(TAF)),
+            decode().flight_categories()
+        );
+
+        let unknown = TAF.replace("PROBABILITY_30_TEMPORARY_FLUCTUATIONS", "SOMETIMES");
+        assert_eq!(
+            change_forecast_flight_categories(&parse(&unknown)),
+            vec![Some(FlightCategory::Vfr), None, Some(FlightCategory::Vfr)]
+        );
+    }
+
+    #[test]
+    fn test_change_kind_from_indicator() {
+        assert_eq!(
+            TafChangeKind::from_indicator("BECOMING"),
+            Some(TafChangeKind::Becoming)
+        );
+        assert_eq!(
+            TafChangeKind::from_indicator("PROBABILITY_40"),
+            Some(TafChangeKind::Probability {
+                percent: 40,
+                temporary: false
+            })
+        );
+        assert_eq!(
+            TafChangeKind::from_indicator("PROBABILITY_30_BECOMING"),
+            None
+        );
+        assert_eq!(TafChangeKind::from_indicator("SOMETIMES"), None);
+    }
+
+    #[test]
+    fn test_visibility_display() {
+        let visibility = |statute_miles| Visibility {
+            statute_miles,
+            greater_than: false,
+            less_than: false,
+        };
+        assert_eq!(visibility(0.25).to_string(), "1/4SM");
+        assert_eq!(visibility(1.5).to_string(), "1 1/2SM");
+        assert_eq!(visibility(2.0).to_string(), "2SM");
+        assert_eq!(visibility(0.0).to_string(), "0SM");
+        assert_eq!(visibility(0.0625).to_string(), "1/16SM");
+        assert_eq!(visibility(0.125).to_string(), "1/8SM");
+        assert_eq!(visibility(0.1875).to_string(), "3/16SM");
+        assert_eq!(visibility(0.3125).to_string(), "5/16SM");
+        assert_eq!(visibility(0.625).to_string(), "5/8SM");
+        assert_eq!(visibility(1.0 + 0.375).to_string(), "1 3/8SM");
+
+        let measured = Visibility::from_measurement("200", "m", None).unwrap();
+        assert_eq!(measured.to_string(), "1/8SM");
+    }
+}
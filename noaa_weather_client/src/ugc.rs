@@ -0,0 +1,476 @@
+//! Parsing for Universal Geographic Code (UGC) strings.
+//!
+//! Text products and alerts identify the counties and zones they cover with
+//! UGC strings such as `AZZ540>544-546-CAC025-011200-`. Each group starts with
+//! a two-letter state or marine area prefix and a `C` (county) or `Z` (zone)
+//! indicator, followed by three-digit numbers or `>` ranges, and the string
+//! ends with the product expiration time as `DDHHMM`.
+//!
+//! See NWS Directive 10-1702 for the full specification.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::models::NwsZoneType;
+//! use noaa_weather_client::ugc::Ugc;
+//!
+//! let ugc: Ugc = "AZZ540>542-CAC025-011200-".parse().unwrap();
+//! let ids: Vec<String> = ugc.codes.iter().map(ToString::to_string).collect();
+//! assert_eq!(ids, ["AZZ540", "AZZ541", "AZZ542", "CAC025"]);
+//! assert_eq!(ugc.codes[0].zone_type(), NwsZoneType::Forecast);
+//! assert_eq!(ugc.codes[3].zone_type(), NwsZoneType::County);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use jiff::Timestamp;
+use jiff::civil::DateTime;
+use jiff::tz::TimeZone;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+use crate::models::{NwsZoneType, StateTerritoryCode};
+
+/// Prefixes used for marine zones, which are not states or territories.
+const MARINE_AREAS: [&str; 15] = [
+    "AM", "AN", "GM", "LC", "LE", "LH", "LM", "LO", "LS", "PH", "PK", "PM", "PS", "PZ", "SL",
+];
+
+/// An error returned when a UGC string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseUgcError {
+    invalid_value: String,
+}
+
+impl ParseUgcError {
+    fn new(invalid_value: &str) -> Self {
+        Self {
+            invalid_value: invalid_value.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseUgcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid UGC value: {}", self.invalid_value)
+    }
+}
+
+impl std::error::Error for ParseUgcError {}
+
+/// The area a UGC code belongs to.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum UgcArea {
+    /// A state or territory.
+    State(StateTerritoryCode),
+    /// A coastal, offshore, or Great Lakes marine area (e.g., "PZ" or "LM").
+    Marine(&'static str),
+}
+
+impl fmt::Display for UgcArea {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::State(state) => write!(f, "{state}"),
+            Self::Marine(area) => write!(f, "{area}"),
+        }
+    }
+}
+
+impl FromStr for UgcArea {
+    type Err = ParseUgcError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if let Some(area) = MARINE_AREAS.iter().find(|area| **area == string) {
+            return Ok(Self::Marine(area));
+        }
+        string
+            .parse()
+            .map(Self::State)
+            .map_err(|_| ParseUgcError::new(string))
+    }
+}
+
+/// Whether a UGC code identifies a county or a zone.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum UgcKind {
+    /// A county, parish, or borough (`C`).
+    County,
+    /// A public forecast, fire weather, or marine zone (`Z`).
+    Zone,
+}
+
+impl UgcKind {
+    fn code(&self) -> char {
+        match self {
+            Self::County => 'C',
+            Self::Zone => 'Z',
+        }
+    }
+}
+
+/// A single county or zone identifier, such as `AZZ540` or `CAC025`.
+#[derive(
+    Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub struct UgcCode {
+    pub area: UgcArea,
+    pub kind: UgcKind,
+    /// The county FIPS number or zone number.
+    pub number: u16,
+}
+
+impl UgcCode {
+    /// Returns the zone type to use when looking this code up with
+    /// [`zones::get_zone`](crate::apis::zones::get_zone).
+    ///
+    /// Land zones are reported as [`NwsZoneType::Forecast`]. Fire weather zones
+    /// share the `Z` indicator and cannot be told apart from the code alone.
+    pub fn zone_type(&self) -> NwsZoneType {
+        match (self.kind, self.area) {
+            (UgcKind::County, _) => NwsZoneType::County,
+            (UgcKind::Zone, UgcArea::Marine(_)) => NwsZoneType::Marine,
+            (UgcKind::Zone, UgcArea::State(_)) => NwsZoneType::Forecast,
+        }
+    }
+}
+
+impl fmt::Display for UgcCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{:03}", self.area, self.kind.code(), self.number)
+    }
+}
+
+impl FromStr for UgcCode {
+    type Err = ParseUgcError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseUgcError::new(string);
+        let (prefix, number) = split_prefix(string).ok_or_else(invalid)?;
+        let (area, kind) = parse_prefix(prefix).ok_or_else(invalid)?;
+        Ok(Self {
+            area,
+            kind,
+            number: parse_number(number).ok_or_else(invalid)?,
+        })
+    }
+}
+
+/// The expiration time of a UGC string, given as day of month, hour, and minute in UTC.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct UgcExpiration {
+    pub day: i8,
+    pub hour: i8,
+    pub minute: i8,
+}
+
+impl UgcExpiration {
+    /// Resolves the expiration to a timestamp, using the product issuance time
+    /// to supply the month and year.
+    ///
+    /// An expiration day earlier than the issuance day is taken to fall in the
+    /// following month.
+    pub fn to_timestamp(&self, issued: Timestamp) -> Option<Timestamp> {
+        let issued = issued.to_zoned(TimeZone::UTC).datetime();
+        let mut date = issued.date().first_of_month();
+        if self.day < issued.day() {
+            date = date.checked_add(jiff::Span::new().months(1)).ok()?;
+        }
+        let datetime = DateTime::new(
+            date.year(),
+            date.month(),
+            self.day,
+            self.hour,
+            self.minute,
+            0,
+            0,
+        )
+        .ok()?;
+        datetime
+            .to_zoned(TimeZone::UTC)
+            .ok()
+            .map(|zoned| zoned.timestamp())
+    }
+}
+
+impl fmt::Display for UgcExpiration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}{:02}{:02}", self.day, self.hour, self.minute)
+    }
+}
+
+/// A parsed UGC string: the expanded list of codes and the expiration time.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, SerializeDisplay, DeserializeFromStr)]
+pub struct Ugc {
+    /// Every county or zone covered, with ranges expanded, in the order given.
+    pub codes: Vec<UgcCode>,
+    /// When the product segment expires, if the string included an expiration time.
+    pub expiration: Option<UgcExpiration>,
+}
+
+impl fmt::Display for Ugc {
+    /// Formats the codes back into the compact UGC form, collapsing runs of three or
+    /// more consecutive numbers into `>` ranges.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut index = 0;
+        let mut previous_prefix = None;
+        while index < self.codes.len() {
+            let code = self.codes[index];
+            let mut end = index;
+            while let Some(next) = self.codes.get(end + 1)
+                && next.area == code.area
+                && next.kind == code.kind
+                && next.number == self.codes[end].number + 1
+            {
+                end += 1;
+            }
+            if previous_prefix == Some((code.area, code.kind)) {
+                write!(f, "{:03}", code.number)?;
+            } else {
+                write!(f, "{code}")?;
+            }
+            if end - index >= 2 {
+                write!(f, ">{:03}", self.codes[end].number)?;
+            } else {
+                end = index;
+            }
+            write!(f, "-")?;
+            previous_prefix = Some((code.area, code.kind));
+            index = end + 1;
+        }
+        if let Some(expiration) = self.expiration {
+            write!(f, "{expiration}-")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Ugc {
+    type Err = ParseUgcError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseUgcError::new(string);
+        let compact: String = string.chars().filter(|c| !c.is_whitespace()).collect();
+        let mut tokens: Vec<&str> = compact.trim_end_matches('-').split('-').collect();
+        let expiration = match tokens.last() {
+            Some(last) if last.len() == 6 && last.chars().all(|c| c.is_ascii_digit()) => {
+                let expiration = parse_expiration(last).ok_or_else(invalid)?;
+                tokens.pop();
+                Some(expiration)
+            }
+            _ => None,
+        };
+
+        let mut codes = Vec::new();
+        let mut prefix = None;
+        for token in tokens {
+            let numbers = match split_prefix(token) {
+                Some((token_prefix, numbers)) => {
+                    prefix = Some(parse_prefix(token_prefix).ok_or_else(invalid)?);
+                    numbers
+                }
+                None => token,
+            };
+            let (area, kind) = prefix.ok_or_else(invalid)?;
+            let (first, last) = match numbers.split_once('>') {
+                Some((first, last)) => (parse_number(first), parse_number(last)),
+                None => (parse_number(numbers), parse_number(numbers)),
+            };
+            let (first, last) = first.zip(last).ok_or_else(invalid)?;
+            if last < first {
+                return Err(invalid());
+            }
+            codes.extend((first..=last).map(|number| UgcCode { area, kind, number }));
+        }
+        if codes.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self { codes, expiration })
+    }
+}
+
+/// Finds and parses every UGC string in a block of text, such as a text product.
+///
+/// A UGC string starts at the beginning of a line and may wrap across several
+/// lines; it ends at the line ending with the `DDHHMM-` expiration time.
+pub fn find_ugc(text: &str) -> Vec<Ugc> {
+    let mut found = Vec::new();
+    let mut pending: Option<String> = None;
+    for line in text.lines().map(str::trim) {
+        let starts_ugc = starts_ugc(line);
+        match pending.as_mut() {
+            Some(buffer) if line.ends_with('-') && !line.contains(' ') => buffer.push_str(line),
+            _ if starts_ugc && !line.contains(' ') => pending = Some(line.to_owned()),
+            _ => {
+                pending = None;
+                continue;
+            }
+        }
+        if ends_with_expiration(line)
+            && let Some(buffer) = pending.take()
+            && let Ok(ugc) = buffer.parse()
+        {
+            found.push(ugc);
+        }
+    }
+    found
+}
+
+/// Returns true if the line starts with a state or marine area prefix and ends with `-`.
+pub(crate) fn starts_ugc(line: &str) -> bool {
+    split_prefix(line)
+        .and_then(|(prefix, _)| parse_prefix(prefix))
+        .is_some()
+        && line.ends_with('-')
+}
+
+/// Returns true if the line ends with a `DDHHMM-` expiration time.
+pub(crate) fn ends_with_expiration(line: &str) -> bool {
+    line.trim_end_matches('-')
+        .rsplit('-')
+        .next()
+        .is_some_and(|last| last.len() == 6 && last.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Parses a `DDHHMM` expiration time.
+fn parse_expiration(token: &str) -> Option<UgcExpiration> {
+    let field = |range: std::ops::Range<usize>| token.get(range)?.parse::<i8>().ok();
+    Some(UgcExpiration {
+        day: field(0..2).filter(|day| (1..=31).contains(day))?,
+        hour: field(2..4).filter(|hour| (0..24).contains(hour))?,
+        minute: field(4..6).filter(|minute| (0..60).contains(minute))?,
+    })
+}
+
+/// Splits a token such as `AZZ540>544` into its `AZZ` prefix and the remainder.
+fn split_prefix(token: &str) -> Option<(&str, &str)> {
+    let prefix = token.get(..3)?;
+    prefix
+        .chars()
+        .all(|c| c.is_ascii_uppercase())
+        .then(|| (prefix, &token[3..]))
+}
+
+/// Parses a prefix such as `AZZ` into its area and kind.
+fn parse_prefix(prefix: &str) -> Option<(UgcArea, UgcKind)> {
+    let kind = match prefix.get(2..)? {
+        "C" => UgcKind::County,
+        "Z" => UgcKind::Zone,
+        _ => return None,
+    };
+    let area: UgcArea = prefix.get(..2)?.parse().ok()?;
+    if kind == UgcKind::County && matches!(area, UgcArea::Marine(_)) {
+        return None;
+    }
+    Some((area, kind))
+}
+
+/// Parses a three-digit county or zone number.
+fn parse_number(number: &str) -> Option<u16> {
+    if number.len() != 3 || !number.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    number.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(ugc: &Ugc) -> Vec<String> {
+        ugc.codes.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_ugc_with_ranges() {
+        let ugc: Ugc = "AZZ540>544-546-CAC025-011200-".parse().unwrap();
+        assert_eq!(
+            ids(&ugc),
+            [
+                "AZZ540", "AZZ541", "AZZ542", "AZZ543", "AZZ544", "AZZ546", "CAC025"
+            ]
+        );
+        assert_eq!(
+            ugc.expiration,
+            Some(UgcExpiration {
+                day: 1,
+                hour: 12,
+                minute: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_ugc_across_lines() {
+        let ugc: Ugc = "AZZ530>537-539>544-\n546-548>551-559-\n012300-"
+            .parse()
+            .unwrap();
+        assert_eq!(ugc.codes.len(), 20);
+        assert_eq!(ugc.codes.last().unwrap().to_string(), "AZZ559");
+    }
+
+    #[test]
+    fn test_parse_ugc_marine() {
+        let ugc: Ugc = "PZZ350-353-020300-".parse().unwrap();
+        assert_eq!(ugc.codes[0].area, UgcArea::Marine("PZ"));
+        assert_eq!(ugc.codes[0].zone_type(), NwsZoneType::Marine);
+    }
+
+    #[test]
+    fn test_parse_ugc_err() {
+        assert!("XXZ540-011200-".parse::<Ugc>().is_err());
+        assert!("AZQ540-011200-".parse::<Ugc>().is_err());
+        assert!("540-011200-".parse::<Ugc>().is_err());
+        assert!("AZZ544>540-011200-".parse::<Ugc>().is_err());
+        assert!("PZC350-011200-".parse::<Ugc>().is_err());
+        assert!("AZZ540-013200-".parse::<Ugc>().is_err());
+    }
+
+    #[test]
+    fn test_ugc_round_trip() {
+        let original = "AZZ540>544-546-547-CAC025-011200-";
+        let ugc: Ugc = original.parse().unwrap();
+        assert_eq!(ugc.to_string(), original);
+    }
+
+    #[test]
+    fn test_parse_ugc_code() {
+        let code: UgcCode = "CAC025".parse().unwrap();
+        assert_eq!(code.area, UgcArea::State(StateTerritoryCode::Ca));
+        assert_eq!(code.kind, UgcKind::County);
+        assert_eq!(code.number, 25);
+        assert!("CAC25".parse::<UgcCode>().is_err());
+    }
+
+    #[test]
+    fn test_expiration_to_timestamp() {
+        let expiration = UgcExpiration {
+            day: 1,
+            hour: 3,
+            minute: 0,
+        };
+        let issued: Timestamp = "2025-06-30T22:00:00Z".parse().unwrap();
+        assert_eq!(
+            expiration.to_timestamp(issued),
+            Some("2025-07-01T03:00:00Z".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_find_ugc() {
+        let text = "\
+WWUS55 KPSR 012300
+SVSPSR
+
+AZZ530>537-
+539-012345-
+
+...A SEVERE THUNDERSTORM WARNING REMAINS IN EFFECT...
+
+CAC025-020100-
+";
+        let found = find_ugc(text);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].codes.len(), 9);
+        assert_eq!(ids(&found[1]), ["CAC025"]);
+    }
+}
@@ -1,3 +1,4 @@
-//! Internal utilities for deserialization edge cases.
+//! Internal utilities for deserialization edge cases and unit conversions.
 
 pub mod serde;
+pub(crate) mod units;
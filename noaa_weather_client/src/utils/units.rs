@@ -0,0 +1,41 @@
+//! Unit conversion factors and helpers for [`ValueUnit`] measurements.
+
+use crate::models::{UnitCodeType, ValueUnit, WmoUnitCode};
+
+pub(crate) const FEET_PER_METER: f64 = 3.280_84;
+pub(crate) const HECTOPASCALS_PER_INCH_OF_MERCURY: f64 = 33.863_886;
+pub(crate) const KNOTS_PER_METER_PER_SECOND: f64 = 1.943_844;
+pub(crate) const METERS_PER_FOOT: f64 = 0.3048;
+pub(crate) const METERS_PER_NAUTICAL_MILE: f64 = 1852.0;
+pub(crate) const METERS_PER_STATUTE_MILE: f64 = 1609.344;
+
+/// Returns a measurement with a WMO unit code.
+pub(crate) fn value_unit(value: Option<f64>, unit: WmoUnitCode) -> ValueUnit {
+    ValueUnit {
+        unit_code: Some(UnitCodeType::Wmo(unit)),
+        value,
+        ..Default::default()
+    }
+}
+
+/// Sets `field` from `value` when the field is missing or has no value.
+pub(crate) fn fill(field: &mut Option<ValueUnit>, value: Option<f64>, unit: WmoUnitCode) {
+    let missing = field
+        .as_ref()
+        .is_none_or(|value_unit| value_unit.value.is_none());
+    if missing && value.is_some() {
+        *field = Some(value_unit(value, unit));
+    }
+}
+
+/// Returns a length value in meters, or `None` when it is missing or not a length.
+pub(crate) fn length_in_meters(value_unit: &ValueUnit) -> Option<f64> {
+    let value = value_unit.value?;
+    match value_unit.unit_code.as_ref()? {
+        UnitCodeType::Wmo(WmoUnitCode::Metre) => Some(value),
+        UnitCodeType::Wmo(WmoUnitCode::Kilometre) => Some(value * 1000.0),
+        UnitCodeType::Wmo(WmoUnitCode::Foot) => Some(value / FEET_PER_METER),
+        UnitCodeType::Wmo(WmoUnitCode::NauticalMile) => Some(value * METERS_PER_NAUTICAL_MILE),
+        _ => None,
+    }
+}
@@ -0,0 +1,626 @@
+//! Parsing for Valid Time Event Code (VTEC) strings.
+//!
+//! NWS watches, warnings, and advisories carry a Primary VTEC (P-VTEC) string
+//! identifying the event, and hydrologic products add a Hydrologic VTEC
+//! (H-VTEC) string describing the flood point. Both arrive untyped, either in
+//! [`Alert::parameters`](crate::models::Alert::parameters) under the `VTEC`
+//! key or embedded in [`TextProduct::product_text`](crate::models::TextProduct::product_text).
+//!
+//! See NWS Directive 10-1703 for the full specification.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::vtec::{PrimaryVtec, VtecAction, VtecSignificance};
+//!
+//! let vtec: PrimaryVtec = "/O.NEW.KPSR.FF.W.0012.250601T2300Z-250602T0200Z/"
+//!     .parse()
+//!     .unwrap();
+//! assert_eq!(vtec.action, VtecAction::New);
+//! assert_eq!(vtec.office, "KPSR");
+//! assert_eq!(vtec.phenomena.to_string(), "FF");
+//! assert_eq!(vtec.significance, VtecSignificance::Warning);
+//! assert_eq!(vtec.event_tracking_number, 12);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use jiff::Timestamp;
+use jiff::civil::DateTime;
+use jiff::tz::TimeZone;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+
+/// The time value VTEC uses for an event time that is not yet known or does not apply.
+const UNDEFINED_TIME: &str = "000000T0000Z";
+
+/// An error returned when a VTEC string or one of its fields cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseVtecError {
+    invalid_value: String,
+}
+
+impl ParseVtecError {
+    fn new(invalid_value: &str) -> Self {
+        Self {
+            invalid_value: invalid_value.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseVtecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid VTEC value: {}", self.invalid_value)
+    }
+}
+
+impl std::error::Error for ParseVtecError {}
+
+/// Defines a code enum that is serialized as, displayed as, and parsed from its VTEC code.
+macro_rules! vtec_code_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident => $code:literal, $description:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash, SerializeDisplay, DeserializeFromStr)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+        }
+
+        impl $name {
+            /// Returns the VTEC code for this value.
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $code,)+
+                }
+            }
+
+            /// Returns a human-readable description of this value.
+            pub fn description(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $description,)+
+                }
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "{}", self.code())
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseVtecError;
+
+            fn from_str(string: &str) -> Result<Self, Self::Err> {
+                match string {
+                    $($code => Ok(Self::$variant),)+
+                    _ => Err(ParseVtecError::new(string)),
+                }
+            }
+        }
+    };
+}
+
+vtec_code_enum! {
+    /// The product class (`k`) of a P-VTEC string.
+    VtecProductClass {
+        /// An operational product.
+        Operational => "O", "Operational",
+        /// A test product.
+        Test => "T", "Test",
+        /// An experimental product.
+        Experimental => "E", "Experimental",
+        /// An experimental VTEC string in an operational product.
+        ExperimentalVtec => "X", "Experimental VTEC in an operational product",
+    }
+}
+
+vtec_code_enum! {
+    /// The action (`aaa`) of a P-VTEC string, describing what this product does to the event.
+    VtecAction {
+        /// A new event.
+        New => "NEW", "New event",
+        /// The event continues unchanged.
+        Continue => "CON", "Event continued",
+        /// The event's end time was extended.
+        ExtendTime => "EXT", "Event extended in time",
+        /// The event's area was extended.
+        ExtendArea => "EXA", "Event extended in area",
+        /// The event's time and area were both extended.
+        ExtendBoth => "EXB", "Event extended in time and area",
+        /// The event was upgraded to a more severe event.
+        Upgrade => "UPG", "Event upgraded",
+        /// The event was cancelled.
+        Cancel => "CAN", "Event cancelled",
+        /// The event expired.
+        Expire => "EXP", "Event expired",
+        /// A correction to a previously issued product.
+        Correction => "COR", "Correction",
+        /// A routine product that is not tied to a hazardous event.
+        Routine => "ROU", "Routine",
+    }
+}
+
+vtec_code_enum! {
+    /// The significance (`s`) of a P-VTEC string.
+    VtecSignificance {
+        Warning => "W", "Warning",
+        Watch => "A", "Watch",
+        Advisory => "Y", "Advisory",
+        Statement => "S", "Statement",
+        Forecast => "F", "Forecast",
+        Outlook => "O", "Outlook",
+        Synopsis => "N", "Synopsis",
+    }
+}
+
+vtec_code_enum! {
+    /// The phenomena (`pp`) of a P-VTEC string.
+    VtecPhenomena {
+        Ashfall => "AF", "Ashfall",
+        AirStagnation => "AS", "Air Stagnation",
+        BeachHazard => "BH", "Beach Hazard",
+        BriskWind => "BW", "Brisk Wind",
+        Blizzard => "BZ", "Blizzard",
+        CoastalFlood => "CF", "Coastal Flood",
+        ColdWeather => "CW", "Cold Weather",
+        DebrisFlow => "DF", "Debris Flow",
+        DustStorm => "DS", "Dust Storm",
+        BlowingDust => "DU", "Blowing Dust",
+        ExtremeCold => "EC", "Extreme Cold",
+        ExcessiveHeat => "EH", "Excessive Heat",
+        ExtremeWind => "EW", "Extreme Wind",
+        ArealFlood => "FA", "Areal Flood",
+        FlashFlood => "FF", "Flash Flood",
+        DenseFog => "FG", "Dense Fog",
+        Flood => "FL", "Flood",
+        Frost => "FR", "Frost",
+        FireWeather => "FW", "Fire Weather",
+        Freeze => "FZ", "Freeze",
+        Gale => "GL", "Gale",
+        HurricaneForceWind => "HF", "Hurricane Force Wind",
+        Heat => "HT", "Heat",
+        Hurricane => "HU", "Hurricane",
+        HighWind => "HW", "High Wind",
+        Hydrologic => "HY", "Hydrologic",
+        HardFreeze => "HZ", "Hard Freeze",
+        IceStorm => "IS", "Ice Storm",
+        LakeEffectSnow => "LE", "Lake Effect Snow",
+        LowWater => "LO", "Low Water",
+        LakeshoreFlood => "LS", "Lakeshore Flood",
+        LakeWind => "LW", "Lake Wind",
+        Marine => "MA", "Marine",
+        MarineDenseFog => "MF", "Dense Fog",
+        MarineAshfall => "MH", "Ashfall",
+        MarineDenseSmoke => "MS", "Dense Smoke",
+        SmallCraftRoughBar => "RB", "Small Craft for Rough Bar",
+        RipCurrentRisk => "RP", "Rip Current Risk",
+        SmallCraft => "SC", "Small Craft",
+        HazardousSeas => "SE", "Hazardous Seas",
+        SmallCraftWinds => "SI", "Small Craft for Winds",
+        DenseSmoke => "SM", "Dense Smoke",
+        SnowSquall => "SQ", "Snow Squall",
+        Storm => "SR", "Storm",
+        StormSurge => "SS", "Storm Surge",
+        HighSurf => "SU", "High Surf",
+        SevereThunderstorm => "SV", "Severe Thunderstorm",
+        SmallCraftHazardousSeas => "SW", "Small Craft for Hazardous Seas",
+        Tornado => "TO", "Tornado",
+        TropicalStorm => "TR", "Tropical Storm",
+        Tsunami => "TS", "Tsunami",
+        Typhoon => "TY", "Typhoon",
+        HeavyFreezingSpray => "UP", "Heavy Freezing Spray",
+        WindChill => "WC", "Wind Chill",
+        Wind => "WI", "Wind",
+        WinterStorm => "WS", "Winter Storm",
+        WinterWeather => "WW", "Winter Weather",
+        ExtremeHeat => "XH", "Extreme Heat",
+        FreezingFog => "ZF", "Freezing Fog",
+        FreezingRain => "ZR", "Freezing Rain",
+        FreezingSpray => "ZY", "Freezing Spray",
+    }
+}
+
+vtec_code_enum! {
+    /// The flood severity (`s`) of an H-VTEC string.
+    FloodSeverity {
+        NoFlooding => "N", "No flooding",
+        AreaFlood => "0", "Areal flood or flash flood products",
+        Minor => "1", "Minor",
+        Moderate => "2", "Moderate",
+        Major => "3", "Major",
+        Unknown => "U", "Unknown",
+    }
+}
+
+vtec_code_enum! {
+    /// The immediate cause (`ic`) of an H-VTEC string.
+    FloodImmediateCause {
+        ExcessiveRainfall => "ER", "Excessive rainfall",
+        Snowmelt => "SM", "Snowmelt",
+        RainAndSnowmelt => "RS", "Rain and snowmelt",
+        IceJam => "IJ", "Ice jam",
+        RainAndIceJam => "IC", "Rain and/or snowmelt and/or ice jam",
+        GlacierOutburst => "GO", "Glacier-dammed lake outburst",
+        DamOrLevee => "DM", "Dam or levee failure",
+        DamRelease => "DR", "Upstream dam or reservoir release",
+        MultipleCauses => "MC", "Other multiple causes",
+        Other => "OT", "Other effects",
+        Unknown => "UU", "Unknown",
+    }
+}
+
+vtec_code_enum! {
+    /// The flood record status (`fr`) of an H-VTEC string.
+    FloodRecord {
+        NearRecord => "NR", "Near record or record flood expected",
+        NoRecord => "NO", "Record flood not expected",
+        Unknown => "UU", "Flood without a period of record to compare",
+        NotApplicable => "OO", "Not applicable",
+    }
+}
+
+/// A parsed Primary VTEC (P-VTEC) string.
+///
+/// Format: `/k.aaa.cccc.pp.s.####.yymmddThhnnZ-yymmddThhnnZ/`
+#[derive(Clone, Debug, PartialEq, Eq, SerializeDisplay, DeserializeFromStr)]
+pub struct PrimaryVtec {
+    /// The product class.
+    pub product_class: VtecProductClass,
+    /// What this product does to the event.
+    pub action: VtecAction,
+    /// The four-letter identifier of the issuing office (e.g., "KPSR").
+    pub office: String,
+    /// The hazard being described.
+    pub phenomena: VtecPhenomena,
+    /// Whether the event is a warning, watch, advisory, etc.
+    pub significance: VtecSignificance,
+    /// The Event Tracking Number, unique per office, phenomena, and significance within a year.
+    pub event_tracking_number: u16,
+    /// The beginning of the event, or `None` if the event is already in effect.
+    pub begin: Option<Timestamp>,
+    /// The end of the event, or `None` if the end time is not yet known.
+    pub end: Option<Timestamp>,
+}
+
+impl PrimaryVtec {
+    /// Returns an identifier for the event this string refers to, e.g., "KPSR.FF.W.0012".
+    ///
+    /// All products describing the same event share this identifier, which makes
+    /// it a stable key for tracking an event across issuances within a year.
+    pub fn event_id(&self) -> String {
+        format!(
+            "{}.{}.{}.{:04}",
+            self.office, self.phenomena, self.significance, self.event_tracking_number
+        )
+    }
+}
+
+impl fmt::Display for PrimaryVtec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "/{}.{}.{}.{}.{}.{:04}.{}-{}/",
+            self.product_class,
+            self.action,
+            self.office,
+            self.phenomena,
+            self.significance,
+            self.event_tracking_number,
+            format_vtec_time(self.begin),
+            format_vtec_time(self.end)
+        )
+    }
+}
+
+impl FromStr for PrimaryVtec {
+    type Err = ParseVtecError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseVtecError::new(string);
+        let fields: Vec<&str> = strip_slashes(string)
+            .ok_or_else(invalid)?
+            .split('.')
+            .collect();
+        let [
+            product_class,
+            action,
+            office,
+            phenomena,
+            significance,
+            event_tracking_number,
+            times,
+        ] = fields.as_slice()
+        else {
+            return Err(invalid());
+        };
+        if office.len() != 4 || !office.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(invalid());
+        }
+        let (begin, end) = times.split_once('-').ok_or_else(invalid)?;
+        Ok(Self {
+            product_class: product_class.parse()?,
+            action: action.parse()?,
+            office: (*office).to_owned(),
+            phenomena: phenomena.parse()?,
+            significance: significance.parse()?,
+            event_tracking_number: event_tracking_number.parse().map_err(|_| invalid())?,
+            begin: parse_vtec_time(begin)?,
+            end: parse_vtec_time(end)?,
+        })
+    }
+}
+
+/// A parsed Hydrologic VTEC (H-VTEC) string.
+///
+/// Format: `/nwsli.s.ic.yymmddThhnnZ.yymmddThhnnZ.yymmddThhnnZ.fr/`
+#[derive(Clone, Debug, PartialEq, Eq, SerializeDisplay, DeserializeFromStr)]
+pub struct HydrologicVtec {
+    /// The NWS Location Identifier of the flood point, or "00000" for areal products.
+    pub nwsli: String,
+    /// The expected flood severity.
+    pub flood_severity: FloodSeverity,
+    /// The immediate cause of the flooding.
+    pub immediate_cause: FloodImmediateCause,
+    /// When the flood begins, if known.
+    pub begin: Option<Timestamp>,
+    /// When the flood crests, if known.
+    pub crest: Option<Timestamp>,
+    /// When the flood ends, if known.
+    pub end: Option<Timestamp>,
+    /// Whether a record flood is expected.
+    pub flood_record: FloodRecord,
+}
+
+impl fmt::Display for HydrologicVtec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "/{}.{}.{}.{}.{}.{}.{}/",
+            self.nwsli,
+            self.flood_severity,
+            self.immediate_cause,
+            format_vtec_time(self.begin),
+            format_vtec_time(self.crest),
+            format_vtec_time(self.end),
+            self.flood_record
+        )
+    }
+}
+
+impl FromStr for HydrologicVtec {
+    type Err = ParseVtecError;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseVtecError::new(string);
+        let fields: Vec<&str> = strip_slashes(string)
+            .ok_or_else(invalid)?
+            .split('.')
+            .collect();
+        let [
+            nwsli,
+            flood_severity,
+            immediate_cause,
+            begin,
+            crest,
+            end,
+            flood_record,
+        ] = fields.as_slice()
+        else {
+            return Err(invalid());
+        };
+        if nwsli.len() != 5 || !nwsli.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(invalid());
+        }
+        Ok(Self {
+            nwsli: (*nwsli).to_owned(),
+            flood_severity: flood_severity.parse()?,
+            immediate_cause: immediate_cause.parse()?,
+            begin: parse_vtec_time(begin)?,
+            crest: parse_vtec_time(crest)?,
+            end: parse_vtec_time(end)?,
+            flood_record: flood_record.parse()?,
+        })
+    }
+}
+
+/// A P-VTEC string together with the H-VTEC string that follows it in hydrologic products.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Vtec {
+    /// The primary VTEC string.
+    pub primary: PrimaryVtec,
+    /// The hydrologic VTEC string, present for flood products.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hydrologic: Option<HydrologicVtec>,
+}
+
+/// Finds and parses every VTEC string in a block of text.
+///
+/// Each P-VTEC string is paired with the H-VTEC string immediately following
+/// it, if any. Slash-delimited tokens that are not valid VTEC strings are
+/// skipped, so this can be run over a complete text product.
+///
+/// # Examples
+///
+/// ```
+/// use noaa_weather_client::vtec::{FloodSeverity, parse_vtec};
+///
+/// let text = "AZC013-020200-\n\
+///     /O.NEW.KPSR.FA.W.0004.250601T2300Z-250602T0200Z/\n\
+///     /00000.0.ER.000000T0000Z.000000T0000Z.000000T0000Z.OO/\n";
+/// let vtecs = parse_vtec(text);
+/// assert_eq!(vtecs.len(), 1);
+/// let hydrologic = vtecs[0].hydrologic.as_ref().unwrap();
+/// assert_eq!(hydrologic.flood_severity, FloodSeverity::AreaFlood);
+/// ```
+pub fn parse_vtec(text: &str) -> Vec<Vtec> {
+    let mut vtecs: Vec<Vtec> = Vec::new();
+    let mut previous_was_primary = false;
+    for token in text.split_whitespace() {
+        if let Ok(primary) = token.parse::<PrimaryVtec>() {
+            vtecs.push(Vtec {
+                primary,
+                hydrologic: None,
+            });
+            previous_was_primary = true;
+            continue;
+        }
+        if let Ok(hydrologic) = token.parse::<HydrologicVtec>()
+            && previous_was_primary
+            && let Some(last) = vtecs.last_mut()
+        {
+            last.hydrologic = Some(hydrologic);
+        }
+        previous_was_primary = false;
+    }
+    vtecs
+}
+
+/// Strips the leading and trailing slashes from a VTEC string.
+fn strip_slashes(string: &str) -> Option<&str> {
+    string.trim().strip_prefix('/')?.strip_suffix('/')
+}
+
+/// Parses a VTEC time (`yymmddThhnnZ`), returning `None` for the undefined time `000000T0000Z`.
+fn parse_vtec_time(string: &str) -> Result<Option<Timestamp>, ParseVtecError> {
+    if string == UNDEFINED_TIME {
+        return Ok(None);
+    }
+    let invalid = || ParseVtecError::new(string);
+    let digits = string
+        .strip_suffix('Z')
+        .and_then(|time| time.split_once('T'))
+        .filter(|(date, time)| date.len() == 6 && time.len() == 4)
+        .ok_or_else(invalid)?;
+    let field = |source: &str, range: std::ops::Range<usize>| -> Result<i8, ParseVtecError> {
+        source
+            .get(range)
+            .and_then(|value| value.parse().ok())
+            .ok_or_else(invalid)
+    };
+    let year = 2000 + i16::from(field(digits.0, 0..2)?);
+    let datetime = DateTime::new(
+        year,
+        field(digits.0, 2..4)?,
+        field(digits.0, 4..6)?,
+        field(digits.1, 0..2)?,
+        field(digits.1, 2..4)?,
+        0,
+        0,
+    )
+    .map_err(|_| invalid())?;
+    datetime
+        .to_zoned(TimeZone::UTC)
+        .map(|zoned| Some(zoned.timestamp()))
+        .map_err(|_| invalid())
+}
+
+/// Formats a timestamp as a VTEC time, using `000000T0000Z` for `None`.
+fn format_vtec_time(timestamp: Option<Timestamp>) -> String {
+    timestamp.map_or_else(
+        || UNDEFINED_TIME.to_owned(),
+        |timestamp| timestamp.strftime("%y%m%dT%H%MZ").to_string(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_primary_vtec() {
+        let vtec: PrimaryVtec = "/O.NEW.KPSR.FF.W.0012.250601T2300Z-250602T0200Z/"
+            .parse()
+            .unwrap();
+        assert_eq!(vtec.product_class, VtecProductClass::Operational);
+        assert_eq!(vtec.action, VtecAction::New);
+        assert_eq!(vtec.office, "KPSR");
+        assert_eq!(vtec.phenomena, VtecPhenomena::FlashFlood);
+        assert_eq!(vtec.significance, VtecSignificance::Warning);
+        assert_eq!(vtec.event_tracking_number, 12);
+        assert_eq!(
+            vtec.begin,
+            Some("2025-06-01T23:00:00Z".parse::<Timestamp>().unwrap())
+        );
+        assert_eq!(
+            vtec.end,
+            Some("2025-06-02T02:00:00Z".parse::<Timestamp>().unwrap())
+        );
+        assert_eq!(vtec.event_id(), "KPSR.FF.W.0012");
+    }
+
+    #[test]
+    fn test_primary_vtec_round_trip() {
+        let original = "/O.EXT.KTWC.HT.Y.0003.000000T0000Z-250615T0300Z/";
+        let vtec: PrimaryVtec = original.parse().unwrap();
+        assert_eq!(vtec.begin, None);
+        assert_eq!(vtec.to_string(), original);
+    }
+
+    #[test]
+    fn test_parse_primary_vtec_err() {
+        assert!("/O.NEW.KPSR.FF.W/".parse::<PrimaryVtec>().is_err());
+        assert!(
+            "/O.BAD.KPSR.FF.W.0012.250601T2300Z-250602T0200Z/"
+                .parse::<PrimaryVtec>()
+                .is_err()
+        );
+        assert!(
+            "O.NEW.KPSR.FF.W.0012.250601T2300Z-250602T0200Z"
+                .parse::<PrimaryVtec>()
+                .is_err()
+        );
+        assert!(
+            "/O.NEW.KPSR.FF.W.0012.251301T2300Z-250602T0200Z/"
+                .parse::<PrimaryVtec>()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parse_hydrologic_vtec() {
+        let original = "/GLDI4.2.ER.250601T1200Z.250603T0600Z.250605T1800Z.NO/";
+        let vtec: HydrologicVtec = original.parse().unwrap();
+        assert_eq!(vtec.nwsli, "GLDI4");
+        assert_eq!(vtec.flood_severity, FloodSeverity::Moderate);
+        assert_eq!(vtec.immediate_cause, FloodImmediateCause::ExcessiveRainfall);
+        assert_eq!(vtec.flood_record, FloodRecord::NoRecord);
+        assert_eq!(vtec.to_string(), original);
+    }
+
+    #[test]
+    fn test_parse_vtec_in_text() {
+        let text = "\
+WGUS55 KPSR 012300
+FFWPSR
+
+AZC013-020200-
+/O.NEW.KPSR.FF.W.0012.250601T2300Z-250602T0200Z/
+/00000.0.ER.000000T0000Z.000000T0000Z.000000T0000Z.OO/
+
+BULLETIN - EAS ACTIVATION REQUESTED
+/O.CON.KPSR.SV.W.0101.000000T0000Z-250601T2345Z/
+";
+        let vtecs = parse_vtec(text);
+        assert_eq!(vtecs.len(), 2);
+        assert_eq!(vtecs[0].primary.phenomena, VtecPhenomena::FlashFlood);
+        assert!(vtecs[0].hydrologic.is_some());
+        assert_eq!(vtecs[1].primary.action, VtecAction::Continue);
+        assert!(vtecs[1].hydrologic.is_none());
+    }
+
+    #[test]
+    fn test_serialize_as_vtec_string() {
+        let vtec: PrimaryVtec = "/O.NEW.KPSR.FF.W.0012.250601T2300Z-250602T0200Z/"
+            .parse()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_string(&vtec).unwrap(),
+            "\"/O.NEW.KPSR.FF.W.0012.250601T2300Z-250602T0200Z/\""
+        );
+    }
+}
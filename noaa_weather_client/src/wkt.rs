@@ -0,0 +1,431 @@
+//! Conversion between Well-Known Text (WKT) and the crate's GeoJSON geometry models.
+//!
+//! JSON-LD responses report geometry as WKT strings, such as the `geometry`
+//! fields of [`Gridpoint`](crate::models::Gridpoint),
+//! [`Observation`](crate::models::Observation), [`Zone`](crate::models::Zone),
+//! and [`Point`](crate::models::Point). This module parses those strings into
+//! [`GeoJsonGeometry`] values and writes them back.
+//!
+//! Positions are written `x y` (longitude, then latitude), matching GeoJSON.
+//! Z and M values are kept as extra position elements.
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::models::GeoJsonGeometry;
+//! use noaa_weather_client::wkt;
+//!
+//! let geometry = wkt::parse_wkt("POINT(-112.07 33.45)").unwrap();
+//! let GeoJsonGeometry::GeoJsonPoint(point) = &geometry else {
+//!     panic!("expected a point");
+//! };
+//! assert_eq!(point.coordinates, [-112.07, 33.45]);
+//! assert_eq!(wkt::to_wkt(&geometry), "POINT(-112.07 33.45)");
+//! ```
+
+use std::fmt;
+
+use crate::models::{
+    GeoJsonGeometry, GeoJsonLineString, GeoJsonMultiLineString, GeoJsonMultiPoint,
+    GeoJsonMultiPolygon, GeoJsonPoint, GeoJsonPolygon,
+};
+
+/// An error returned when a WKT string cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseWktError {
+    invalid_value: String,
+}
+
+impl ParseWktError {
+    fn new(invalid_value: &str) -> Self {
+        Self {
+            invalid_value: invalid_value.to_owned(),
+        }
+    }
+}
+
+impl fmt::Display for ParseWktError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid WKT geometry: {}", self.invalid_value)
+    }
+}
+
+impl std::error::Error for ParseWktError {}
+
+/// A parenthesized WKT coordinate list, before it is matched to a geometry type.
+enum Node {
+    Position(Vec<f64>),
+    List(Vec<Node>),
+}
+
+/// Parses a WKT string into a GeoJSON geometry.
+///
+/// Supports `POINT`, `LINESTRING`, `POLYGON`, `MULTIPOINT`, `MULTILINESTRING`,
+/// and `MULTIPOLYGON`, with optional `Z`, `M`, or `ZM` dimensions and an
+/// optional `SRID=...;` prefix. Keywords are case-insensitive.
+///
+/// # Errors
+///
+/// Returns a [`ParseWktError`] if the string is not valid WKT or uses an unsupported geometry type.
+pub fn parse_wkt(text: &str) -> Result<GeoJsonGeometry, ParseWktError> {
+    let invalid = || ParseWktError::new(text);
+    let trimmed = text.trim();
+    let body = match trimmed.split_once(';') {
+        Some((srid, body)) if srid.trim().to_ascii_uppercase().starts_with("SRID=") => body.trim(),
+        _ => trimmed,
+    };
+    let keyword_end = body
+        .find(|c: char| c == '(' || c.is_whitespace())
+        .unwrap_or(body.len());
+    let keyword = body[..keyword_end].to_ascii_uppercase();
+    let mut rest = body[keyword_end..].trim_start();
+    for dimension in ["ZM", "Z", "M"] {
+        if rest
+            .get(..dimension.len())
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case(dimension))
+            && !rest[dimension.len()..].starts_with(|c: char| c.is_ascii_alphabetic())
+        {
+            rest = rest[dimension.len()..].trim_start();
+            break;
+        }
+    }
+
+    let node = if rest.eq_ignore_ascii_case("EMPTY") {
+        Node::List(Vec::new())
+    } else {
+        let mut parser = Parser {
+            input: rest,
+            pos: 0,
+        };
+        let node = parser.list().ok_or_else(invalid)?;
+        parser.skip_whitespace();
+        if parser.pos != parser.input.len() {
+            return Err(invalid());
+        }
+        node
+    };
+
+    let geometry = match keyword.as_str() {
+        "POINT" => {
+            let Node::List(mut items) = node else {
+                return Err(invalid());
+            };
+            let coordinates = match (items.pop(), items.is_empty()) {
+                (None, _) if rest.eq_ignore_ascii_case("EMPTY") => Vec::new(),
+                (Some(Node::Position(position)), true) => position,
+                _ => return Err(invalid()),
+            };
+            GeoJsonGeometry::GeoJsonPoint(Box::new(GeoJsonPoint {
+                coordinates,
+                ..Default::default()
+            }))
+        }
+        "LINESTRING" => GeoJsonGeometry::GeoJsonLineString(Box::new(GeoJsonLineString {
+            coordinates: positions(node).ok_or_else(invalid)?,
+            ..Default::default()
+        })),
+        "POLYGON" => GeoJsonGeometry::GeoJsonPolygon(Box::new(GeoJsonPolygon {
+            coordinates: rings(node).ok_or_else(invalid)?,
+            ..Default::default()
+        })),
+        "MULTIPOINT" => {
+            // Both `MULTIPOINT((1 2), (3 4))` and `MULTIPOINT(1 2, 3 4)` are valid.
+            let Node::List(items) = node else {
+                return Err(invalid());
+            };
+            let coordinates = items
+                .into_iter()
+                .map(|item| match item {
+                    Node::Position(position) => Some(position),
+                    Node::List(mut inner) if inner.len() == 1 => match inner.pop() {
+                        Some(Node::Position(position)) => Some(position),
+                        _ => None,
+                    },
+                    Node::List(_) => None,
+                })
+                .collect::<Option<_>>()
+                .ok_or_else(invalid)?;
+            GeoJsonGeometry::GeoJsonMultiPoint(Box::new(GeoJsonMultiPoint {
+                coordinates,
+                ..Default::default()
+            }))
+        }
+        "MULTILINESTRING" => {
+            GeoJsonGeometry::GeoJsonMultiLineString(Box::new(GeoJsonMultiLineString {
+                coordinates: rings(node).ok_or_else(invalid)?,
+                ..Default::default()
+            }))
+        }
+        "MULTIPOLYGON" => {
+            let Node::List(items) = node else {
+                return Err(invalid());
+            };
+            GeoJsonGeometry::GeoJsonMultiPolygon(Box::new(GeoJsonMultiPolygon {
+                coordinates: items
+                    .into_iter()
+                    .map(rings)
+                    .collect::<Option<_>>()
+                    .ok_or_else(invalid)?,
+                ..Default::default()
+            }))
+        }
+        _ => return Err(invalid()),
+    };
+    Ok(geometry)
+}
+
+/// Writes a GeoJSON geometry as WKT, e.g., `POLYGON((-112.5 33, -111.5 33, ...))`.
+///
+/// Geometries without coordinates are written as, e.g., `POLYGON EMPTY`.
+pub fn to_wkt(geometry: &GeoJsonGeometry) -> String {
+    let (keyword, body) = match geometry {
+        GeoJsonGeometry::GeoJsonPoint(point) => (
+            "POINT",
+            (!point.coordinates.is_empty())
+                .then(|| format!("({})", format_position(&point.coordinates))),
+        ),
+        GeoJsonGeometry::GeoJsonLineString(line_string) => (
+            "LINESTRING",
+            (!line_string.coordinates.is_empty())
+                .then(|| format_positions(&line_string.coordinates)),
+        ),
+        GeoJsonGeometry::GeoJsonPolygon(polygon) => (
+            "POLYGON",
+            (!polygon.coordinates.is_empty()).then(|| format_rings(&polygon.coordinates)),
+        ),
+        GeoJsonGeometry::GeoJsonMultiPoint(multi_point) => {
+            let points: Vec<String> = multi_point
+                .coordinates
+                .iter()
+                .map(|position| format!("({})", format_position(position)))
+                .collect();
+            (
+                "MULTIPOINT",
+                (!points.is_empty()).then(|| format!("({})", points.join(", "))),
+            )
+        }
+        GeoJsonGeometry::GeoJsonMultiLineString(multi_line_string) => (
+            "MULTILINESTRING",
+            (!multi_line_string.coordinates.is_empty())
+                .then(|| format_rings(&multi_line_string.coordinates)),
+        ),
+        GeoJsonGeometry::GeoJsonMultiPolygon(multi_polygon) => {
+            let polygons: Vec<String> = multi_polygon
+                .coordinates
+                .iter()
+                .map(|polygon| format_rings(polygon))
+                .collect();
+            (
+                "MULTIPOLYGON",
+                (!polygons.is_empty()).then(|| format!("({})", polygons.join(", "))),
+            )
+        }
+    };
+    match body {
+        Some(body) => format!("{keyword}{body}"),
+        None => format!("{keyword} EMPTY"),
+    }
+}
+
+/// Parses an optional WKT field, as found on the JSON-LD models.
+///
+/// Returns `Ok(None)` when the field is absent or null.
+pub(crate) fn parse_optional_wkt(
+    geometry: Option<&Option<String>>,
+) -> Result<Option<GeoJsonGeometry>, ParseWktError> {
+    geometry
+        .and_then(Option::as_deref)
+        .map(parse_wkt)
+        .transpose()
+}
+
+fn positions(node: Node) -> Option<Vec<Vec<f64>>> {
+    let Node::List(items) = node else {
+        return None;
+    };
+    items
+        .into_iter()
+        .map(|item| match item {
+            Node::Position(position) => Some(position),
+            Node::List(_) => None,
+        })
+        .collect()
+}
+
+fn rings(node: Node) -> Option<Vec<Vec<Vec<f64>>>> {
+    let Node::List(items) = node else {
+        return None;
+    };
+    items.into_iter().map(positions).collect()
+}
+
+fn format_position(position: &[f64]) -> String {
+    position
+        .iter()
+        .map(f64::to_string)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn format_positions(positions: &[Vec<f64>]) -> String {
+    let positions: Vec<String> = positions
+        .iter()
+        .map(|position| format_position(position))
+        .collect();
+    format!("({})", positions.join(", "))
+}
+
+fn format_rings(rings: &[Vec<Vec<f64>>]) -> String {
+    let rings: Vec<String> = rings.iter().map(|ring| format_positions(ring)).collect();
+    format!("({})", rings.join(", "))
+}
+
+/// A recursive-descent parser over the parenthesized part of a WKT string.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        if self.input[self.pos..].starts_with(expected) {
+            self.pos += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Parses `( item, item, ... )`, where each item is a nested list or a position.
+    fn list(&mut self) -> Option<Node> {
+        if !self.eat('(') {
+            return None;
+        }
+        let mut items = Vec::new();
+        loop {
+            self.skip_whitespace();
+            let item = if self.input[self.pos..].starts_with('(') {
+                self.list()?
+            } else {
+                Node::Position(self.position()?)
+            };
+            items.push(item);
+            if self.eat(')') {
+                return Some(Node::List(items));
+            }
+            if !self.eat(',') {
+                return None;
+            }
+        }
+    }
+
+    /// Parses whitespace-separated numbers up to the next `,` or `)`.
+    fn position(&mut self) -> Option<Vec<f64>> {
+        let rest = &self.input[self.pos..];
+        let end = rest.find([',', ')'])?;
+        let position: Vec<f64> = rest[..end]
+            .split_whitespace()
+            .map(|number| number.parse().ok())
+            .collect::<Option<_>>()?;
+        if !(2..=4).contains(&position.len()) {
+            return None;
+        }
+        self.pos += end;
+        Some(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(text: &str) {
+        let geometry = parse_wkt(text).unwrap();
+        assert_eq!(to_wkt(&geometry), text);
+    }
+
+    #[test]
+    fn test_round_trip_all_types() {
+        round_trip("POINT(-112.07 33.45)");
+        round_trip("LINESTRING(-112 33, -111.5 33.5)");
+        round_trip("POLYGON((-112.5 33, -111.5 33, -111.5 34, -112.5 33))");
+        round_trip("MULTIPOINT((-112 33), (-111 34))");
+        round_trip("MULTILINESTRING((-112 33, -111 34), (-110 32, -109 31))");
+        round_trip(
+            "MULTIPOLYGON(((-112 33, -111 33, -111 34, -112 33)), ((-110 32, -109 32, -109 33, -110 32)))",
+        );
+    }
+
+    #[test]
+    fn test_round_trip_empty() {
+        for keyword in [
+            "POINT",
+            "LINESTRING",
+            "POLYGON",
+            "MULTIPOINT",
+            "MULTILINESTRING",
+            "MULTIPOLYGON",
+        ] {
+            round_trip(&format!("{keyword} EMPTY"));
+        }
+        assert_eq!(
+            to_wkt(&GeoJsonGeometry::GeoJsonPoint(Box::default())),
+            "POINT EMPTY"
+        );
+    }
+
+    #[test]
+    fn test_parse_variants() {
+        let geometry = parse_wkt("SRID=4326;point z (-112.07 33.45 331)").unwrap();
+        let GeoJsonGeometry::GeoJsonPoint(point) = geometry else {
+            panic!("expected a point");
+        };
+        assert_eq!(point.coordinates, [-112.07, 33.45, 331.0]);
+
+        let geometry = parse_wkt("MULTIPOINT (-112 33, -111 34)").unwrap();
+        let GeoJsonGeometry::GeoJsonMultiPoint(multi_point) = geometry else {
+            panic!("expected a multipoint");
+        };
+        assert_eq!(multi_point.coordinates.len(), 2);
+
+        let geometry = parse_wkt("POLYGON EMPTY").unwrap();
+        let GeoJsonGeometry::GeoJsonPolygon(polygon) = geometry else {
+            panic!("expected a polygon");
+        };
+        assert!(polygon.coordinates.is_empty());
+        assert_eq!(
+            to_wkt(&GeoJsonGeometry::GeoJsonPolygon(polygon)),
+            "POLYGON EMPTY"
+        );
+    }
+
+    #[test]
+    fn test_parse_wkt_err() {
+        assert!(parse_wkt("POINT(-112.07)").is_err());
+        assert!(parse_wkt("POINT(-112.07 33.45").is_err());
+        assert!(parse_wkt("POINT(-112.07 33.45) extra").is_err());
+        assert!(parse_wkt("POLYGON(-112 33, -111 34)").is_err());
+        assert!(parse_wkt("CIRCLE(1 2)").is_err());
+        assert!(parse_wkt("GEOMETRYCOLLECTION(POINT(1 2))").is_err());
+        assert!(parse_wkt("POINT xé(1 2)").is_err());
+        assert!(parse_wkt("POINT é").is_err());
+        assert!(parse_wkt("POINT()").is_err());
+    }
+
+    #[test]
+    fn test_parse_optional_wkt() {
+        assert_eq!(parse_optional_wkt(None), Ok(None));
+        assert_eq!(parse_optional_wkt(Some(&None)), Ok(None));
+        assert!(
+            parse_optional_wkt(Some(&Some("POINT(1 2)".to_owned())))
+                .unwrap()
+                .is_some()
+        );
+    }
+}
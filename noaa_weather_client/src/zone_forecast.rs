@@ -0,0 +1,432 @@
+//! Summaries of zone forecast period text.
+//!
+//! The periods of a zone forecast only carry free text, such as
+//! `Sunny. Highs 98 to 103. Southwest winds 5 to 10 mph.` A
+//! [`ZonePeriodSummary`] pulls the sky condition, high and low temperature
+//! ranges, wind, and chance of precipitation out of that text. Temperatures
+//! are in degrees Fahrenheit and speeds in miles per hour, as written in the
+//! forecast. Phrases such as `in the mid 70s` become ranges (`74` to `76`).
+//!
+//! Summaries are available for zone forecast periods through
+//! [`ZoneForecastPeriodsInner::summary`](crate::models::ZoneForecastPeriodsInner::summary).
+//!
+//! # Examples
+//!
+//! ```
+//! use noaa_weather_client::models::gridpoint_12h_forecast_period::WindDirection;
+//! use noaa_weather_client::zone_forecast::{ForecastRange, SkyCondition, ZonePeriodSummary};
+//!
+//! let summary = ZonePeriodSummary::from_text(
+//!     "Sunny. Highs 98 to 103. Southwest winds 5 to 10 mph.",
+//! );
+//! assert_eq!(summary.sky, Some(SkyCondition::Sunny));
+//! assert_eq!(summary.high, Some(ForecastRange::new(98, 103)));
+//! assert_eq!(summary.wind_direction, Some(WindDirection::Sw));
+//! assert_eq!(summary.wind_speed, Some(ForecastRange::new(5, 10)));
+//! ```
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::gridpoint_12h_forecast_period::WindDirection;
+
+/// The values extracted from the text of a zone forecast period.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZonePeriodSummary {
+    /// The sky condition, e.g., `Mostly sunny`.
+    pub sky: Option<SkyCondition>,
+    /// The forecast high temperature in degrees Fahrenheit.
+    pub high: Option<ForecastRange>,
+    /// The forecast low temperature in degrees Fahrenheit.
+    pub low: Option<ForecastRange>,
+    /// The wind direction, absent for light and variable winds.
+    pub wind_direction: Option<WindDirection>,
+    /// The wind speed in miles per hour.
+    pub wind_speed: Option<ForecastRange>,
+    /// The wind gust speed in miles per hour.
+    pub wind_gust: Option<i16>,
+    /// The chance of precipitation in percent.
+    pub precipitation_chance: Option<u8>,
+}
+
+impl ZonePeriodSummary {
+    /// Extracts a summary from the text of a forecast period.
+    ///
+    /// Values that are not mentioned, or are phrased in a way that is not
+    /// recognized, are left as `None`.
+    pub fn from_text(text: &str) -> Self {
+        let mut summary = Self::default();
+        for sentence in sentences(text) {
+            let words = words(&sentence);
+            match words.first().map(String::as_str) {
+                Some("high" | "highs") if summary.high.is_none() => {
+                    summary.high = parse_temperature(&words[1..]);
+                }
+                Some("low" | "lows") if summary.low.is_none() => {
+                    summary.low = parse_temperature(&words[1..]);
+                }
+                _ => {}
+            }
+            if summary.sky.is_none() {
+                summary.sky = SkyCondition::from_sentence(&sentence);
+            }
+            if summary.wind_speed.is_none() && summary.wind_direction.is_none() {
+                summary.parse_wind(&words);
+            }
+            if summary.precipitation_chance.is_none() {
+                summary.precipitation_chance = parse_precipitation_chance(&words);
+            }
+        }
+        summary
+    }
+
+    /// Returns true if nothing could be extracted.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Parses the wind direction, speed, and gusts from a sentence such as
+    /// `West winds 10 to 15 mph with gusts to around 25 mph`.
+    fn parse_wind(&mut self, words: &[String]) {
+        let Some(wind) = words
+            .iter()
+            .position(|word| word == "wind" || word == "winds")
+        else {
+            return;
+        };
+        self.wind_direction = parse_direction(&words[..wind], true)
+            .or_else(|| parse_direction(&words[wind + 1..], false));
+        let speed_end = words[wind..]
+            .iter()
+            .position(|word| word == "mph")
+            .map(|offset| wind + offset);
+        let gusts = words.iter().position(|word| word.starts_with("gust"));
+        if let Some(speed_end) = speed_end.filter(|&end| gusts.is_none_or(|gusts| end < gusts)) {
+            self.wind_speed = parse_speed(&words[wind + 1..speed_end]);
+        }
+        if let Some(gusts) = gusts {
+            self.wind_gust = words[gusts + 1..]
+                .iter()
+                .take_while(|word| *word != "mph")
+                .filter_map(|word| word.parse().ok())
+                .last();
+        }
+    }
+}
+
+/// An inclusive range of forecast values, such as `98 to 103`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForecastRange {
+    /// The lower bound.
+    pub min: i16,
+    /// The upper bound.
+    pub max: i16,
+}
+
+impl ForecastRange {
+    /// Creates a range, swapping the bounds if they are reversed.
+    pub fn new(min: i16, max: i16) -> Self {
+        Self {
+            min: min.min(max),
+            max: min.max(max),
+        }
+    }
+
+    /// Creates a range holding a single value.
+    pub fn single(value: i16) -> Self {
+        Self::new(value, value)
+    }
+}
+
+/// Formats the range as `98-103`, or as a single value when both bounds are equal.
+impl fmt::Display for ForecastRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.min == self.max {
+            write!(f, "{}", self.min)
+        } else {
+            write!(f, "{}-{}", self.min, self.max)
+        }
+    }
+}
+
+/// The sky condition of a forecast period.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkyCondition {
+    Sunny,
+    MostlySunny,
+    PartlySunny,
+    Clear,
+    MostlyClear,
+    PartlyCloudy,
+    MostlyCloudy,
+    Cloudy,
+}
+
+impl SkyCondition {
+    const PHRASES: [(&str, Self); 8] = [
+        ("mostly sunny", Self::MostlySunny),
+        ("partly sunny", Self::PartlySunny),
+        ("mostly clear", Self::MostlyClear),
+        ("partly cloudy", Self::PartlyCloudy),
+        ("mostly cloudy", Self::MostlyCloudy),
+        ("sunny", Self::Sunny),
+        ("clear", Self::Clear),
+        ("cloudy", Self::Cloudy),
+    ];
+
+    /// Returns the sky condition a sentence starts with, e.g., `Mostly sunny with a slight chance of showers`.
+    fn from_sentence(sentence: &str) -> Option<Self> {
+        let sentence = sentence.to_ascii_lowercase();
+        Self::PHRASES.iter().find_map(|(phrase, sky)| {
+            sentence
+                .strip_prefix(phrase)
+                .filter(|rest| !rest.starts_with(|c: char| c.is_ascii_alphabetic()))
+                .map(|_| *sky)
+        })
+    }
+}
+
+impl fmt::Display for SkyCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sunny => write!(f, "Sunny"),
+            Self::MostlySunny => write!(f, "Mostly sunny"),
+            Self::PartlySunny => write!(f, "Partly sunny"),
+            Self::Clear => write!(f, "Clear"),
+            Self::MostlyClear => write!(f, "Mostly clear"),
+            Self::PartlyCloudy => write!(f, "Partly cloudy"),
+            Self::MostlyCloudy => write!(f, "Mostly cloudy"),
+            Self::Cloudy => write!(f, "Cloudy"),
+        }
+    }
+}
+
+/// Splits forecast text into sentences without their trailing periods.
+fn sentences(text: &str) -> Vec<String> {
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .split(". ")
+        .map(|sentence| sentence.trim().trim_end_matches('.').to_string())
+        .filter(|sentence| !sentence.is_empty())
+        .collect()
+}
+
+/// Splits a sentence into lowercase words without punctuation.
+fn words(sentence: &str) -> Vec<String> {
+    sentence
+        .split_whitespace()
+        .map(|word| {
+            word.trim_matches(|c: char| matches!(c, ',' | ';' | '.'))
+                .to_ascii_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+/// Parses a temperature phrase such as `98 to 103`, `around 100`,
+/// `in the mid 70s`, or `5 below to 10 above zero`.
+fn parse_temperature(words: &[String]) -> Option<ForecastRange> {
+    let (first, rest) = parse_temperature_value(words)?;
+    match rest.split_first() {
+        Some((to, rest)) if to == "to" => {
+            let (last, _) = parse_temperature_value(rest)?;
+            Some(ForecastRange::new(first.min, last.max))
+        }
+        _ => Some(first),
+    }
+}
+
+/// Parses a single temperature value or decade phrase, returning the words that follow it.
+fn parse_temperature_value(words: &[String]) -> Option<(ForecastRange, &[String])> {
+    let mut words = words;
+    while let Some((word, rest)) = words.split_first()
+        && matches!(word.as_str(), "around" | "near" | "about" | "in" | "the")
+    {
+        words = rest;
+    }
+    let (word, mut rest) = words.split_first()?;
+    let range = match word.as_str() {
+        "lower" | "mid" | "upper" => {
+            let (decade, after) = rest.split_first()?;
+            rest = after;
+            let decade = parse_decade(decade)?;
+            let third = (decade.max - decade.min + 1) / 3;
+            match word.as_str() {
+                "lower" => ForecastRange::new(decade.min, decade.min + third),
+                "mid" => ForecastRange::new(decade.min + third + 1, decade.max - third),
+                _ => ForecastRange::new(decade.max - third + 1, decade.max),
+            }
+        }
+        "zero" => ForecastRange::single(0),
+        "single" if rest.first().is_some_and(|digits| digits == "digits") => {
+            rest = &rest[1..];
+            ForecastRange::new(1, 9)
+        }
+        _ => match word.parse::<i16>() {
+            Ok(value) => {
+                let value = match rest.first().map(String::as_str) {
+                    Some("below") => {
+                        rest = &rest[1..];
+                        -value
+                    }
+                    Some("above") => {
+                        rest = &rest[1..];
+                        value
+                    }
+                    _ => value,
+                };
+                if rest.first().is_some_and(|zero| zero == "zero") {
+                    rest = &rest[1..];
+                }
+                ForecastRange::single(value)
+            }
+            Err(_) => parse_decade(word)?,
+        },
+    };
+    Some((range, rest))
+}
+
+/// Parses a decade such as `70s`, `100s`, or `teens`.
+fn parse_decade(word: &str) -> Option<ForecastRange> {
+    if word == "teens" {
+        return Some(ForecastRange::new(13, 19));
+    }
+    let decade: i16 = word.strip_suffix('s')?.parse().ok()?;
+    (decade % 10 == 0).then(|| ForecastRange::new(decade, decade + 9))
+}
+
+/// Parses a wind direction such as `southwest` or `north northwest`, reading
+/// backwards from the end of `words` when `before` is set.
+fn parse_direction(words: &[String], before: bool) -> Option<WindDirection> {
+    let candidates: Vec<&String> = if before {
+        words.iter().rev().take(2).collect()
+    } else {
+        words.iter().take(2).collect()
+    };
+    let mut points = Vec::new();
+    for word in candidates {
+        let point = match word.as_str() {
+            "north" | "northerly" => "N",
+            "northeast" | "northeasterly" => "NE",
+            "east" | "easterly" => "E",
+            "southeast" | "southeasterly" => "SE",
+            "south" | "southerly" => "S",
+            "southwest" | "southwesterly" => "SW",
+            "west" | "westerly" => "W",
+            "northwest" | "northwesterly" => "NW",
+            _ => break,
+        };
+        points.push(point);
+    }
+    if before {
+        points.reverse();
+    }
+    points.concat().parse().ok()
+}
+
+/// Parses a wind speed phrase such as `5 to 10`, `around 10`, or `up to 15`.
+fn parse_speed(words: &[String]) -> Option<ForecastRange> {
+    let values: Vec<i16> = words.iter().filter_map(|word| word.parse().ok()).collect();
+    let up_to = words.iter().any(|word| word == "up");
+    match values.as_slice() {
+        [value] if up_to => Some(ForecastRange::new(0, *value)),
+        [value] => Some(ForecastRange::single(*value)),
+        [min, max, ..] => Some(ForecastRange::new(*min, *max)),
+        [] => None,
+    }
+}
+
+/// Parses a chance of precipitation such as `Chance of rain 30 percent` or
+/// `Chance of precipitation is 30%`.
+fn parse_precipitation_chance(words: &[String]) -> Option<u8> {
+    let chance = words.windows(2).position(|pair| pair == ["chance", "of"])?;
+    let words = &words[chance + 2..];
+    words.iter().enumerate().find_map(|(index, word)| {
+        word.strip_suffix('%')
+            .or_else(|| {
+                words
+                    .get(index + 1)
+                    .filter(|percent| *percent == "percent")
+                    .map(|_| word.as_str())
+            })
+            .and_then(|value| value.parse().ok())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_with_ranges() {
+        let summary = ZonePeriodSummary::from_text(
+            "Mostly sunny with a slight chance of showers. Highs 98 to 103. West winds 10 to 15 mph with gusts to around 25 mph. Chance of rain 20 percent.",
+        );
+        assert_eq!(
+            summary,
+            ZonePeriodSummary {
+                sky: Some(SkyCondition::MostlySunny),
+                high: Some(ForecastRange::new(98, 103)),
+                low: None,
+                wind_direction: Some(WindDirection::W),
+                wind_speed: Some(ForecastRange::new(10, 15)),
+                wind_gust: Some(25),
+                precipitation_chance: Some(20),
+            }
+        );
+    }
+
+    #[test]
+    fn test_summary_with_decades() {
+        let summary = ZonePeriodSummary::from_text(
+            "Partly cloudy. Lows in the mid 70s. Light winds becoming north northwest around 10 mph after midnight.",
+        );
+        assert_eq!(summary.sky, Some(SkyCondition::PartlyCloudy));
+        assert_eq!(summary.low, Some(ForecastRange::new(74, 76)));
+        assert_eq!(summary.wind_direction, None);
+        assert_eq!(summary.wind_speed, Some(ForecastRange::single(10)));
+        assert_eq!(summary.precipitation_chance, None);
+    }
+
+    #[test]
+    fn test_temperature_phrases() {
+        let parse = |text: &str| parse_temperature(&words(text));
+        assert_eq!(parse("around 100"), Some(ForecastRange::single(100)));
+        assert_eq!(parse("in the 90s"), Some(ForecastRange::new(90, 99)));
+        assert_eq!(
+            parse("in the lower 100s"),
+            Some(ForecastRange::new(100, 103))
+        );
+        assert_eq!(parse("in the upper 20s"), Some(ForecastRange::new(27, 29)));
+        assert_eq!(
+            parse("in the upper 80s to around 92"),
+            Some(ForecastRange::new(87, 92))
+        );
+        assert_eq!(
+            parse("5 below to 10 above zero"),
+            Some(ForecastRange::new(-5, 10))
+        );
+        assert_eq!(parse("around 5 below"), Some(ForecastRange::single(-5)));
+        assert_eq!(parse("cooler"), None);
+    }
+
+    #[test]
+    fn test_wind_and_precipitation_phrases() {
+        let summary = ZonePeriodSummary::from_text(
+            "Cloudy. Winds southwest up to 15 mph. Chance of precipitation is 40%.",
+        );
+        assert_eq!(summary.wind_direction, Some(WindDirection::Sw));
+        assert_eq!(summary.wind_speed, Some(ForecastRange::new(0, 15)));
+        assert_eq!(summary.precipitation_chance, Some(40));
+    }
+
+    #[test]
+    fn test_summary_of_unrecognized_text() {
+        let summary = ZonePeriodSummary::from_text("Patchy blowing dust in the afternoon.");
+        assert!(summary.is_empty());
+        assert_eq!(ForecastRange::new(103, 98).to_string(), "98-103");
+    }
+}